@@ -57,7 +57,7 @@ macro_rules! struct_error_params {
             pub $limit:ident: Vec<$type:ty>,
         }
     ) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, serde::Serialize)]
         pub struct $Error {
             pub $limit: Vec<$type>,
         }
@@ -91,7 +91,7 @@ macro_rules! struct_error_params {
             pub $limit:ident: $type:ty,
         }
     ) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, serde::Serialize)]
         pub struct $Error {
             pub $limit: $type,
         }
@@ -117,7 +117,7 @@ macro_rules! struct_error_params {
         #[default_message=$default_message:literal]
         pub struct $Error:ident;
     ) => {
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, serde::Serialize)]
         pub struct $Error;
 
         impl FormatDefault for $Error {
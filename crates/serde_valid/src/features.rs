@@ -1,8 +1,20 @@
+#[cfg(feature = "async")]
+pub mod async_validate;
+
 #[cfg(feature = "fluent")]
 pub mod fluent;
 
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "schemars")]
+pub mod schemars;
+
 #[cfg(feature = "toml")]
 pub mod toml;
 
+#[cfg(feature = "utoipa")]
+pub mod utoipa;
+
 #[cfg(feature = "yaml")]
 pub mod yaml;
@@ -0,0 +1,346 @@
+use crate::validation::custom::IntoVecErrors;
+use crate::validation::{ArrayErrors, Error, Errors, ObjectErrors, VecErrors};
+use crate::{ErrorLimit, Groups, Validate, ValidateOptions, ValidationGroup};
+use futures_util::stream::{self, StreamExt};
+use indexmap::IndexMap;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Asynchronous validation, which runs the rules of [`Validate`]
+/// and then awaits the `#[validate(custom_async = ...)]` validators.
+///
+/// With the `async` feature, `#[derive(Validate)]` implements this trait
+/// for the type with `#[validate(asynchronous)]`, which can not have `#[validate(context = ...)]`.
+/// The types implementing [`Validate`] by hand can use the provided methods,
+/// which run only the rules of [`Validate`].
+///
+/// The futures are [`Send`], so that they can be spawned on a multi-threaded runtime.
+pub trait AsyncValidate: Validate + Sync {
+    fn validate_async(&self) -> impl Future<Output = Result<(), Errors>> + Send {
+        self.validate_async_with_concurrency(usize::MAX)
+    }
+
+    /// Validate with at most `concurrency` asynchronous validations running at once
+    /// across the fields of each struct and the items of each collection.
+    fn validate_async_with_concurrency(
+        &self,
+        concurrency: usize,
+    ) -> impl Future<Output = Result<(), Errors>> + Send {
+        async move {
+            self.__validate_async_limited(concurrency, &Groups::none(), &ErrorLimit::unlimited())
+                .await
+        }
+    }
+
+    /// Validate with the rules of the given groups, as [`Validate::validate_groups`].
+    fn validate_groups_async<G: ValidationGroup>(
+        &self,
+        groups: &[G],
+    ) -> impl Future<Output = Result<(), Errors>> + Send
+    where
+        Self: Sized,
+    {
+        async move {
+            self.__validate_async_limited(
+                usize::MAX,
+                &Groups::new(groups),
+                &ErrorLimit::unlimited(),
+            )
+            .await
+        }
+    }
+
+    /// Validate with the options, as [`Validate::validate_with_options`].
+    ///
+    /// No more asynchronous validations start once the limit is reached,
+    /// and the errors of the running ones are kept within the limit.
+    fn validate_async_with_options(
+        &self,
+        options: &ValidateOptions,
+    ) -> impl Future<Output = Result<(), Errors>> + Send {
+        async move {
+            self.__validate_async_limited(usize::MAX, &options.groups, &ErrorLimit::new(options))
+                .await
+        }
+    }
+
+    /// The validation of [`AsyncValidate::validate_groups_async`] with the concurrency,
+    /// counting the errors in `limit`.
+    #[doc(hidden)]
+    fn __validate_async_limited(
+        &self,
+        concurrency: usize,
+        groups: &Groups<'_>,
+        limit: &ErrorLimit,
+    ) -> impl Future<Output = Result<(), Errors>> + Send {
+        let _ = concurrency;
+        async move { self.__validate_limited(groups, limit) }
+    }
+}
+
+type FieldValidation<'a> = Pin<Box<dyn Future<Output = VecErrors> + Send + 'a>>;
+
+/// The asynchronous validations of the fields, which the derived [`AsyncValidate`] awaits together.
+#[doc(hidden)]
+pub struct AsyncValidations<'a, K> {
+    validations: Vec<(K, FieldValidation<'a>)>,
+    limit: &'a ErrorLimit,
+}
+
+impl<'a, K> AsyncValidations<'a, K> {
+    pub fn new(limit: &'a ErrorLimit) -> Self {
+        Self {
+            validations: vec![],
+            limit,
+        }
+    }
+
+    /// Push the validation of `#[validate(custom_async = ...)]`.
+    pub fn push_custom<M: IntoVecErrors>(
+        &mut self,
+        key: K,
+        validation: impl Future<Output = Result<(), M>> + Send + 'a,
+    ) {
+        let limit = self.limit;
+        self.validations.push((
+            key,
+            Box::pin(async move {
+                if limit.is_reached() {
+                    return vec![];
+                }
+                limit.take(
+                    validation
+                        .await
+                        .map_err(IntoVecErrors::into_vec_errors)
+                        .err()
+                        .unwrap_or_default(),
+                )
+            }),
+        ));
+    }
+
+    /// Push the validation of the `#[validate]` field, which counts its errors itself.
+    pub fn push_nested(
+        &mut self,
+        key: K,
+        validation: impl Future<Output = Result<(), Errors>> + Send + 'a,
+    ) {
+        let limit = self.limit;
+        self.validations.push((
+            key,
+            Box::pin(async move {
+                if limit.is_reached() {
+                    return vec![];
+                }
+                match validation.await {
+                    Ok(()) => vec![],
+                    Err(Errors::Object(object_errors)) => vec![Error::Properties(object_errors)],
+                    Err(Errors::Array(array_errors)) => vec![Error::Items(array_errors)],
+                    Err(Errors::NewType(new_type_errors)) => new_type_errors,
+                }
+            }),
+        ));
+    }
+
+    /// Await the validations, at most `concurrency` at once, in the pushed order.
+    pub async fn run(self, concurrency: usize) -> Vec<(K, VecErrors)> {
+        let (keys, validations): (Vec<_>, Vec<_>) = self.validations.into_iter().unzip();
+        let errors = stream::iter(validations)
+            .buffered(concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await;
+
+        keys.into_iter().zip(errors).collect()
+    }
+}
+
+type ItemValidation<'a, K> = Pin<Box<dyn Future<Output = (K, Result<(), Errors>)> + Send + 'a>>;
+
+/// Validate the items, at most `concurrency` at once.
+///
+/// The validations are boxed before the returned future,
+/// which holds no closure over the items and so is [`Send`].
+fn validate_items<'a, K, T>(
+    items: impl Iterator<Item = (K, &'a T)>,
+    concurrency: usize,
+    groups: &'a Groups<'a>,
+    limit: &'a ErrorLimit,
+) -> impl Future<Output = Vec<(K, Errors)>> + Send + 'a
+where
+    K: Send + 'a,
+    T: AsyncValidate + 'a,
+{
+    let validations = items
+        .map(|(key, item)| -> ItemValidation<'a, K> {
+            Box::pin(async move {
+                if limit.is_reached() {
+                    return (key, Ok(()));
+                }
+                (
+                    key,
+                    item.__validate_async_limited(concurrency, groups, limit)
+                        .await,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    async move {
+        stream::iter(validations)
+            .buffered(concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .filter_map(|(key, result)| result.err().map(|errors| (key, errors)))
+            .collect()
+    }
+}
+
+impl<T> AsyncValidate for Vec<T>
+where
+    T: AsyncValidate,
+{
+    async fn __validate_async_limited(
+        &self,
+        concurrency: usize,
+        groups: &Groups<'_>,
+        limit: &ErrorLimit,
+    ) -> Result<(), Errors> {
+        let items = validate_items(self.iter().enumerate(), concurrency, groups, limit).await;
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::Array(ArrayErrors::new(
+                vec![],
+                items.into_iter().collect(),
+            )))
+        }
+    }
+}
+
+impl<T, const N: usize> AsyncValidate for [T; N]
+where
+    T: AsyncValidate,
+{
+    async fn __validate_async_limited(
+        &self,
+        concurrency: usize,
+        groups: &Groups<'_>,
+        limit: &ErrorLimit,
+    ) -> Result<(), Errors> {
+        let items = validate_items(self.iter().enumerate(), concurrency, groups, limit).await;
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::Array(ArrayErrors::new(
+                vec![],
+                items.into_iter().collect(),
+            )))
+        }
+    }
+}
+
+impl<K, V> AsyncValidate for HashMap<K, V>
+where
+    K: Sync,
+    V: AsyncValidate,
+    for<'a> &'a K: Into<String>,
+{
+    async fn __validate_async_limited(
+        &self,
+        concurrency: usize,
+        groups: &Groups<'_>,
+        limit: &ErrorLimit,
+    ) -> Result<(), Errors> {
+        let items = validate_items(
+            self.iter()
+                .map(|(key, value)| (Cow::from(key.into()), value)),
+            concurrency,
+            groups,
+            limit,
+        )
+        .await;
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::Object(ObjectErrors::new(
+                vec![],
+                items.into_iter().collect(),
+            )))
+        }
+    }
+}
+
+impl<K, V> AsyncValidate for IndexMap<K, V>
+where
+    K: Sync,
+    V: AsyncValidate,
+    for<'a> &'a K: Into<String>,
+{
+    async fn __validate_async_limited(
+        &self,
+        concurrency: usize,
+        groups: &Groups<'_>,
+        limit: &ErrorLimit,
+    ) -> Result<(), Errors> {
+        let items = validate_items(
+            self.iter()
+                .map(|(key, value)| (Cow::from(key.into()), value)),
+            concurrency,
+            groups,
+            limit,
+        )
+        .await;
+
+        if items.is_empty() {
+            Ok(())
+        } else {
+            Err(Errors::Object(ObjectErrors::new(
+                vec![],
+                items.into_iter().collect(),
+            )))
+        }
+    }
+}
+
+impl<T> AsyncValidate for Option<T>
+where
+    T: AsyncValidate,
+{
+    async fn __validate_async_limited(
+        &self,
+        concurrency: usize,
+        groups: &Groups<'_>,
+        limit: &ErrorLimit,
+    ) -> Result<(), Errors> {
+        match self {
+            Some(value) => {
+                value
+                    .__validate_async_limited(concurrency, groups, limit)
+                    .await
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> AsyncValidate for Box<T>
+where
+    T: AsyncValidate,
+{
+    async fn __validate_async_limited(
+        &self,
+        concurrency: usize,
+        groups: &Groups<'_>,
+        limit: &ErrorLimit,
+    ) -> Result<(), Errors> {
+        self.as_ref()
+            .__validate_async_limited(concurrency, groups, limit)
+            .await
+    }
+}
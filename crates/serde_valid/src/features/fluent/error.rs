@@ -1,3 +1,4 @@
+use crate::validation::error::{NestedError, NestedErrors};
 use crate::validation::{ArrayErrors, ObjectErrors};
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -8,6 +9,16 @@ pub enum LocalizedError {
     Properties(ObjectErrors<LocalizedError>),
 }
 
+impl NestedError for LocalizedError {
+    fn nested_errors(&self) -> Option<NestedErrors<'_, Self>> {
+        match self {
+            LocalizedError::String(_) => None,
+            LocalizedError::Items(items) => Some(NestedErrors::Items(items)),
+            LocalizedError::Properties(properties) => Some(NestedErrors::Properties(properties)),
+        }
+    }
+}
+
 impl std::fmt::Display for LocalizedError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -116,6 +116,7 @@ impl Localize for crate::validation::Error {
             Self::MinProperties(message) => message.localize(bundle),
             Self::MaxProperties(message) => message.localize(bundle),
             Self::Enumerate(message) => message.localize(bundle),
+            Self::Rule(message) => message.localize(bundle),
             Self::Custom(message) => LocalizedError::String(message.to_string()),
             Self::Items(message) => LocalizedError::Items(message.localize(bundle)),
             Self::Properties(message) => LocalizedError::Properties(message.localize(bundle)),
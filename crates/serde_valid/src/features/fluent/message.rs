@@ -9,3 +9,26 @@ impl std::fmt::Display for Message {
         self.id.fmt(f)
     }
 }
+
+impl Message {
+    /// The fluent id and arguments as a JSON object.
+    pub fn params(&self) -> serde_json::Map<String, serde_json::Value> {
+        let args = self
+            .args
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    fluent::FluentValue::String(string) => serde_json::Value::from(string.as_ref()),
+                    fluent::FluentValue::Number(number) => serde_json::Value::from(number.value),
+                    _ => serde_json::Value::Null,
+                };
+                (key.to_string(), value)
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        serde_json::Map::from_iter([
+            ("id".to_string(), serde_json::Value::from(self.id)),
+            ("args".to_string(), serde_json::Value::Object(args)),
+        ])
+    }
+}
@@ -184,6 +184,7 @@ impl TryLocalize for crate::validation::Error {
             Self::MinProperties(message) => message.try_localize(bundle),
             Self::MaxProperties(message) => message.try_localize(bundle),
             Self::Enumerate(message) => message.try_localize(bundle),
+            Self::Rule(message) => message.try_localize(bundle),
             Self::Custom(message) => Ok(LocalizedError::String(message.to_string())),
             Self::Items(message) => Ok(LocalizedError::Items(message.try_localize(bundle)?)),
             Self::Properties(message) => {
@@ -0,0 +1,842 @@
+//! Strategies of [proptest](https://docs.rs/proptest) generating valid and invalid instances.
+//!
+//! With the `proptest` feature, `#[derive(ValidStrategy)]` reads the same `#[validate(...)]`
+//! attributes as `#[derive(Validate)]`, and implements [`ValidStrategy`].
+//!
+//! - [`ValidStrategy::valid_strategy`] generates instances which pass `validate()`.
+//! - [`ValidStrategy::invalid_strategy`] mutates one constraint of a valid instance,
+//!   and generates instances which fail `validate()` at [`Invalid::path`].
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use serde_valid::proptest::ValidStrategy;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Debug, Validate, ValidStrategy)]
+//! struct Data {
+//!     #[validate(minimum = 0)]
+//!     #[validate(exclusive_maximum = 100)]
+//!     val: i32,
+//!     #[validate(max_items = 4)]
+//!     #[validate(pattern = "^[a-z]{1,8}$")]
+//!     tags: Vec<String>,
+//!     count: u8,
+//! }
+//!
+//! proptest!(|(data in Data::valid_strategy())| {
+//!     prop_assert!(data.validate().is_ok());
+//! });
+//!
+//! proptest!(|(invalid in Data::invalid_strategy().unwrap())| {
+//!     let output = serde_valid::output::evaluate(&invalid.value, Default::default());
+//!     prop_assert!(!output.valid());
+//!     prop_assert!(["/val", "/tags"].iter().any(|path| invalid.path.starts_with(path)));
+//! });
+//! ```
+//!
+//! The values of the fields with `#[validate(...)]` attributes are generated by [`FieldStrategy`],
+//! which is implemented for the integers, the floats, `String`, `Vec<T>`, `Option<T>`
+//! and the derived types. The other fields are generated by [`Arbitrary`](proptest::arbitrary::Arbitrary).
+//!
+//! - The lengths of strings are counted in graphemes, as `min_length`/`max_length` do.
+//! - Strings of a `pattern` are generated from the regular expression.
+//! - Custom validators cannot be generated; the instances failing them are rejected.
+//! - Recursive types are not supported.
+
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use proptest::collection::vec;
+use proptest::sample::{select, Index};
+use proptest::strategy::{BoxedStrategy, LazyJust, Strategy, Union};
+
+use crate::output::{evaluate, OutputFormat, OutputUnit};
+use crate::rules::Rule;
+use crate::validation::error::escape_pointer_token;
+use crate::validation::{Literal, Number, Pattern};
+
+pub use serde_valid_derive::ValidStrategy;
+
+/// Strategies of a type, derived by `#[derive(ValidStrategy)]`.
+pub trait ValidStrategy: crate::Validate + Debug + Sized + 'static {
+    /// Generate the instances which pass `validate()`.
+    fn valid_strategy() -> BoxedStrategy<Self>;
+
+    /// Generate the instances which violate one rule, and fail `validate()` at [`Invalid::path`].
+    ///
+    /// `None` if the type has no rule which can be violated.
+    fn invalid_strategy() -> Option<BoxedStrategy<Invalid<Self>>>;
+}
+
+/// Strategies of a field type under the rules of the field.
+///
+/// The limits of items (e.g. `maximum` of `Vec<i32>`) apply to each item, as serde_valid does.
+pub trait FieldStrategy: Debug + Sized + 'static {
+    /// Generate the values which satisfy `rules`.
+    fn valid_field_strategy(rules: &[Rule]) -> BoxedStrategy<Self>;
+
+    /// Generate the values which violate one of `rules` (or of the rules of the items).
+    ///
+    /// `None` if no rule can be violated.
+    fn invalid_field_strategy(rules: &[Rule]) -> Option<BoxedStrategy<Invalid<Self>>>;
+}
+
+/// An instance violating a rule.
+#[derive(Debug, Clone)]
+pub struct Invalid<T> {
+    pub value: T,
+
+    /// The JSON pointer of the value failing the validation (e.g. `/tags/2`).
+    pub path: String,
+
+    /// The violated rule.
+    pub rule: Rule,
+}
+
+impl<T> Invalid<T> {
+    fn new(value: T, rule: Rule) -> Self {
+        Self {
+            value,
+            path: String::new(),
+            rule,
+        }
+    }
+
+    /// Prefix the path with the key of the value in its parent.
+    pub fn within(mut self, key: &str) -> Self {
+        self.path = format!("/{}{}", escape_pointer_token(key), self.path);
+        self
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Invalid<U> {
+        Invalid {
+            value: f(self.value),
+            path: self.path,
+            rule: self.rule,
+        }
+    }
+}
+
+/// Filter the instances which pass `validate()`, including the custom validators.
+#[doc(hidden)]
+pub fn __valid<T>(strategy: impl Strategy<Value = T> + 'static) -> BoxedStrategy<T>
+where
+    T: crate::Validate + Debug,
+{
+    strategy
+        .prop_filter("the value must pass `validate()`", |value| {
+            value.validate().is_ok()
+        })
+        .boxed()
+}
+
+/// Filter the instances which fail `validate()` at their paths.
+#[doc(hidden)]
+pub fn __invalid<T>(strategies: Vec<BoxedStrategy<Invalid<T>>>) -> Option<BoxedStrategy<Invalid<T>>>
+where
+    T: crate::Validate + Debug + 'static,
+{
+    Some(
+        union(strategies)?
+            .prop_filter("the value must fail `validate()` at the path", |invalid| {
+                fails_at(&invalid.value, &invalid.path)
+            })
+            .boxed(),
+    )
+}
+
+/// Generate one of the variants.
+#[doc(hidden)]
+pub fn __one_of<T: Debug + 'static>(strategies: Vec<BoxedStrategy<T>>) -> BoxedStrategy<T> {
+    union(strategies).unwrap_or_else(nothing)
+}
+
+fn fails_at<T: crate::Validate + ?Sized>(value: &T, path: &str) -> bool {
+    fn contains(unit: &OutputUnit, path: &str) -> bool {
+        (unit.error.is_some() && unit.instance_location == path)
+            || unit.errors.iter().any(|unit| contains(unit, path))
+    }
+
+    match evaluate(value, OutputFormat::Basic) {
+        crate::output::Output::Unit(unit) => contains(&unit, path),
+        crate::output::Output::Flag { .. } => false,
+    }
+}
+
+fn union<T: Debug + 'static>(strategies: Vec<BoxedStrategy<T>>) -> Option<BoxedStrategy<T>> {
+    match strategies.len() {
+        0 => None,
+        1 => strategies.into_iter().next(),
+        _ => Some(Union::new(strategies).boxed()),
+    }
+}
+
+/// A strategy rejecting every value, for the rules which cannot be satisfied.
+fn nothing<T: Debug + 'static>() -> BoxedStrategy<T> {
+    LazyJust::new(|| ())
+        .prop_filter_map("no value satisfies the rules", |()| None)
+        .boxed()
+}
+
+fn invalid<T: Debug + 'static>(
+    strategy: impl Strategy<Value = T> + 'static,
+    rule: &Rule,
+) -> BoxedStrategy<Invalid<T>> {
+    let rule = rule.clone();
+    strategy
+        .prop_map(move |value| Invalid::new(value, rule.clone()))
+        .boxed()
+}
+
+/// The values of `enumerate`, which are numbers for the numeric `T` and strings for `String`.
+fn enumerated<T: std::str::FromStr + Enumerable>(rules: &[Rule]) -> Option<Vec<T>> {
+    rules.iter().find_map(|rule| match rule {
+        Rule::Enumerate(literals) => Some(
+            literals
+                .iter()
+                .filter(|literal| T::is_enumerable(literal))
+                .filter_map(|literal| literal.to_string().parse().ok())
+                .collect(),
+        ),
+        _ => None,
+    })
+}
+
+trait Enumerable {
+    fn is_enumerable(literal: &Literal) -> bool;
+}
+
+impl Enumerable for i128 {
+    fn is_enumerable(literal: &Literal) -> bool {
+        matches!(literal, Literal::Number(_))
+    }
+}
+
+impl Enumerable for f64 {
+    fn is_enumerable(literal: &Literal) -> bool {
+        matches!(literal, Literal::Number(_))
+    }
+}
+
+impl Enumerable for String {
+    fn is_enumerable(literal: &Literal) -> bool {
+        matches!(literal, Literal::String(_) | Literal::Char(_))
+    }
+}
+
+fn number_to_f64(number: &Number) -> f64 {
+    number.to_string().parse().unwrap_or(f64::NAN)
+}
+
+/// The integer limit of a number, rounded by `round` if it has a fraction.
+fn number_to_i128(number: &Number, round: fn(f64) -> f64) -> i128 {
+    let source = number.to_string();
+    source
+        .parse()
+        .unwrap_or_else(|_| round(source.parse().unwrap_or(f64::NAN)) as i128)
+}
+
+/// Inclusive limits of the integers satisfying the numeric rules.
+fn integer_bounds(rules: &[Rule], min: i128, max: i128) -> (i128, i128) {
+    rules
+        .iter()
+        .fold((min, max), |(lower, upper), rule| match rule {
+            Rule::Minimum(limit) => (lower.max(number_to_i128(limit, f64::ceil)), upper),
+            Rule::ExclusiveMinimum(limit) => (
+                lower.max(number_to_i128(limit, f64::floor).saturating_add(1)),
+                upper,
+            ),
+            Rule::Maximum(limit) => (lower, upper.min(number_to_i128(limit, f64::floor))),
+            Rule::ExclusiveMaximum(limit) => (
+                lower,
+                upper.min(number_to_i128(limit, f64::ceil).saturating_sub(1)),
+            ),
+            _ => (lower, upper),
+        })
+}
+
+fn integer_multiple_of(rules: &[Rule]) -> Option<i128> {
+    rules.iter().find_map(|rule| match rule {
+        Rule::MultipleOf(limit) => Some(number_to_i128(limit, f64::round).abs()).filter(|m| *m > 1),
+        _ => None,
+    })
+}
+
+fn valid_integer_strategy(rules: &[Rule], min: i128, max: i128) -> BoxedStrategy<i128> {
+    let (lower, upper) = integer_bounds(rules, min, max);
+    let multiple_of = integer_multiple_of(rules);
+    let satisfies = move |value: &i128| {
+        lower <= *value && *value <= upper && multiple_of.is_none_or(|m| value % m == 0)
+    };
+
+    if let Some(values) = enumerated::<i128>(rules) {
+        let values = values.into_iter().filter(satisfies).collect::<Vec<_>>();
+        return match values.is_empty() {
+            true => nothing(),
+            false => select(values).boxed(),
+        };
+    }
+    if lower > upper {
+        return nothing();
+    }
+    match multiple_of {
+        Some(m) => {
+            let (first, last) = (lower.div_euclid(m) + 1, upper.div_euclid(m));
+            let first = if (first - 1) * m >= lower {
+                first - 1
+            } else {
+                first
+            };
+            match first > last {
+                true => nothing(),
+                false => (first..=last).prop_map(move |k| k * m).boxed(),
+            }
+        }
+        None => (lower..=upper).boxed(),
+    }
+}
+
+fn invalid_integer_strategies(
+    rules: &[Rule],
+    min: i128,
+    max: i128,
+) -> Vec<BoxedStrategy<Invalid<i128>>> {
+    /// The range of the violating values near the limit.
+    const SPAN: i128 = 1000;
+
+    let (lower, upper) = integer_bounds(rules, min, max);
+    rules
+        .iter()
+        .filter_map(|rule| {
+            let strategy = match rule {
+                Rule::Minimum(_) | Rule::ExclusiveMinimum(_) => {
+                    let limit = integer_bounds(std::slice::from_ref(rule), min, max).0;
+                    (limit > min)
+                        .then(|| (limit.saturating_sub(SPAN).max(min)..=limit - 1).boxed())?
+                }
+                Rule::Maximum(_) | Rule::ExclusiveMaximum(_) => {
+                    let limit = integer_bounds(std::slice::from_ref(rule), min, max).1;
+                    (limit < max)
+                        .then(|| (limit + 1..=limit.saturating_add(SPAN).min(max)).boxed())?
+                }
+                Rule::MultipleOf(_) => {
+                    let m = integer_multiple_of(std::slice::from_ref(rule))?;
+                    (lower.min(upper)..=upper.max(lower))
+                        .prop_filter("the value must not be a multiple", move |value| {
+                            value % m != 0
+                        })
+                        .boxed()
+                }
+                Rule::Enumerate(_) => {
+                    let values = enumerated::<i128>(std::slice::from_ref(rule))?;
+                    let first = values.iter().min().copied().unwrap_or(0);
+                    let last = values.iter().max().copied().unwrap_or(0);
+                    (first.saturating_sub(SPAN).max(min)..=last.saturating_add(SPAN).min(max))
+                        .prop_filter("the value must not be enumerated", move |value| {
+                            !values.contains(value)
+                        })
+                        .boxed()
+                }
+                _ => return None,
+            };
+            Some(invalid(strategy, rule))
+        })
+        .collect()
+}
+
+macro_rules! impl_integer_field_strategy {
+    ($($type:ty),*) => {
+        $(
+            impl FieldStrategy for $type {
+                fn valid_field_strategy(rules: &[Rule]) -> BoxedStrategy<Self> {
+                    valid_integer_strategy(rules, <$type>::MIN as i128, max_of::<$type>())
+                        .prop_map(|value| value as $type)
+                        .boxed()
+                }
+
+                fn invalid_field_strategy(rules: &[Rule]) -> Option<BoxedStrategy<Invalid<Self>>> {
+                    union(
+                        invalid_integer_strategies(rules, <$type>::MIN as i128, max_of::<$type>())
+                            .into_iter()
+                            .map(|strategy| {
+                                strategy
+                                    .prop_map(|invalid| invalid.map(|value| value as $type))
+                                    .boxed()
+                            })
+                            .collect(),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+/// The maximum of an integer type, saturated to `i128`.
+fn max_of<T: TryInto<i128> + num_traits::Bounded>() -> i128 {
+    T::max_value().try_into().unwrap_or(i128::MAX)
+}
+
+impl_integer_field_strategy!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+#[cfg(feature = "i128")]
+impl_integer_field_strategy!(i128, u128);
+
+/// Limits of the floats satisfying the numeric rules, with the exclusiveness.
+fn float_bounds(rules: &[Rule]) -> ((f64, bool), (f64, bool)) {
+    rules.iter().fold(
+        ((f64::NEG_INFINITY, false), (f64::INFINITY, false)),
+        |(lower, upper), rule| match rule {
+            Rule::Minimum(limit) => (tighter_lower(lower, (number_to_f64(limit), false)), upper),
+            Rule::ExclusiveMinimum(limit) => {
+                (tighter_lower(lower, (number_to_f64(limit), true)), upper)
+            }
+            Rule::Maximum(limit) => (lower, tighter_upper(upper, (number_to_f64(limit), false))),
+            Rule::ExclusiveMaximum(limit) => {
+                (lower, tighter_upper(upper, (number_to_f64(limit), true)))
+            }
+            _ => (lower, upper),
+        },
+    )
+}
+
+fn tighter_lower(a: (f64, bool), b: (f64, bool)) -> (f64, bool) {
+    if b.0 > a.0 || (b.0 == a.0 && b.1) {
+        b
+    } else {
+        a
+    }
+}
+
+fn tighter_upper(a: (f64, bool), b: (f64, bool)) -> (f64, bool) {
+    if b.0 < a.0 || (b.0 == a.0 && b.1) {
+        b
+    } else {
+        a
+    }
+}
+
+fn float_multiple_of(rules: &[Rule]) -> Option<f64> {
+    rules.iter().find_map(|rule| match rule {
+        Rule::MultipleOf(limit) => Some(number_to_f64(limit).abs()).filter(|m| *m > 0.0),
+        _ => None,
+    })
+}
+
+/// The range of the generated floats beyond an open end.
+const FLOAT_SPAN: f64 = 1000.0;
+
+fn valid_float_strategy(rules: &[Rule]) -> BoxedStrategy<f64> {
+    let ((lower, exclusive_lower), (upper, exclusive_upper)) = float_bounds(rules);
+    let multiple_of = float_multiple_of(rules);
+    let satisfies = move |value: &f64| {
+        (*value > lower || (!exclusive_lower && *value == lower))
+            && (*value < upper || (!exclusive_upper && *value == upper))
+            && multiple_of.is_none_or(|m| value % m == 0.0)
+    };
+
+    if let Some(values) = enumerated::<f64>(rules) {
+        let values = values.into_iter().filter(satisfies).collect::<Vec<_>>();
+        return match values.is_empty() {
+            true => nothing(),
+            false => select(values).boxed(),
+        };
+    }
+    let (lower, upper) = match (lower.is_finite(), upper.is_finite()) {
+        (true, true) => (lower, upper),
+        (true, false) => (lower, lower + FLOAT_SPAN),
+        (false, true) => (upper - FLOAT_SPAN, upper),
+        (false, false) => (-FLOAT_SPAN, FLOAT_SPAN),
+    };
+    if lower > upper {
+        return nothing();
+    }
+    let strategy = match multiple_of {
+        Some(m) => {
+            let (first, last) = ((lower / m).ceil() as i64, (upper / m).floor() as i64);
+            match first > last {
+                true => return nothing(),
+                false => (first..=last).prop_map(move |k| k as f64 * m).boxed(),
+            }
+        }
+        None => (lower..=upper).boxed(),
+    };
+    strategy
+        .prop_filter("the value must satisfy the rules", satisfies)
+        .boxed()
+}
+
+fn invalid_float_strategies(rules: &[Rule]) -> Vec<BoxedStrategy<Invalid<f64>>> {
+    rules
+        .iter()
+        .filter_map(|rule| {
+            let strategy = match rule {
+                Rule::Minimum(limit) => {
+                    let limit = number_to_f64(limit);
+                    (limit - FLOAT_SPAN..limit).boxed()
+                }
+                Rule::ExclusiveMinimum(limit) => {
+                    let limit = number_to_f64(limit);
+                    (limit - FLOAT_SPAN..=limit).boxed()
+                }
+                Rule::Maximum(limit) => {
+                    let limit = number_to_f64(limit);
+                    (limit..=limit + FLOAT_SPAN)
+                        .prop_filter("the value must be greater", move |value| *value > limit)
+                        .boxed()
+                }
+                Rule::ExclusiveMaximum(limit) => {
+                    let limit = number_to_f64(limit);
+                    (limit..=limit + FLOAT_SPAN).boxed()
+                }
+                Rule::MultipleOf(_) => {
+                    let m = float_multiple_of(std::slice::from_ref(rule))?;
+                    valid_float_strategy(&[])
+                        .prop_filter("the value must not be a multiple", move |value| {
+                            value % m != 0.0
+                        })
+                        .boxed()
+                }
+                Rule::Enumerate(_) => {
+                    let values = enumerated::<f64>(std::slice::from_ref(rule))?;
+                    valid_float_strategy(&[])
+                        .prop_filter("the value must not be enumerated", move |value| {
+                            !values.contains(value)
+                        })
+                        .boxed()
+                }
+                _ => return None,
+            };
+            Some(invalid(strategy, rule))
+        })
+        .collect()
+}
+
+macro_rules! impl_float_field_strategy {
+    ($($type:ty),*) => {
+        $(
+            impl FieldStrategy for $type {
+                fn valid_field_strategy(rules: &[Rule]) -> BoxedStrategy<Self> {
+                    valid_float_strategy(rules)
+                        .prop_map(|value| value as $type)
+                        .boxed()
+                }
+
+                fn invalid_field_strategy(rules: &[Rule]) -> Option<BoxedStrategy<Invalid<Self>>> {
+                    union(
+                        invalid_float_strategies(rules)
+                            .into_iter()
+                            .map(|strategy| {
+                                strategy
+                                    .prop_map(|invalid| invalid.map(|value| value as $type))
+                                    .boxed()
+                            })
+                            .collect(),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_float_field_strategy!(f32, f64);
+
+/// The characters of the generated strings, each of which is a grapheme.
+fn grapheme_char() -> impl Strategy<Value = char> {
+    proptest::char::ranges(Cow::Borrowed(&[
+        ' '..='~',
+        '\u{a0}'..='\u{2ff}',
+        '\u{3040}'..='\u{30ff}',
+        '\u{4e00}'..='\u{9fff}',
+    ]))
+}
+
+fn grapheme_string(min: usize, max: usize) -> BoxedStrategy<String> {
+    vec(grapheme_char(), min..=max)
+        .prop_map(|chars| chars.into_iter().collect())
+        .boxed()
+}
+
+fn grapheme_len(value: &str) -> usize {
+    unicode_segmentation::UnicodeSegmentation::graphemes(value, true).count()
+}
+
+/// The source of a pattern, which is kept in the `Debug` format.
+fn pattern_source(pattern: &Pattern) -> String {
+    let debug = pattern.to_string();
+    let quoted = debug
+        .strip_prefix("Regex(")
+        .and_then(|debug| debug.strip_suffix(')'))
+        .unwrap_or(&debug);
+    let quoted = quoted
+        .strip_prefix('"')
+        .and_then(|quoted| quoted.strip_suffix('"'))
+        .unwrap_or(quoted);
+
+    let mut source = String::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            source.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => source.push('\n'),
+            Some('r') => source.push('\r'),
+            Some('t') => source.push('\t'),
+            Some('0') => source.push('\0'),
+            Some('u') => {
+                let code = chars
+                    .by_ref()
+                    .skip(1)
+                    .take_while(|c| *c != '}')
+                    .collect::<String>();
+                source.extend(u32::from_str_radix(&code, 16).ok().and_then(char::from_u32));
+            }
+            Some(c) => source.push(c),
+            None => {}
+        }
+    }
+    source
+}
+
+/// Generate the strings of a pattern.
+///
+/// The anchors at the ends, which proptest does not support, are removed,
+/// because the strings generated from the rest match the anchored pattern too.
+fn pattern_strategy(source: &str) -> Option<BoxedStrategy<String>> {
+    let source = source
+        .strip_prefix('^')
+        .or_else(|| source.strip_prefix(r"\A"))
+        .unwrap_or(source);
+    let source = match source
+        .strip_suffix('$')
+        .or_else(|| source.strip_suffix(r"\z"))
+    {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => source,
+    };
+    proptest::string::string_regex(source)
+        .ok()
+        .map(Strategy::boxed)
+}
+
+fn string_patterns(rules: &[Rule]) -> Vec<regex::Regex> {
+    rules
+        .iter()
+        .filter_map(|rule| match rule {
+            Rule::Pattern(pattern) => regex::Regex::new(&pattern_source(pattern)).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Limits of the length of strings (or of the items of arrays).
+fn length_bounds(
+    rules: &[Rule],
+    min_of: fn(&Rule) -> Option<usize>,
+    max_of: fn(&Rule) -> Option<usize>,
+) -> (usize, usize) {
+    let min = rules.iter().filter_map(min_of).max().unwrap_or(0);
+    let max = rules.iter().filter_map(max_of).min().unwrap_or(min + 16);
+    (min, max)
+}
+
+fn string_length_bounds(rules: &[Rule]) -> (usize, usize) {
+    length_bounds(
+        rules,
+        |rule| match rule {
+            Rule::MinLength(min) => Some(*min),
+            _ => None,
+        },
+        |rule| match rule {
+            Rule::MaxLength(max) => Some(*max),
+            _ => None,
+        },
+    )
+}
+
+impl FieldStrategy for String {
+    fn valid_field_strategy(rules: &[Rule]) -> BoxedStrategy<Self> {
+        let (min, max) = string_length_bounds(rules);
+        let patterns = string_patterns(rules);
+        let satisfies = move |value: &String| {
+            (min..=max).contains(&grapheme_len(value))
+                && patterns.iter().all(|pattern| pattern.is_match(value))
+        };
+
+        if let Some(values) = enumerated::<String>(rules) {
+            let values = values.into_iter().filter(satisfies).collect::<Vec<_>>();
+            return match values.is_empty() {
+                true => nothing(),
+                false => select(values).boxed(),
+            };
+        }
+        if min > max {
+            return nothing();
+        }
+        let strategy = rules
+            .iter()
+            .find_map(|rule| match rule {
+                Rule::Pattern(pattern) => pattern_strategy(&pattern_source(pattern)),
+                _ => None,
+            })
+            .unwrap_or_else(|| grapheme_string(min, max));
+        strategy
+            .prop_filter("the value must satisfy the rules", satisfies)
+            .boxed()
+    }
+
+    fn invalid_field_strategy(rules: &[Rule]) -> Option<BoxedStrategy<Invalid<Self>>> {
+        let (min, max) = string_length_bounds(rules);
+        union(
+            rules
+                .iter()
+                .filter_map(|rule| {
+                    let strategy = match rule {
+                        Rule::MinLength(limit) if *limit > 0 => grapheme_string(0, limit - 1),
+                        Rule::MaxLength(limit) => grapheme_string(limit + 1, limit + 8),
+                        Rule::Pattern(_) => {
+                            let patterns = string_patterns(std::slice::from_ref(rule));
+                            grapheme_string(min, max.max(min))
+                                .prop_filter("the value must not match the pattern", move |value| {
+                                    !patterns.iter().all(|pattern| pattern.is_match(value))
+                                })
+                                .boxed()
+                        }
+                        Rule::Enumerate(_) => {
+                            let values = enumerated::<String>(std::slice::from_ref(rule))?;
+                            grapheme_string(min, max.max(min))
+                                .prop_filter("the value must not be enumerated", move |value| {
+                                    !values.contains(value)
+                                })
+                                .boxed()
+                        }
+                        _ => return None,
+                    };
+                    Some(invalid(strategy, rule))
+                })
+                .collect(),
+        )
+    }
+}
+
+fn is_array_rule(rule: &Rule) -> bool {
+    matches!(
+        rule,
+        Rule::MinItems(_) | Rule::MaxItems(_) | Rule::UniqueItems
+    )
+}
+
+fn items_bounds(rules: &[Rule]) -> (usize, usize) {
+    let (min, max) = length_bounds(
+        rules,
+        |rule| match rule {
+            Rule::MinItems(min) => Some(*min),
+            _ => None,
+        },
+        |rule| match rule {
+            Rule::MaxItems(max) => Some(*max),
+            _ => None,
+        },
+    );
+    (min, max.min(min + 4))
+}
+
+/// Whether the items are unique, distinguished by the `Debug` format.
+fn is_unique<T: Debug>(items: &[T]) -> bool {
+    let items = items
+        .iter()
+        .map(|item| format!("{item:?}"))
+        .collect::<Vec<_>>();
+    items
+        .iter()
+        .enumerate()
+        .all(|(index, item)| !items[..index].contains(item))
+}
+
+impl<T> FieldStrategy for Vec<T>
+where
+    T: FieldStrategy + Clone,
+{
+    fn valid_field_strategy(rules: &[Rule]) -> BoxedStrategy<Self> {
+        let (min, max) = items_bounds(rules);
+        let (array_rules, item_rules) = rules.iter().cloned().partition::<Vec<_>, _>(is_array_rule);
+        if min > max {
+            return nothing();
+        }
+        let strategy = vec(T::valid_field_strategy(&item_rules), min..=max);
+        match array_rules.contains(&Rule::UniqueItems) {
+            true => strategy
+                .prop_filter("the items must be unique", |items| is_unique(items))
+                .boxed(),
+            false => strategy.boxed(),
+        }
+    }
+
+    fn invalid_field_strategy(rules: &[Rule]) -> Option<BoxedStrategy<Invalid<Self>>> {
+        let (min, max) = items_bounds(rules);
+        let (array_rules, item_rules) = rules.iter().cloned().partition::<Vec<_>, _>(is_array_rule);
+        let items = || T::valid_field_strategy(&item_rules);
+
+        let mut strategies = array_rules
+            .iter()
+            .filter_map(|rule| {
+                let strategy = match rule {
+                    Rule::MinItems(limit) if *limit > 0 => vec(items(), 0..*limit).boxed(),
+                    Rule::MaxItems(limit) => vec(items(), limit + 1..=limit + 4).boxed(),
+                    Rule::UniqueItems => (vec(items(), min.max(1)..=max.max(1)), any_index())
+                        .prop_map(|(mut items, index)| {
+                            items.push(index.get(&items).clone());
+                            items
+                        })
+                        .boxed(),
+                    _ => return None,
+                };
+                Some(invalid(strategy, rule))
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(item) = T::invalid_field_strategy(&item_rules).filter(|_| max > 0) {
+            strategies.push(
+                (
+                    vec(items(), min.saturating_sub(1)..=max - 1),
+                    item,
+                    any_index(),
+                )
+                    .prop_map(|(mut items, item, index)| {
+                        let index = index.index(items.len() + 1);
+                        item.within(&index.to_string()).map(|item| {
+                            items.insert(index, item);
+                            items
+                        })
+                    })
+                    .boxed(),
+            );
+        }
+        union(strategies)
+    }
+}
+
+fn any_index() -> impl Strategy<Value = Index> {
+    proptest::arbitrary::any::<Index>()
+}
+
+impl<T> FieldStrategy for Option<T>
+where
+    T: FieldStrategy,
+{
+    fn valid_field_strategy(rules: &[Rule]) -> BoxedStrategy<Self> {
+        Union::new([
+            LazyJust::new(|| None).boxed(),
+            T::valid_field_strategy(rules).prop_map(Some).boxed(),
+        ])
+        .boxed()
+    }
+
+    fn invalid_field_strategy(rules: &[Rule]) -> Option<BoxedStrategy<Invalid<Self>>> {
+        Some(
+            T::invalid_field_strategy(rules)?
+                .prop_map(|invalid| invalid.map(Some))
+                .boxed(),
+        )
+    }
+}
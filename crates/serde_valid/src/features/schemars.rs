@@ -0,0 +1,49 @@
+//! Integration with [schemars](https://graham.cool/schemars/).
+//!
+//! With the `schemars` feature, `#[derive(Validate)]` also implements [`ValidateSchema`],
+//! and [`transform`] adds the keywords of the validations to the schema generated by schemars.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate, schemars::JsonSchema)]
+//! #[schemars(transform = serde_valid::schemars::transform::<Self>)]
+//! struct Data {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//!     #[validate(pattern = r"^\d+$")]
+//!     codes: Vec<String>,
+//! }
+//!
+//! let schema = schemars::schema_for!(Data);
+//!
+//! assert_eq!(schema.get("properties").unwrap()["val"]["maximum"], json!(10));
+//! assert_eq!(
+//!     schema.get("properties").unwrap()["codes"]["items"]["pattern"],
+//!     json!(r"^\d+$")
+//! );
+//! ```
+//!
+//! Only the validations of structs are added. The fields of enum variants are left
+//! without the keywords of their validations, since the validations of enums are not supported yet.
+
+use serde_json::Value;
+
+use crate::json_schema::apply_validation_keywords;
+
+pub use crate::json_schema::{KeywordLocation, ValidateSchema, ValidationKeyword};
+
+/// Add the keywords of the validations of `T` to its schema.
+///
+/// Use it as `#[schemars(transform = serde_valid::schemars::transform::<Self>)]`.
+pub fn transform<T>(schema: &mut schemars::Schema)
+where
+    T: ValidateSchema + ?Sized,
+{
+    let mut root = Value::Object(std::mem::take(schema.ensure_object()));
+    apply_validation_keywords::<T>(&mut root);
+    if let Value::Object(root) = root {
+        *schema.ensure_object() = root;
+    }
+}
@@ -20,6 +20,35 @@ where
     /// assert!(s.is_ok())
     /// ```
     fn from_toml_str(str: &'de str) -> Result<Self, crate::Error<serde_toml::de::Error>>;
+
+    /// Convert from toml str, and locate the errors in the source.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::toml::FromTomlStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 10)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let err = TestStruct::from_toml_str_with_spans("# config\nval = 12\n").unwrap_err();
+    ///
+    /// assert_eq!(err.to_string(), "/val at 2:7: The number must be `<= 10`.");
+    /// ```
+    fn from_toml_str_with_spans(
+        str: &'de str,
+    ) -> Result<Self, crate::span::SpannedError<serde_toml::de::Error>>;
+
+    /// Convert from toml str, with the options of the validation.
+    ///
+    /// See [`ValidateOptions`](crate::ValidateOptions).
+    fn from_toml_str_with_options(
+        str: &'de str,
+        options: &crate::ValidateOptions,
+    ) -> Result<Self, crate::Error<serde_toml::de::Error>>;
 }
 
 impl<'de, T> FromTomlStr<'de> for T
@@ -31,4 +60,25 @@ where
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_toml_str_with_spans(
+        str: &'de str,
+    ) -> Result<Self, crate::span::SpannedError<serde_toml::de::Error>> {
+        Self::from_toml_str(str).map_err(|error| {
+            crate::span::SpannedError::new(error, str, crate::span::locate_toml, |error| {
+                error.span().map(|span| crate::span::Span::new(str, span))
+            })
+        })
+    }
+
+    fn from_toml_str_with_options(
+        str: &'de str,
+        options: &crate::ValidateOptions,
+    ) -> Result<Self, crate::Error<serde_toml::de::Error>> {
+        let model = T::deserialize(serde_toml::Deserializer::new(str))?;
+        model
+            .validate_with_options(options)
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
 }
@@ -0,0 +1,75 @@
+//! Integration with [utoipa](https://docs.rs/utoipa).
+//!
+//! With the `utoipa` feature, `#[derive(Validate)]` also implements [`ValidateSchema`],
+//! and [`apply`] adds the keywords of the validations to the component schema of a type.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//! use utoipa::OpenApi;
+//!
+//! #[derive(Validate, utoipa::ToSchema)]
+//! struct Data {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//!     #[validate(pattern = r"^\d+$")]
+//!     codes: Vec<String>,
+//! }
+//!
+//! #[derive(OpenApi)]
+//! #[openapi(components(schemas(Data)))]
+//! struct ApiDoc;
+//!
+//! let mut openapi = ApiDoc::openapi();
+//! serde_valid::utoipa::apply::<Data>(&mut openapi).unwrap();
+//!
+//! let schema = serde_json::to_value(&openapi.components.unwrap().schemas["Data"]).unwrap();
+//! assert_eq!(schema["properties"]["val"]["maximum"], json!(10));
+//! assert_eq!(schema["properties"]["codes"]["items"]["pattern"], json!(r"^\d+$"));
+//! ```
+//!
+//! The schemas of nested types are separate components, so apply each of them,
+//! e.g. in a [`Modify`](utoipa::Modify) of the `OpenApi`.
+//!
+//! Only the validations of structs are added. The fields of enum variants are left
+//! without the keywords of their validations, since the validations of enums are not supported yet.
+
+use utoipa::openapi::{OpenApi, RefOr, Schema};
+
+use crate::json_schema::apply_validation_keywords;
+
+pub use crate::json_schema::{KeywordLocation, ValidateSchema, ValidationKeyword};
+
+/// Add the keywords of the validations of `T` to the component schema of `T` in `openapi`.
+///
+/// See [`transform`] for the errors.
+pub fn apply<T>(openapi: &mut OpenApi) -> Result<(), serde_json::Error>
+where
+    T: ValidateSchema + utoipa::ToSchema,
+{
+    match openapi
+        .components
+        .as_mut()
+        .and_then(|components| components.schemas.get_mut(T::name().as_ref()))
+    {
+        Some(schema) => transform::<T>(schema),
+        None => Ok(()),
+    }
+}
+
+/// Add the keywords of the validations of `T` to its schema.
+///
+/// The schema is left as it is if it is a reference.
+/// Fails, leaving the schema as it is, if the schema with the keywords is not a utoipa schema.
+pub fn transform<T>(schema: &mut RefOr<Schema>) -> Result<(), serde_json::Error>
+where
+    T: ValidateSchema + ?Sized,
+{
+    let RefOr::T(inner) = schema else {
+        return Ok(());
+    };
+    let mut value = serde_json::to_value(&*inner)?;
+    apply_validation_keywords::<T>(&mut value);
+    *inner = serde_json::from_value(value)?;
+    Ok(())
+}
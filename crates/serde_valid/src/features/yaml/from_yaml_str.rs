@@ -20,6 +20,35 @@ where
     /// assert!(s.is_ok())
     /// ```
     fn from_yaml_str(str: &'de str) -> Result<Self, crate::Error<serde_yaml::Error>>;
+
+    /// Convert from yaml str, and locate the errors in the source.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::yaml::FromYamlStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 10)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let err = TestStruct::from_yaml_str_with_spans("---\nval: 12\n").unwrap_err();
+    ///
+    /// assert_eq!(err.to_string(), "/val at 2:6: The number must be `<= 10`.");
+    /// ```
+    fn from_yaml_str_with_spans(
+        str: &'de str,
+    ) -> Result<Self, crate::span::SpannedError<serde_yaml::Error>>;
+
+    /// Convert from yaml str, with the options of the validation.
+    ///
+    /// See [`ValidateOptions`](crate::ValidateOptions).
+    fn from_yaml_str_with_options(
+        str: &'de str,
+        options: &crate::ValidateOptions,
+    ) -> Result<Self, crate::Error<serde_yaml::Error>>;
 }
 
 impl<'de, T> FromYamlStr<'de> for T
@@ -31,4 +60,26 @@ where
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_yaml_str_with_spans(
+        str: &'de str,
+    ) -> Result<Self, crate::span::SpannedError<serde_yaml::Error>> {
+        Self::from_yaml_str(str).map_err(|error| {
+            crate::span::SpannedError::new(error, str, crate::span::locate_yaml, |error| {
+                let location = error.location()?;
+                crate::span::Span::from_line_column(str, location.line(), location.column())
+            })
+        })
+    }
+
+    fn from_yaml_str_with_options(
+        str: &'de str,
+        options: &crate::ValidateOptions,
+    ) -> Result<Self, crate::Error<serde_yaml::Error>> {
+        let model: T = serde_yaml::from_str(str)?;
+        model
+            .validate_with_options(options)
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
 }
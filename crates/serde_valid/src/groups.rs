@@ -0,0 +1,86 @@
+use std::any::Any;
+
+/// A group of rules, selected by [`Validate::validate_groups`](crate::Validate::validate_groups).
+///
+/// Usually a fieldless enum, whose values are given by `#[validate(..., groups = [...])]`.
+///
+/// ```rust
+/// use serde_valid::{Validate, ValidationGroup};
+///
+/// #[derive(PartialEq)]
+/// enum Group {
+///     Create,
+///     Update,
+/// }
+///
+/// impl ValidationGroup for Group {}
+///
+/// #[derive(Validate)]
+/// struct User {
+///     #[validate(min_length = 12, groups = [Group::Create])]
+///     password: String,
+/// }
+///
+/// let user = User { password: "short".to_owned() };
+///
+/// assert!(user.validate_groups(&[Group::Update]).is_ok());
+/// assert!(user.validate_groups(&[Group::Create]).is_err());
+/// ```
+pub trait ValidationGroup: PartialEq + Send + Sync + 'static {}
+
+/// The selected groups of a validation, which can be of any [`ValidationGroup`] types.
+#[derive(Debug, Clone, Default)]
+pub struct Groups<'a> {
+    groups: Vec<&'a (dyn Any + Send + Sync)>,
+}
+
+impl<'a> Groups<'a> {
+    pub fn new<G: ValidationGroup>(groups: &'a [G]) -> Self {
+        Self {
+            groups: groups
+                .iter()
+                .map(|group| group as &(dyn Any + Send + Sync))
+                .collect(),
+        }
+    }
+
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn contains<G: ValidationGroup>(&self, group: &G) -> bool {
+        self.groups
+            .iter()
+            .any(|selected| selected.downcast_ref::<G>() == Some(group))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Group {
+        Create,
+        Update,
+    }
+
+    impl ValidationGroup for Group {}
+
+    #[derive(Debug, PartialEq)]
+    enum OtherGroup {
+        Create,
+    }
+
+    impl ValidationGroup for OtherGroup {}
+
+    #[test]
+    fn test_groups_contains() {
+        let groups = Groups::new(&[Group::Create]);
+
+        assert!(groups.contains(&Group::Create));
+        assert!(!groups.contains(&Group::Update));
+        assert!(!groups.contains(&OtherGroup::Create));
+        assert!(!Groups::none().contains(&Group::Create));
+    }
+}
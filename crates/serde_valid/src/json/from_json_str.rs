@@ -20,6 +20,35 @@ where
     /// assert!(s.is_ok())
     /// ```
     fn from_json_str(str: &'de str) -> Result<Self, crate::Error<serde_json::Error>>;
+
+    /// Convert from json str, and locate the errors in the source.
+    ///
+    /// ```rust
+    /// use serde::Deserialize;
+    /// use serde_valid::Validate;
+    /// use serde_valid::json::FromJsonStr;
+    ///
+    /// #[derive(Debug, Validate, Deserialize)]
+    /// struct TestStruct {
+    ///     #[validate(maximum = 10)]
+    ///     val: i32,
+    /// }
+    ///
+    /// let err = TestStruct::from_json_str_with_spans("{\n  \"val\": 12\n}").unwrap_err();
+    ///
+    /// assert_eq!(err.to_string(), "/val at 2:10: The number must be `<= 10`.");
+    /// ```
+    fn from_json_str_with_spans(
+        str: &'de str,
+    ) -> Result<Self, crate::span::SpannedError<serde_json::Error>>;
+
+    /// Convert from json str, with the options of the validation.
+    ///
+    /// See [`ValidateOptions`](crate::ValidateOptions).
+    fn from_json_str_with_options(
+        str: &'de str,
+        options: &crate::ValidateOptions,
+    ) -> Result<Self, crate::Error<serde_json::Error>>;
 }
 
 impl<'de, T> FromJsonStr<'de> for T
@@ -31,4 +60,25 @@ where
         model.validate().map_err(crate::Error::ValidationError)?;
         Ok(model)
     }
+
+    fn from_json_str_with_spans(
+        str: &'de str,
+    ) -> Result<Self, crate::span::SpannedError<serde_json::Error>> {
+        Self::from_json_str(str).map_err(|error| {
+            crate::span::SpannedError::new(error, str, crate::span::locate_json, |error| {
+                crate::span::Span::from_line_column(str, error.line(), error.column())
+            })
+        })
+    }
+
+    fn from_json_str_with_options(
+        str: &'de str,
+        options: &crate::ValidateOptions,
+    ) -> Result<Self, crate::Error<serde_json::Error>> {
+        let model: Self = serde_json::from_str(str)?;
+        model
+            .validate_with_options(options)
+            .map_err(crate::Error::ValidationError)?;
+        Ok(model)
+    }
 }
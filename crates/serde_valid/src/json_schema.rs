@@ -0,0 +1,405 @@
+//! JSON Schema ([draft 2020-12](https://json-schema.org/draft/2020-12/schema)) generation.
+//!
+//! `#[derive(JsonSchema)]` reads the same `#[validate(...)]` attributes as `#[derive(Validate)]`,
+//! so the schema never drifts from the validations.
+//!
+//! - Field validations become keywords of the property (e.g. `#[validate(max_length = 4)]` → `"maxLength": 4`).
+//!   Keywords of the items (e.g. `maximum`) are applied to `items` of array fields, as serde_valid does.
+//! - Derived types are placed in `$defs` and referenced with `$ref`.
+//! - Enums become `oneOf` (`anyOf` for `#[serde(untagged)]`), following the serde tagging.
+//! - Serde `rename`, `rename_all`, `skip`, `default`, `flatten` and `deny_unknown_fields` are respected.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//! use serde_valid::json_schema::JsonSchema;
+//!
+//! #[derive(Validate, JsonSchema)]
+//! struct Data {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//!     #[validate(max_length = 4)]
+//!     names: Vec<String>,
+//!     #[validate]
+//!     child: Option<Child>,
+//! }
+//!
+//! #[derive(Validate, JsonSchema)]
+//! struct Child {
+//!     #[validate(pattern = r"^\d+$")]
+//!     code: String,
+//! }
+//!
+//! assert_eq!(
+//!     Data::json_schema(),
+//!     json!({
+//!         "$schema": "https://json-schema.org/draft/2020-12/schema",
+//!         "type": "object",
+//!         "properties": {
+//!             "val": { "type": "integer", "maximum": 10 },
+//!             "names": { "type": "array", "items": { "type": "string", "maxLength": 4 } },
+//!             "child": { "anyOf": [{ "$ref": "#/$defs/Child" }, { "type": "null" }] }
+//!         },
+//!         "required": ["val", "names"],
+//!         "$defs": {
+//!             "Child": {
+//!                 "type": "object",
+//!                 "properties": {
+//!                     "code": { "type": "string", "pattern": r"^\d+$" }
+//!                 },
+//!                 "required": ["code"]
+//!             }
+//!         }
+//!     })
+//! );
+//! ```
+
+mod impls;
+
+use std::borrow::Cow;
+
+use serde_json::{json, Map, Value};
+
+pub use serde_valid_derive::JsonSchema;
+
+/// URI of the JSON Schema dialect of the generated schemas.
+pub const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Schemas of the derived types, keyed by [`JsonSchema::schema_name`].
+pub type Definitions = Map<String, Value>;
+
+/// A type that can describe itself as a JSON Schema.
+pub trait JsonSchema {
+    /// `true` if the property of this type may be absent from its object.
+    const OPTIONAL: bool = false;
+
+    /// Name of the schema in `$defs`.
+    fn schema_name() -> Cow<'static, str>;
+
+    /// The schema of this type, with the schemas of the other types in `definitions`.
+    fn json_schema_object(definitions: &mut Definitions) -> Value;
+
+    /// The schema to use where this type is a part of another schema.
+    ///
+    /// Derived types return a `$ref` to `#/$defs/{schema_name}`.
+    fn json_subschema(definitions: &mut Definitions) -> Value {
+        Self::json_schema_object(definitions)
+    }
+
+    /// The root schema of this type, with `$schema` and `$defs`.
+    fn json_schema() -> Value
+    where
+        Self: Sized,
+    {
+        let mut definitions = Definitions::new();
+        let mut schema = Self::json_schema_object(&mut definitions);
+        if let Value::Object(object) = &mut schema {
+            let mut root = Map::new();
+            root.insert("$schema".to_string(), Value::from(DRAFT_2020_12));
+            root.append(object);
+            if !definitions.is_empty() {
+                root.insert("$defs".to_string(), Value::Object(definitions));
+            }
+            *object = root;
+        }
+        schema
+    }
+}
+
+/// Place the schema of `T` in `definitions`, and return a reference to it.
+pub fn reference<T>(definitions: &mut Definitions) -> Value
+where
+    T: JsonSchema + ?Sized,
+{
+    let name = T::schema_name().into_owned();
+    if !definitions.contains_key(&name) {
+        // Insert a placeholder first, so that recursive types terminate.
+        definitions.insert(name.clone(), Value::Bool(true));
+        let schema = T::json_schema_object(definitions);
+        definitions.insert(name.clone(), schema);
+    }
+    json!({ "$ref": format!("#/$defs/{name}") })
+}
+
+/// Apply a validation keyword to a schema.
+///
+/// As the validations of serde_valid, the keywords of the items (e.g. `maximum`, `maxLength`)
+/// are applied to `items` of arrays, and the keywords are applied to the non-null branch of options.
+pub fn apply_keyword(schema: &mut Value, keyword: &str, mut value: Value) {
+    let Value::Object(object) = schema else {
+        return;
+    };
+
+    if let Some(Value::Array(any_of)) = object.get_mut("anyOf") {
+        if any_of.iter().any(is_null_schema) {
+            for branch in any_of.iter_mut().filter(|branch| !is_null_schema(branch)) {
+                apply_keyword(branch, keyword, value.clone());
+            }
+            return;
+        }
+    }
+
+    let is_item_keyword = !matches!(
+        keyword,
+        "minItems" | "maxItems" | "uniqueItems" | "minProperties" | "maxProperties"
+    );
+    if is_item_keyword && has_type(object, "array") {
+        if let Some(items) = object.get_mut("items") {
+            apply_keyword(items, keyword, value);
+            return;
+        }
+    }
+
+    if keyword == "enum" && has_type(object, "null") {
+        // `enumerate` does not validate `None` of options.
+        if let Value::Array(values) = &mut value {
+            values.push(Value::Null);
+        }
+    }
+    object.insert(keyword.to_string(), value);
+}
+
+/// Add a `description` to a schema.
+pub fn describe(schema: &mut Value, description: &str) {
+    if let Value::Object(object) = schema {
+        object.insert("description".to_string(), Value::from(description));
+    }
+}
+
+/// A type whose validations can be described as JSON Schema keywords,
+/// to add them to the schemas of the other generators (e.g. schemars, utoipa).
+///
+/// This trait is implemented by `#[derive(Validate)]` with the `schemars` or `utoipa` feature.
+pub trait ValidateSchema {
+    /// The keywords of the field validations.
+    fn validation_keywords() -> Vec<ValidationKeyword>;
+}
+
+/// A JSON Schema keyword of a field validation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationKeyword {
+    pub location: KeywordLocation,
+    pub keyword: &'static str,
+    pub value: Value,
+}
+
+/// The subschema to which a keyword is added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordLocation {
+    /// The schema itself, for the field of a new type struct.
+    Root,
+
+    /// `properties/{name}`, for the fields of a named struct.
+    Property(&'static str),
+
+    /// `prefixItems/{index}`, for the fields of a tuple struct.
+    PrefixItem(usize),
+}
+
+/// Add the keywords of the validations of `T` to its schema.
+pub fn apply_validation_keywords<T>(schema: &mut Value)
+where
+    T: ValidateSchema + ?Sized,
+{
+    for ValidationKeyword {
+        location,
+        keyword,
+        value,
+    } in T::validation_keywords()
+    {
+        let subschema = match location {
+            KeywordLocation::Root => Some(&mut *schema),
+            KeywordLocation::Property(name) => schema
+                .get_mut("properties")
+                .and_then(|properties| properties.get_mut(name)),
+            KeywordLocation::PrefixItem(index) => schema
+                .get_mut("prefixItems")
+                .and_then(|items| items.get_mut(index)),
+        };
+        if let Some(subschema) = subschema {
+            apply_keyword(subschema, keyword, value);
+        }
+    }
+}
+
+fn is_null_schema(schema: &Value) -> bool {
+    schema.get("type") == Some(&Value::from("null"))
+}
+
+fn has_type(object: &Map<String, Value>, type_: &str) -> bool {
+    match object.get("type") {
+        Some(Value::String(value)) => value == type_,
+        Some(Value::Array(values)) => values.iter().any(|value| value == type_),
+        _ => false,
+    }
+}
+
+/// The schema of an object.
+pub fn object_schema(
+    properties: Map<String, Value>,
+    required: Vec<String>,
+    deny_unknown_fields: bool,
+) -> Value {
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), Value::from("object"));
+    schema.insert("properties".to_string(), Value::Object(properties));
+    if !required.is_empty() {
+        schema.insert("required".to_string(), Value::from(required));
+    }
+    if deny_unknown_fields {
+        schema.insert("additionalProperties".to_string(), Value::Bool(false));
+    }
+    Value::Object(schema)
+}
+
+/// The schema of a fixed length array.
+pub fn tuple_schema(items: Vec<Value>) -> Value {
+    let len = items.len();
+    json!({
+        "type": "array",
+        "prefixItems": items,
+        "minItems": len,
+        "maxItems": len,
+    })
+}
+
+/// Add the schemas of `#[serde(flatten)]` fields to an object schema.
+pub fn flatten_schema(schema: Value, flattened: Vec<Value>) -> Value {
+    if flattened.is_empty() {
+        return schema;
+    }
+    let mut all_of = vec![schema];
+    all_of.extend(flattened);
+    json!({ "allOf": all_of })
+}
+
+/// Representation of an enum, see <https://serde.rs/enum-representations.html>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tagging<'a> {
+    External,
+    Internal { tag: &'a str },
+    Adjacent { tag: &'a str, content: &'a str },
+    Untagged,
+}
+
+/// The schema of an enum variant.
+///
+/// `schema` is `None` for the unit variants.
+pub fn variant_schema(tagging: Tagging, name: &str, schema: Option<Value>) -> Value {
+    match (tagging, schema) {
+        (Tagging::External, None) => json!({ "const": name }),
+        (Tagging::External, Some(schema)) => json!({
+            "type": "object",
+            "properties": { name: schema },
+            "required": [name],
+            "additionalProperties": false,
+        }),
+        (Tagging::Internal { tag }, None) => json!({
+            "type": "object",
+            "properties": { tag: { "const": name } },
+            "required": [tag],
+        }),
+        (Tagging::Internal { tag }, Some(mut schema)) => {
+            let tag_schema = json!({ "const": name });
+            match schema.as_object_mut() {
+                Some(object) if has_type(object, "object") => {
+                    if let Some(Value::Object(properties)) = object.get_mut("properties") {
+                        properties.insert(tag.to_string(), tag_schema);
+                    }
+                    match object.get_mut("required") {
+                        Some(Value::Array(required)) => required.insert(0, Value::from(tag)),
+                        _ => {
+                            object.insert("required".to_string(), json!([tag]));
+                        }
+                    }
+                    schema
+                }
+                _ => json!({
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "properties": { tag: tag_schema },
+                            "required": [tag],
+                        },
+                        schema,
+                    ]
+                }),
+            }
+        }
+        (Tagging::Adjacent { tag, .. }, None) => json!({
+            "type": "object",
+            "properties": { tag: { "const": name } },
+            "required": [tag],
+        }),
+        (Tagging::Adjacent { tag, content }, Some(schema)) => json!({
+            "type": "object",
+            "properties": { tag: { "const": name }, content: schema },
+            "required": [tag, content],
+        }),
+        (Tagging::Untagged, None) => json!({ "type": "null" }),
+        (Tagging::Untagged, Some(schema)) => schema,
+    }
+}
+
+/// The schema of an enum from the schemas of its variants.
+pub fn enum_schema(tagging: Tagging, variants: Vec<Value>) -> Value {
+    match tagging {
+        Tagging::Untagged => json!({ "anyOf": variants }),
+        _ => json!({ "oneOf": variants }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_keyword_to_items() {
+        let mut schema = json!({
+            "anyOf": [
+                { "type": "array", "items": { "type": "integer" } },
+                { "type": "null" }
+            ]
+        });
+
+        apply_keyword(&mut schema, "maximum", json!(10));
+        apply_keyword(&mut schema, "maxItems", json!(3));
+
+        assert_eq!(
+            schema,
+            json!({
+                "anyOf": [
+                    {
+                        "type": "array",
+                        "items": { "type": "integer", "maximum": 10 },
+                        "maxItems": 3
+                    },
+                    { "type": "null" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn internal_tagged_variant_schema() {
+        assert_eq!(
+            variant_schema(
+                Tagging::Internal { tag: "type" },
+                "Circle",
+                Some(object_schema(
+                    Map::from_iter([("radius".to_string(), json!({ "type": "number" }))]),
+                    vec!["radius".to_string()],
+                    false,
+                )),
+            ),
+            json!({
+                "type": "object",
+                "properties": {
+                    "radius": { "type": "number" },
+                    "type": { "const": "Circle" }
+                },
+                "required": ["type", "radius"]
+            })
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::borrow::Cow;
+
+use serde_json::{json, Value};
+
+use super::{tuple_schema, Definitions, JsonSchema};
+
+macro_rules! impl_json_schema {
+    ($schema:tt, $($type:ty),*) => {
+        $(
+            impl JsonSchema for $type {
+                fn schema_name() -> Cow<'static, str> {
+                    Cow::Borrowed(stringify!($type))
+                }
+
+                fn json_schema_object(_definitions: &mut Definitions) -> Value {
+                    json!($schema)
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema!({ "type": "boolean" }, bool);
+impl_json_schema!({ "type": "integer" }, i8, i16, i32, i64, isize);
+impl_json_schema!(
+    { "type": "integer", "minimum": 0 },
+    u8,
+    u16,
+    u32,
+    u64,
+    usize
+);
+#[cfg(feature = "i128")]
+impl_json_schema!({ "type": "integer" }, i128);
+#[cfg(feature = "i128")]
+impl_json_schema!({ "type": "integer", "minimum": 0 }, u128);
+impl_json_schema!(
+    { "type": "integer", "minimum": 1 },
+    std::num::NonZeroU8,
+    std::num::NonZeroU16,
+    std::num::NonZeroU32,
+    std::num::NonZeroU64,
+    std::num::NonZeroUsize
+);
+impl_json_schema!({ "type": "number" }, f32, f64);
+impl_json_schema!({ "type": "string" }, str, String, std::path::Path, std::path::PathBuf);
+impl_json_schema!({ "type": "string", "minLength": 1, "maxLength": 1 }, char);
+impl_json_schema!({ "type": "null" }, ());
+impl_json_schema!({}, serde_json::Value);
+
+macro_rules! impl_json_schema_for_wrapper {
+    ($($type:ty),*) => {
+        $(
+            impl<T> JsonSchema for $type
+            where
+                T: JsonSchema + ?Sized,
+            {
+                const OPTIONAL: bool = T::OPTIONAL;
+
+                fn schema_name() -> Cow<'static, str> {
+                    T::schema_name()
+                }
+
+                fn json_schema_object(definitions: &mut Definitions) -> Value {
+                    T::json_schema_object(definitions)
+                }
+
+                fn json_subschema(definitions: &mut Definitions) -> Value {
+                    T::json_subschema(definitions)
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema_for_wrapper!(&T, Box<T>, std::rc::Rc<T>, std::sync::Arc<T>);
+
+impl<T> JsonSchema for Cow<'_, T>
+where
+    T: JsonSchema + ToOwned + ?Sized,
+{
+    const OPTIONAL: bool = T::OPTIONAL;
+
+    fn schema_name() -> Cow<'static, str> {
+        T::schema_name()
+    }
+
+    fn json_schema_object(definitions: &mut Definitions) -> Value {
+        T::json_schema_object(definitions)
+    }
+
+    fn json_subschema(definitions: &mut Definitions) -> Value {
+        T::json_subschema(definitions)
+    }
+}
+
+impl<T> JsonSchema for Option<T>
+where
+    T: JsonSchema,
+{
+    const OPTIONAL: bool = true;
+
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Owned(format!("Nullable_{}", T::schema_name()))
+    }
+
+    fn json_schema_object(definitions: &mut Definitions) -> Value {
+        json!({ "anyOf": [T::json_subschema(definitions), { "type": "null" }] })
+    }
+}
+
+macro_rules! impl_json_schema_for_array {
+    ($unique_items:literal, $($type:ty),*) => {
+        $(
+            impl<T> JsonSchema for $type
+            where
+                T: JsonSchema,
+            {
+                fn schema_name() -> Cow<'static, str> {
+                    Cow::Owned(format!("Array_of_{}", T::schema_name()))
+                }
+
+                fn json_schema_object(definitions: &mut Definitions) -> Value {
+                    let mut schema = json!({ "type": "array", "items": T::json_subschema(definitions) });
+                    if $unique_items {
+                        schema["uniqueItems"] = Value::Bool(true);
+                    }
+                    schema
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema_for_array!(false, [T], Vec<T>, std::collections::VecDeque<T>);
+impl_json_schema_for_array!(
+    true,
+    std::collections::HashSet<T>,
+    std::collections::BTreeSet<T>
+);
+
+impl<T, const N: usize> JsonSchema for [T; N]
+where
+    T: JsonSchema,
+{
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Owned(format!("Array_of_{}_{N}", T::schema_name()))
+    }
+
+    fn json_schema_object(definitions: &mut Definitions) -> Value {
+        json!({
+            "type": "array",
+            "items": T::json_subschema(definitions),
+            "minItems": N,
+            "maxItems": N,
+        })
+    }
+}
+
+macro_rules! impl_json_schema_for_map {
+    ($($type:ty),*) => {
+        $(
+            impl<K, V> JsonSchema for $type
+            where
+                V: JsonSchema,
+            {
+                fn schema_name() -> Cow<'static, str> {
+                    Cow::Owned(format!("Map_of_{}", V::schema_name()))
+                }
+
+                fn json_schema_object(definitions: &mut Definitions) -> Value {
+                    json!({
+                        "type": "object",
+                        "additionalProperties": V::json_subschema(definitions),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema_for_map!(
+    std::collections::HashMap<K, V>,
+    std::collections::BTreeMap<K, V>,
+    indexmap::IndexMap<K, V>
+);
+
+macro_rules! impl_json_schema_for_tuple {
+    ($($type:ident),+) => {
+        impl<$($type),+> JsonSchema for ($($type,)+)
+        where
+            $($type: JsonSchema),+
+        {
+            fn schema_name() -> Cow<'static, str> {
+                let names: Vec<String> = vec![$($type::schema_name().into_owned()),+];
+                Cow::Owned(format!("Tuple_of_{}", names.join("_and_")))
+            }
+
+            fn json_schema_object(definitions: &mut Definitions) -> Value {
+                tuple_schema(vec![$($type::json_subschema(definitions)),+])
+            }
+        }
+    };
+}
+
+impl_json_schema_for_tuple!(T1);
+impl_json_schema_for_tuple!(T1, T2);
+impl_json_schema_for_tuple!(T1, T2, T3);
+impl_json_schema_for_tuple!(T1, T2, T3, T4);
+impl_json_schema_for_tuple!(T1, T2, T3, T4, T5);
+impl_json_schema_for_tuple!(T1, T2, T3, T4, T5, T6);
+impl_json_schema_for_tuple!(T1, T2, T3, T4, T5, T6, T7);
+impl_json_schema_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8);
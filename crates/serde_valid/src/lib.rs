@@ -41,6 +41,11 @@
 //! - `yaml` - provide serialization/deserialization in `yaml` format.
 //! - `i128` - support `i128`/`u128` type (default).
 //! - `fluent` - provide localization using [fluent](https://projectfluent.org/).
+//! - `colored` - provide colored terminal output of validation errors.
+//! - `proptest` - generate valid and invalid instances with [proptest](https://docs.rs/proptest).
+//! - `schemars` - add the validations to the JSON Schemas of [schemars](https://graham.cool/schemars/).
+//! - `utoipa` - add the validations to the OpenAPI component schemas of [utoipa](https://docs.rs/utoipa).
+//! - `async` - provide `AsyncValidate` with asynchronous custom validations.
 //!
 //! ## Validations
 //!
@@ -335,6 +340,9 @@
 //! assert!(s.validate().is_ok());
 //! ```
 //!
+//! `message` and `message_fn` replace each error of custom validations,
+//! where `message_fn` takes the error returned by the validation.
+//!
 //! ### Multi Fields Validation
 //! Now, you can use `#[validate(custom = ...)]` for multi fields validation.
 //!
@@ -361,6 +369,314 @@
 //! assert!(s.validate().is_ok());
 //! ```
 //!
+//! The validator of multi fields validation can also be a function call, e.g. `#[validate(custom = check_limits(10, 20))]`,
+//! which returns the closure taking the struct or the enum.
+//!
+//! With [`FieldErrors`](crate::validation::FieldErrors), the errors of multi fields validation are attached to the fields,
+//! where the names of the fields are renamed by `#[serde(rename = ...)]`.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::validation::{Error, FieldErrors};
+//! use serde_valid::Validate;
+//!
+//! fn period_validation(period: &Period) -> Result<(), FieldErrors> {
+//!     if period.end <= period.start {
+//!         Err(FieldErrors::new().at("end", Error::Custom("end must be after start.".to_owned())))
+//!     } else {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! #[validate(custom = period_validation)]
+//! struct Period {
+//!     start: i32,
+//!     end: i32,
+//! }
+//!
+//! assert_eq!(
+//!     Period { start: 2, end: 1 }.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "end": { "errors": ["end must be after start."] }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! `.at_path("/items/3/price", error)` attaches the error to the nested value by JSON Pointer.
+//!
+//! ### Custom Rules
+//! Implementing `serde_valid::validation::ValidationRule`, you can define your own keyword,
+//! used as `#[validate(rule(Name))]` or `#[validate(rule(Name = param))]`.
+//! Like the built-in validations, the rules support `message`, `message_fn` and `fluent`,
+//! and validate the items of `Vec` and `Option` values.
+//!
+//! ```rust
+//! use serde_valid::validation::error::FormatDefault;
+//! use serde_valid::validation::ValidationRule;
+//! use serde_valid::Validate;
+//!
+//! struct Slug;
+//!
+//! #[derive(Clone, serde::Serialize)]
+//! struct SlugError;
+//!
+//! impl FormatDefault for SlugError {
+//!     fn format_default(&self) -> String {
+//!         "The value must be a slug.".to_owned()
+//!     }
+//! }
+//!
+//! impl ValidationRule for Slug {
+//!     type Value = str;
+//!     type Param = ();
+//!     type Error = SlugError;
+//!
+//!     const NAME: &'static str = "slug";
+//!
+//!     fn validate(value: &str, _: &()) -> Result<(), SlugError> {
+//!         if value.chars().all(|c| c.is_ascii_lowercase() || c == '-') {
+//!             Ok(())
+//!         } else {
+//!             Err(SlugError)
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! struct Data {
+//!     #[validate(rule(Slug), message = "tags must be slugs.")]
+//!     tags: Vec<String>,
+//! }
+//!
+//! let s = Data {
+//!     tags: vec!["rust".to_owned(), "serde-valid".to_owned()],
+//! };
+//!
+//! assert!(s.validate().is_ok());
+//! ```
+//!
+//! The errors of the rules are the new `validation::Error::Rule` variant, whose code is the name of the rule.
+//! This is a breaking change for the exhaustive `match` expressions over `validation::Error`,
+//! which need an arm of `Error::Rule`.
+//!
+//! ### Rule Sets
+//! With `#[derive(RuleSet)]` on a unit struct, you can name a set of validations once
+//! and apply it to fields with `#[validate(rules = Name)]`.
+//! This works the same as writing the attributes on the field,
+//! and `#[validate(rules = Name, message = ...)]` overrides the messages of the set.
+//!
+//! The paths in the attributes are resolved where the set is used,
+//! so use `crate::...` paths for the items of the crate defining the set.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::{RuleSet, Validate};
+//!
+//! #[derive(RuleSet)]
+//! #[validate(min_length = 3)]
+//! #[validate(max_length = 32)]
+//! #[validate(pattern = r"^[a-z0-9_]+$")]
+//! pub struct Username;
+//!
+//! #[derive(Validate)]
+//! struct Data {
+//!     #[validate(rules = Username)]
+//!     name: String,
+//!     #[validate(rules = Username, message = "invalid username.")]
+//!     friends: Vec<String>,
+//! }
+//!
+//! let s = Data {
+//!     name: "user_1".to_owned(),
+//!     friends: vec!["User 2".to_owned()],
+//! };
+//!
+//! assert_eq!(
+//!     s.validate().unwrap_err().to_string(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "friends": {
+//!                 "errors": [],
+//!                 "items": {
+//!                     "0": { "errors": ["invalid username."] }
+//!                 }
+//!             }
+//!         }
+//!     })
+//!     .to_string()
+//! );
+//! ```
+//!
+//! ### Validation Context
+//! With `#[validate(context = ...)]`, custom validations take runtime data as the second argument,
+//! and the type implements `ValidateWithContext` instead of `Validate`.
+//! The context is forwarded to the `#[validate]` fields.
+//!
+//! ```rust
+//! use serde_valid::{Validate, ValidateWithContext};
+//!
+//! struct AppContext {
+//!     tenant_ids: Vec<u32>,
+//! }
+//!
+//! fn known_tenant(tenant_id: &u32, context: &AppContext) -> Result<(), serde_valid::validation::Error> {
+//!     if context.tenant_ids.contains(tenant_id) {
+//!         Ok(())
+//!     } else {
+//!         Err(serde_valid::validation::Error::Custom("unknown tenant.".to_owned()))
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! #[validate(context = AppContext)]
+//! struct Data {
+//!     #[validate(custom = known_tenant)]
+//!     tenant_id: u32,
+//! }
+//!
+//! let context = AppContext { tenant_ids: vec![1, 2] };
+//!
+//! assert!(Data { tenant_id: 1 }.validate_with(&context).is_ok());
+//! assert!(Data { tenant_id: 3 }.validate_with(&context).is_err());
+//! ```
+//!
+//! ### Validation Groups
+//! Rules with `groups = [...]` run only with `validate_groups` selecting one of the groups,
+//! while the rules without groups always run.
+//! The groups are values of a type implementing `ValidationGroup`, usually a fieldless enum,
+//! and the selected groups are forwarded to the `#[validate]` fields.
+//!
+//! ```rust
+//! use serde_valid::{Validate, ValidationGroup};
+//!
+//! #[derive(PartialEq)]
+//! enum Group {
+//!     Create,
+//!     Update,
+//! }
+//!
+//! impl ValidationGroup for Group {}
+//!
+//! #[derive(Validate)]
+//! struct User {
+//!     #[validate(min_length = 12, groups = [Group::Create])]
+//!     password: String,
+//!     #[validate(max_length = 8)]
+//!     name: String,
+//! }
+//!
+//! let user = User {
+//!     password: "short".to_owned(),
+//!     name: "name".to_owned(),
+//! };
+//!
+//! assert!(user.validate().is_ok());
+//! assert!(user.validate_groups(&[Group::Update]).is_ok());
+//! assert!(user.validate_groups(&[Group::Create]).is_err());
+//! ```
+//!
+//! ### Conditional Validation
+//! Rules with `when = ...` run only when the condition over `self` holds,
+//! and `#[validate(skip_if = ...)]` skips all the rules of the field when the function returns `true`.
+//!
+//! ```rust
+//! use serde_valid::Validate;
+//!
+//! fn is_disabled(config: &Config) -> bool {
+//!     !config.enabled
+//! }
+//!
+//! #[derive(Validate)]
+//! struct Config {
+//!     enabled: bool,
+//!     short: bool,
+//!     #[validate(max_length = 4, when = self.short)]
+//!     name: String,
+//!     #[validate(skip_if = is_disabled)]
+//!     #[validate(minimum = 1)]
+//!     workers: u32,
+//! }
+//!
+//! let config = Config {
+//!     enabled: false,
+//!     short: false,
+//!     name: "long name".to_owned(),
+//!     workers: 0,
+//! };
+//!
+//! assert!(config.validate().is_ok());
+//! ```
+//!
+//! ### Validation Options
+//! `validate_with_options` stops the validation at the first error with `fail_fast`,
+//! or when the number of errors reaches `max_errors`, and selects the groups with `with_groups`.
+//! `ValidateWithContext::validate_options_with` and `AsyncValidate::validate_async_with_options`
+//! take the same options.
+//!
+//! ```rust
+//! use serde_valid::{Validate, ValidateOptions};
+//!
+//! #[derive(Validate)]
+//! struct Data {
+//!     #[validate(maximum = 10)]
+//!     values: Vec<i32>,
+//! }
+//!
+//! let data = Data { values: vec![11; 1000] };
+//!
+//! let errors = data.validate_with_options(&ValidateOptions::fail_fast()).unwrap_err();
+//!
+//! assert_eq!(errors.flatten().len(), 1);
+//! ```
+//!
+//! ### Async Validation
+//!
+//! <section class="warning">
+//! <code>async</code> feature is required.
+//! </section>
+//!
+//! `#[derive(Validate)]` implements `AsyncValidate` for the type with `#[validate(asynchronous)]`.
+//! `AsyncValidate::validate_async` runs the rules of `Validate`, and then awaits the
+//! `#[validate(custom_async = ...)]` validators and the `#[validate]` fields,
+//! whose types need `AsyncValidate` too.
+//! `validate_async_with_concurrency` bounds the number of the validations running at once
+//! across the fields and the items.
+//! `custom_async` takes the path of an `async fn`, whose future must be `Send`,
+//! so that the futures of `AsyncValidate` are `Send` too.
+//!
+//! ```rust
+//! # #[cfg(feature = "async")] {
+//! use serde_valid::{AsyncValidate, Validate};
+//!
+//! async fn username_not_taken(username: &str) -> Result<(), serde_valid::validation::Error> {
+//!     if username == "alice" {
+//!         Err(serde_valid::validation::Error::Custom("username is taken.".to_owned()))
+//!     } else {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[derive(Validate)]
+//! #[validate(asynchronous)]
+//! struct User {
+//!     #[validate(min_length = 3)]
+//!     #[validate(custom_async = username_not_taken)]
+//!     username: String,
+//! }
+//!
+//! let user = User { username: "alice".to_owned() };
+//!
+//! assert!(user.validate().is_ok());
+//! assert!(futures_executor::block_on(user.validate_async()).is_err());
+//! # }
+//! ```
+//!
 //! ## Validate Traits
 //!
 //! By implementing the validation trait, Your original type can uses Serde Valid validations.
@@ -573,11 +889,23 @@
 
 pub mod error;
 mod features;
+mod groups;
 pub mod json;
+pub mod json_schema;
+mod options;
+pub mod output;
+pub mod problem_details;
+pub mod rules;
+pub mod schema;
+pub mod span;
+pub mod testing;
 mod traits;
 pub mod utils;
+mod valid;
 pub mod validation;
 
+#[cfg(feature = "async")]
+pub use async_validate::AsyncValidate;
 pub use error::{
     EnumerateError, Error, ExclusiveMaximumError, ExclusiveMinimumError, MaxItemsError,
     MaxLengthError, MaxPropertiesError, MaximumError, MinItemsError, MinLengthError,
@@ -585,8 +913,12 @@ pub use error::{
 };
 #[allow(unused_imports)]
 pub use features::*;
+pub use groups::{Groups, ValidationGroup};
 use indexmap::IndexMap;
+pub use options::{ErrorLimit, ValidateOptions};
+pub use rules::RuleSet;
 use std::{borrow::Cow, collections::HashMap};
+pub use valid::Valid;
 pub use validation::{
     ValidateEnumerate, ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaxItems,
     ValidateMaxLength, ValidateMaxProperties, ValidateMaximum, ValidateMinItems, ValidateMinLength,
@@ -598,120 +930,269 @@ pub mod export {
     #[cfg(feature = "fluent")]
     pub use fluent;
     pub use once_cell;
+    #[cfg(feature = "proptest")]
+    pub use proptest;
     pub use regex;
 }
 
 pub trait Validate {
     fn validate(&self) -> std::result::Result<(), self::validation::Errors>;
-}
 
-impl<T> Validate for Vec<T>
-where
-    T: Validate,
-{
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
+    /// Validate with the rules of the given groups, as well as the rules without groups.
+    ///
+    /// The rules of a group are given by `#[validate(..., groups = [...])]`,
+    /// and the groups are forwarded to the `#[validate]` fields.
+    fn validate_groups<G: ValidationGroup>(
+        &self,
+        groups: &[G],
+    ) -> std::result::Result<(), self::validation::Errors>
+    where
+        Self: Sized,
+    {
+        self.__validate_limited(&Groups::new(groups), &ErrorLimit::unlimited())
+    }
 
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
-            }
-        }
+    /// Validate with the limit of the number of errors, stopping the validation when it is reached,
+    /// and with the selected groups.
+    ///
+    /// See [`ValidateOptions`].
+    fn validate_with_options(
+        &self,
+        options: &ValidateOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.__validate_limited(&options.groups, &ErrorLimit::new(options))
+    }
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Array(
-                validation::error::ArrayErrors::new(vec![], items),
-            ))
+    /// The validation of [`Validate::validate_groups`], counting the errors in `limit`.
+    #[doc(hidden)]
+    fn __validate_limited(
+        &self,
+        groups: &Groups<'_>,
+        limit: &ErrorLimit,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        let _ = groups;
+        let result = self.validate();
+        if let Err(errors) = &result {
+            limit.add_errors(errors);
         }
+        result
+    }
+
+    /// The rules of the type, for the links of `#[validate]` fields in [`rules::FieldRules`],
+    /// which `#[derive(Validate)]` gives.
+    #[doc(hidden)]
+    fn __validate_rules() -> Option<rules::TypeRules>
+    where
+        Self: Sized,
+    {
+        None
     }
 }
 
-impl<T, const N: usize> Validate for [T; N]
-where
-    T: Validate,
-{
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
+/// Validation with runtime data, which is passed to the custom validations as the second argument.
+///
+/// `#[derive(Validate)]` with `#[validate(context = C)]` implements `ValidateWithContext<C>`,
+/// and the other derived types implement it for every context, ignoring the context,
+/// so that they can be `#[validate]` fields of the types with a context.
+pub trait ValidateWithContext<C: ?Sized> {
+    fn validate_with(&self, context: &C) -> std::result::Result<(), self::validation::Errors>;
 
-        for (index, item) in self.iter().enumerate() {
-            if let Err(errors) = item.validate() {
-                items.insert(index, errors);
-            }
-        }
+    /// Validate with the rules of the given groups, as [`Validate::validate_groups`].
+    fn validate_groups_with<G: ValidationGroup>(
+        &self,
+        context: &C,
+        groups: &[G],
+    ) -> std::result::Result<(), self::validation::Errors>
+    where
+        Self: Sized,
+    {
+        self.__validate_limited_with(context, &Groups::new(groups), &ErrorLimit::unlimited())
+    }
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Array(
-                validation::error::ArrayErrors::new(vec![], items),
-            ))
+    /// Validate with the options, as [`Validate::validate_with_options`].
+    fn validate_options_with(
+        &self,
+        context: &C,
+        options: &ValidateOptions,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        self.__validate_limited_with(context, &options.groups, &ErrorLimit::new(options))
+    }
+
+    /// The validation of [`ValidateWithContext::validate_groups_with`], counting the errors in `limit`.
+    #[doc(hidden)]
+    fn __validate_limited_with(
+        &self,
+        context: &C,
+        groups: &Groups<'_>,
+        limit: &ErrorLimit,
+    ) -> std::result::Result<(), self::validation::Errors> {
+        let _ = groups;
+        let result = self.validate_with(context);
+        if let Err(errors) = &result {
+            limit.add_errors(errors);
         }
+        result
+    }
+
+    /// The rules of the type, for the links of `#[validate]` fields in [`rules::FieldRules`],
+    /// which `#[derive(Validate)]` gives.
+    #[doc(hidden)]
+    fn __validate_rules() -> Option<rules::TypeRules>
+    where
+        Self: Sized,
+    {
+        None
     }
 }
 
-impl<K, V> Validate for HashMap<K, V>
-where
-    V: Validate,
-    for<'a> &'a K: Into<String>,
-{
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
+/// `Validate` and `ValidateWithContext` of the containers,
+/// which validate their items with `$validate_items` over `self.$items()`.
+macro_rules! impl_validate_of_items {
+    ($(
+        impl[$($params:tt)*] $ty:ty where $item:ident {$($bounds:tt)*}
+            => $validate_items:ident($items:ident);
+    )*) => {
+        $(
+            impl<$($params)*> Validate for $ty
+            where
+                $item: Validate,
+                $($bounds)*
+            {
+                fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
+                    self.__validate_limited(&Groups::none(), &ErrorLimit::unlimited())
+                }
 
-        for (key, value) in self.iter() {
-            if let Err(errors) = value.validate() {
-                items.insert(Cow::from(key.into()), errors);
+                fn __validate_limited(
+                    &self,
+                    groups: &Groups<'_>,
+                    limit: &ErrorLimit,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    $validate_items(self.$items(), limit, |item, limit| {
+                        item.__validate_limited(groups, limit)
+                    })
+                }
+
+                fn __validate_rules() -> Option<rules::TypeRules> {
+                    <$item as Validate>::__validate_rules()
+                }
             }
-        }
 
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Object(
-                validation::error::ObjectErrors::new(vec![], items),
-            ))
+            impl<C: ?Sized, $($params)*> ValidateWithContext<C> for $ty
+            where
+                $item: ValidateWithContext<C>,
+                $($bounds)*
+            {
+                fn validate_with(
+                    &self,
+                    context: &C,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    self.__validate_limited_with(context, &Groups::none(), &ErrorLimit::unlimited())
+                }
+
+                fn __validate_limited_with(
+                    &self,
+                    context: &C,
+                    groups: &Groups<'_>,
+                    limit: &ErrorLimit,
+                ) -> std::result::Result<(), self::validation::Errors> {
+                    $validate_items(self.$items(), limit, |item, limit| {
+                        item.__validate_limited_with(context, groups, limit)
+                    })
+                }
+
+                fn __validate_rules() -> Option<rules::TypeRules> {
+                    <$item as ValidateWithContext<C>>::__validate_rules()
+                }
+            }
+        )*
+    };
+}
+
+impl_validate_of_items! {
+    impl[T] Vec<T> where T {} => validate_array_items(iter);
+    impl[T, const N: usize] [T; N] where T {} => validate_array_items(iter);
+    impl[K, V] HashMap<K, V> where V { for<'a> &'a K: Into<String>, } => validate_object_items(iter);
+    impl[K, V] IndexMap<K, V> where V { for<'a> &'a K: Into<String>, } => validate_object_items(iter);
+    impl[T] Option<T> where T {} => validate_option_item(as_ref);
+    impl[T] Box<T> where T {} => validate_item(as_ref);
+}
+
+fn validate_array_items<'a, T: 'a>(
+    items: impl Iterator<Item = &'a T>,
+    limit: &ErrorLimit,
+    mut validate: impl FnMut(&'a T, &ErrorLimit) -> std::result::Result<(), self::validation::Errors>,
+) -> std::result::Result<(), self::validation::Errors> {
+    let mut items_errors = IndexMap::new();
+
+    for (index, item) in items.enumerate() {
+        if limit.is_reached() {
+            break;
+        }
+        if let Err(errors) = validate(item, limit) {
+            items_errors.insert(index, errors);
         }
     }
+
+    if items_errors.is_empty() {
+        Ok(())
+    } else {
+        Err(self::validation::Errors::Array(
+            validation::ArrayErrors::new(vec![], items_errors),
+        ))
+    }
 }
 
-impl<K, V> Validate for IndexMap<K, V>
+fn validate_object_items<'a, K: 'a, V: 'a>(
+    items: impl Iterator<Item = (&'a K, &'a V)>,
+    limit: &ErrorLimit,
+    mut validate: impl FnMut(&'a V, &ErrorLimit) -> std::result::Result<(), self::validation::Errors>,
+) -> std::result::Result<(), self::validation::Errors>
 where
-    V: Validate,
-    for<'a> &'a K: Into<String>,
+    &'a K: Into<String>,
 {
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        let mut items = IndexMap::new();
+    let mut properties = IndexMap::new();
 
-        for (key, value) in self.iter() {
-            if let Err(errors) = value.validate() {
-                items.insert(Cow::from(key.into()), errors);
-            }
+    for (key, value) in items {
+        if limit.is_reached() {
+            break;
         }
-
-        if items.is_empty() {
-            Ok(())
-        } else {
-            Err(self::validation::Errors::Object(
-                validation::ObjectErrors::new(vec![], items),
-            ))
+        if let Err(errors) = validate(value, limit) {
+            properties.insert(Cow::from(key.into()), errors);
         }
     }
+
+    if properties.is_empty() {
+        Ok(())
+    } else {
+        Err(self::validation::Errors::Object(
+            validation::ObjectErrors::new(vec![], properties),
+        ))
+    }
 }
 
-impl<T> Validate for Option<T>
-where
-    T: Validate,
-{
-    fn validate(&self) -> std::result::Result<(), self::validation::Errors> {
-        match self {
-            Some(value) => value.validate(),
-            None => Ok(()),
-        }
+fn validate_option_item<'a, T>(
+    item: Option<&'a T>,
+    limit: &ErrorLimit,
+    validate: impl FnOnce(&'a T, &ErrorLimit) -> std::result::Result<(), self::validation::Errors>,
+) -> std::result::Result<(), self::validation::Errors> {
+    match item {
+        Some(item) => validate(item, limit),
+        None => Ok(()),
     }
 }
 
-pub use serde_valid_derive::Validate;
+fn validate_item<'a, T>(
+    item: &'a T,
+    limit: &ErrorLimit,
+    validate: impl FnOnce(&'a T, &ErrorLimit) -> std::result::Result<(), self::validation::Errors>,
+) -> std::result::Result<(), self::validation::Errors> {
+    validate(item, limit)
+}
+
+pub use serde_valid_derive::{RuleSet, Validate};
+
+#[doc(hidden)]
+pub use serde_valid_derive::__expand_rule_set;
 
 #[cfg(test)]
 pub mod tests {
@@ -0,0 +1,117 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::validation::Errors;
+use crate::{Groups, ValidationGroup};
+
+/// Options of [`Validate::validate_with_options`](crate::Validate::validate_with_options),
+/// [`ValidateWithContext::validate_options_with`](crate::ValidateWithContext::validate_options_with)
+/// and `AsyncValidate::validate_async_with_options`.
+///
+/// ```rust
+/// use serde_valid::{Validate, ValidateOptions};
+///
+/// #[derive(Validate)]
+/// struct Data {
+///     #[validate(maximum = 10)]
+///     values: Vec<i32>,
+/// }
+///
+/// let data = Data { values: vec![11; 100_000] };
+///
+/// let errors = data
+///     .validate_with_options(&ValidateOptions {
+///         max_errors: Some(50),
+///         ..Default::default()
+///     })
+///     .unwrap_err();
+///
+/// assert_eq!(errors.flatten().len(), 50);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ValidateOptions<'a> {
+    /// Stop at the first error.
+    pub fail_fast: bool,
+
+    /// Stop when the number of errors reaches this limit.
+    pub max_errors: Option<usize>,
+
+    /// The selected groups, as [`Validate::validate_groups`](crate::Validate::validate_groups).
+    pub groups: Groups<'a>,
+}
+
+impl<'a> ValidateOptions<'a> {
+    pub fn fail_fast() -> Self {
+        Self {
+            fail_fast: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn max_errors(max_errors: usize) -> Self {
+        Self {
+            max_errors: Some(max_errors),
+            ..Default::default()
+        }
+    }
+
+    /// Select the groups, as [`Validate::validate_groups`](crate::Validate::validate_groups).
+    pub fn with_groups<G: ValidationGroup>(self, groups: &'a [G]) -> Self {
+        Self {
+            groups: Groups::new(groups),
+            ..self
+        }
+    }
+}
+
+/// The number of errors found by the validation, and the limit given by [`ValidateOptions`].
+///
+/// The errors are counted through shared references,
+/// because the asynchronous validations run at once.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ErrorLimit {
+    max: Option<usize>,
+    count: AtomicUsize,
+}
+
+impl ErrorLimit {
+    pub fn new(options: &ValidateOptions) -> Self {
+        Self {
+            max: match options.fail_fast {
+                true => Some(1),
+                false => options.max_errors,
+            },
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self {
+            max: None,
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn add(&self, count: usize) {
+        self.count.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Count the errors, keeping only the ones within the limit.
+    pub fn take<E>(&self, mut errors: Vec<E>) -> Vec<E> {
+        let count = self.count.fetch_add(errors.len(), Ordering::Relaxed);
+        if let Some(max) = self.max {
+            errors.truncate(max.saturating_sub(count));
+        }
+        errors
+    }
+
+    /// Count the errors of a validation which does not count them itself.
+    pub fn add_errors(&self, errors: &Errors) {
+        self.add(errors.flatten().len());
+    }
+
+    pub fn is_reached(&self) -> bool {
+        self.max
+            .is_some_and(|max| self.count.load(Ordering::Relaxed) >= max)
+    }
+}
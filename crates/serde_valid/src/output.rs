@@ -0,0 +1,286 @@
+//! JSON Schema [output formats](https://json-schema.org/draft/2020-12/json-schema-core#name-output-formatting)
+//! of validation results.
+//!
+//! The `keywordLocation` of an error is built from the validation that raised it,
+//! e.g. `/properties/val/maximum`.
+//! The errors of the validations which are not JSON Schema keywords, e.g. `custom`,
+//! are located at the schema of the value, and have their code in `code`.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//! use serde_valid::output::{self, OutputFormat};
+//!
+//! #[derive(Validate)]
+//! struct Data {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//! }
+//!
+//! assert_eq!(
+//!     serde_json::to_value(output::evaluate(&Data { val: 12 }, OutputFormat::Basic)).unwrap(),
+//!     json!({
+//!         "valid": false,
+//!         "keywordLocation": "",
+//!         "instanceLocation": "",
+//!         "errors": [
+//!             {
+//!                 "valid": false,
+//!                 "keywordLocation": "/properties/val/maximum",
+//!                 "instanceLocation": "/val",
+//!                 "error": "The number must be `<= 10`."
+//!             }
+//!         ]
+//!     })
+//! );
+//! ```
+
+use crate::validation::error::escape_pointer_token;
+use crate::validation::{Errors, RemoteError};
+
+/// The structure of an [`Output`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Only the `valid` member.
+    Flag,
+
+    /// Flat list of the errors.
+    #[default]
+    Basic,
+
+    /// Errors nested by instance location, with single-child nodes collapsed.
+    Detailed,
+
+    /// Errors nested by instance location.
+    Verbose,
+}
+
+/// Result of a validation in one of the [`OutputFormat`]s.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(untagged)]
+pub enum Output {
+    Flag { valid: bool },
+    Unit(OutputUnit),
+}
+
+impl Output {
+    pub fn valid(&self) -> bool {
+        match self {
+            Self::Flag { valid } => *valid,
+            Self::Unit(unit) => unit.valid,
+        }
+    }
+}
+
+/// An output unit.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputUnit {
+    pub valid: bool,
+
+    pub keyword_location: String,
+
+    pub instance_location: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// The code of an error which is not raised by a JSON Schema keyword.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<OutputUnit>,
+}
+
+impl OutputUnit {
+    fn new(keyword_location: String, instance_location: String) -> Self {
+        Self {
+            valid: false,
+            keyword_location,
+            instance_location,
+            error: None,
+            code: None,
+            errors: vec![],
+        }
+    }
+}
+
+/// Validate the value and convert the result to the output format.
+pub fn evaluate<T>(value: &T, format: OutputFormat) -> Output
+where
+    T: crate::Validate + ?Sized,
+{
+    match value.validate() {
+        Ok(()) => match format {
+            OutputFormat::Flag => Output::Flag { valid: true },
+            _ => Output::Unit(OutputUnit {
+                valid: true,
+                ..OutputUnit::new(String::new(), String::new())
+            }),
+        },
+        Err(errors) => errors.to_output(format),
+    }
+}
+
+impl Errors<crate::validation::Error> {
+    /// Convert to the output format.
+    pub fn to_output(&self, format: OutputFormat) -> Output {
+        self.to_remote().to_output(format)
+    }
+}
+
+impl Errors<RemoteError> {
+    /// Convert to the output format.
+    ///
+    /// Errors without `code` or with a code which is not a JSON Schema keyword
+    /// are located at the schema of the value.
+    pub fn to_output(&self, format: OutputFormat) -> Output {
+        let mut root = OutputUnit::new(String::new(), String::new());
+        match format {
+            OutputFormat::Flag => return Output::Flag { valid: false },
+            OutputFormat::Basic => flatten_units(self, "", "", &mut root.errors),
+            OutputFormat::Detailed => root.errors = nested_units(self, "", "", true),
+            OutputFormat::Verbose => root.errors = nested_units(self, "", "", false),
+        }
+        Output::Unit(root)
+    }
+}
+
+fn error_unit(error: &RemoteError, keyword_location: &str, instance_location: &str) -> OutputUnit {
+    let (keyword_location, code) = match error.code.as_deref() {
+        Some(code) => match keyword(code) {
+            Some(keyword) => (format!("{keyword_location}/{keyword}"), None),
+            None => (keyword_location.to_string(), Some(code.to_string())),
+        },
+        None => (keyword_location.to_string(), None),
+    };
+    OutputUnit {
+        error: Some(error.message.clone()),
+        code,
+        ..OutputUnit::new(keyword_location, instance_location.to_string())
+    }
+}
+
+/// Locations of the children of an `Errors`.
+#[allow(clippy::type_complexity)]
+fn children<'a>(
+    errors: &'a Errors<RemoteError>,
+    keyword_location: &str,
+    instance_location: &str,
+) -> (
+    &'a Vec<RemoteError>,
+    Vec<(String, String, &'a Errors<RemoteError>)>,
+) {
+    match errors {
+        Errors::Array(array) => (
+            &array.errors,
+            array
+                .items
+                .iter()
+                .map(|(index, item)| {
+                    (
+                        format!("{keyword_location}/items"),
+                        format!("{instance_location}/{index}"),
+                        item,
+                    )
+                })
+                .collect(),
+        ),
+        Errors::Object(object) => (
+            &object.errors,
+            object
+                .properties
+                .iter()
+                .map(|(property, item)| {
+                    let property = escape_pointer_token(property);
+                    (
+                        format!("{keyword_location}/properties/{property}"),
+                        format!("{instance_location}/{property}"),
+                        item,
+                    )
+                })
+                .collect(),
+        ),
+        Errors::NewType(errors) => (errors, vec![]),
+    }
+}
+
+fn flatten_units(
+    errors: &Errors<RemoteError>,
+    keyword_location: &str,
+    instance_location: &str,
+    units: &mut Vec<OutputUnit>,
+) {
+    let (vec_errors, children) = children(errors, keyword_location, instance_location);
+    units.extend(
+        vec_errors
+            .iter()
+            .map(|error| error_unit(error, keyword_location, instance_location)),
+    );
+    for (keyword_location, instance_location, child) in children {
+        flatten_units(child, &keyword_location, &instance_location, units);
+    }
+}
+
+fn nested_units(
+    errors: &Errors<RemoteError>,
+    keyword_location: &str,
+    instance_location: &str,
+    condensed: bool,
+) -> Vec<OutputUnit> {
+    let (vec_errors, children) = children(errors, keyword_location, instance_location);
+    let mut units: Vec<OutputUnit> = vec_errors
+        .iter()
+        .map(|error| error_unit(error, keyword_location, instance_location))
+        .collect();
+
+    for (keyword_location, instance_location, child) in children {
+        let mut child_units = nested_units(child, &keyword_location, &instance_location, condensed);
+        if condensed && child_units.len() == 1 {
+            units.append(&mut child_units);
+        } else {
+            units.push(OutputUnit {
+                errors: child_units,
+                ..OutputUnit::new(keyword_location, instance_location)
+            });
+        }
+    }
+    units
+}
+
+/// JSON Schema keyword of an error code, if any.
+fn keyword(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "minimum" => "minimum",
+        "maximum" => "maximum",
+        "exclusive_minimum" => "exclusiveMinimum",
+        "exclusive_maximum" => "exclusiveMaximum",
+        "multiple_of" => "multipleOf",
+        "min_length" => "minLength",
+        "max_length" => "maxLength",
+        "pattern" => "pattern",
+        "min_items" => "minItems",
+        "max_items" => "maxItems",
+        "unique_items" => "uniqueItems",
+        "min_properties" => "minProperties",
+        "max_properties" => "maxProperties",
+        "enumerate" => "enum",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyword_from_code() {
+        assert_eq!(keyword("maximum"), Some("maximum"));
+        assert_eq!(keyword("exclusive_minimum"), Some("exclusiveMinimum"));
+        assert_eq!(keyword("unique_items"), Some("uniqueItems"));
+        assert_eq!(keyword("enumerate"), Some("enum"));
+        assert_eq!(keyword("custom"), None);
+        assert_eq!(keyword("fluent"), None);
+    }
+}
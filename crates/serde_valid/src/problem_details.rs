@@ -0,0 +1,153 @@
+//! Problem Details for HTTP APIs ([RFC 9457](https://www.rfc-editor.org/rfc/rfc9457)).
+//!
+//! Validation errors are converted to `422 Unprocessable Content`,
+//! and deserialize errors to `400 Bad Request`.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_json::json;
+//! use serde_valid::Validate;
+//! use serde_valid::json::FromJsonStr;
+//! use serde_valid::problem_details::ProblemDetails;
+//!
+//! #[derive(Debug, Validate, Deserialize)]
+//! struct Data {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//! }
+//!
+//! let err = Data::from_json_str(r#"{ "val": 12 }"#).unwrap_err();
+//!
+//! assert_eq!(
+//!     serde_json::to_value(ProblemDetails::from(&err)).unwrap(),
+//!     json!({
+//!         "type": "about:blank",
+//!         "title": "Unprocessable Content",
+//!         "status": 422,
+//!         "errors": [
+//!             { "pointer": "/val", "detail": "The number must be `<= 10`." }
+//!         ]
+//!     })
+//! );
+//! ```
+
+/// Media type of a problem document.
+pub const CONTENT_TYPE: &str = "application/problem+json";
+
+/// A problem document.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ProblemDetails {
+    /// URI reference that identifies the problem type.
+    #[serde(rename = "type", default = "default_type")]
+    pub type_: String,
+
+    pub title: String,
+
+    pub status: u16,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+
+    /// Extension member listing each violation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<Violation>,
+}
+
+/// A violation of a validation rule.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Violation {
+    /// JSON Pointer of the value. The root value has an empty pointer.
+    pub pointer: String,
+
+    pub detail: String,
+}
+
+fn default_type() -> String {
+    "about:blank".to_string()
+}
+
+impl ProblemDetails {
+    pub fn new(status: u16, title: impl Into<String>) -> Self {
+        Self {
+            type_: default_type(),
+            title: title.into(),
+            status,
+            detail: None,
+            instance: None,
+            errors: vec![],
+        }
+    }
+
+    pub fn with_type(mut self, type_: impl Into<String>) -> Self {
+        self.type_ = type_.into();
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn with_instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+}
+
+impl From<&crate::validation::Errors> for ProblemDetails {
+    fn from(errors: &crate::validation::Errors) -> Self {
+        Self {
+            errors: errors
+                .to_remote()
+                .flatten()
+                .into_iter()
+                .map(|(pointer, error)| Violation {
+                    pointer,
+                    detail: error.message.clone(),
+                })
+                .collect(),
+            ..Self::new(422, "Unprocessable Content")
+        }
+    }
+}
+
+impl From<crate::validation::Errors> for ProblemDetails {
+    fn from(errors: crate::validation::Errors) -> Self {
+        Self::from(&errors)
+    }
+}
+
+impl<E> From<&crate::Error<E>> for ProblemDetails
+where
+    E: 'static + std::error::Error,
+{
+    fn from(error: &crate::Error<E>) -> Self {
+        match error {
+            crate::Error::DeserializeError(error) => {
+                Self::new(400, "Bad Request").with_detail(error.to_string())
+            }
+            crate::Error::ValidationError(errors) => Self::from(errors),
+        }
+    }
+}
+
+impl<E> From<crate::Error<E>> for ProblemDetails
+where
+    E: 'static + std::error::Error,
+{
+    fn from(error: crate::Error<E>) -> Self {
+        Self::from(&error)
+    }
+}
+
+impl std::fmt::Display for ProblemDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_string(self) {
+            Ok(json_string) => write!(f, "{}", json_string),
+            Err(_) => Err(std::fmt::Error),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Runtime introspection of the validations.
+//!
+//! `#[derive(Validate)]` also implements [`ValidateRules`], which describes the `#[validate(...)]`
+//! attributes of a type as data, e.g. to render form hints or reference documents.
+//!
+//! - Fields are described by their serde-renamed keys (`"0"`, `"1"`, ... for unnamed fields).
+//!   Only the fields with `#[validate(...)]` attributes are listed.
+//! - The rules with a `when = ...` condition or with `groups = [...]` are left out,
+//!   since they do not always run.
+//! - Custom validators are described by the paths of their functions, and closures by `None`.
+//! - `#[validate]` fields link to the rules of the nested types with [`FieldRules::nested`],
+//!   which are `None` for the types implementing `Validate` by hand.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_valid::rules::{Rule, ValidateRules};
+//! use serde_valid::validation::Number;
+//! use serde_valid::Validate;
+//!
+//! #[derive(Deserialize, Validate)]
+//! #[serde(rename_all = "camelCase")]
+//! struct Data {
+//!     #[validate(max_length = 64)]
+//!     user_name: String,
+//!     #[validate]
+//!     child: Child,
+//! }
+//!
+//! #[derive(Deserialize, Validate)]
+//! struct Child {
+//!     #[validate(minimum = 0)]
+//!     val: i32,
+//! }
+//!
+//! let rules = Data::validate_rules();
+//!
+//! assert_eq!(rules.fields[0].key, "userName");
+//! assert_eq!(rules.fields[0].rules, vec![Rule::MaxLength(64)]);
+//! assert_eq!(
+//!     rules.fields[1].nested().unwrap().fields[0].rules,
+//!     vec![Rule::Minimum(Number::I32(0))]
+//! );
+//! ```
+
+use crate::validation::{Literal, Number, Pattern};
+
+/// The rules of the validations of a type, implemented by `#[derive(Validate)]`.
+pub trait ValidateRules {
+    fn validate_rules() -> TypeRules;
+}
+
+/// A named set of validations, used as `#[validate(rules = Name)]`.
+///
+/// Implemented by `#[derive(RuleSet)]`, whose rules are those of the `#[validate(...)]` attributes.
+pub trait RuleSet {
+    fn rules() -> Vec<Rule>;
+}
+
+/// The rules of a struct or an enum.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct TypeRules {
+    /// The name of the type.
+    pub name: &'static str,
+
+    /// The rules of `#[validate(custom = ...)]` on the type, which apply to each variant of an enum.
+    pub rules: Vec<Rule>,
+
+    /// The fields of a struct.
+    pub fields: Vec<FieldRules>,
+
+    /// The variants of an enum.
+    pub variants: Vec<VariantRules>,
+}
+
+/// The rules of an enum variant.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct VariantRules {
+    /// The serde-renamed name of the variant.
+    pub name: &'static str,
+
+    /// The fields of the variant.
+    pub fields: Vec<FieldRules>,
+}
+
+/// The rules of a field.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FieldRules {
+    /// The serde-renamed key of the field.
+    pub key: &'static str,
+
+    /// The rules of the field.
+    pub rules: Vec<Rule>,
+
+    /// The rules of the field type, for a `#[validate]` field.
+    ///
+    /// The containers (e.g. `Vec<T>`, `Option<T>`) give the rules of their items.
+    #[serde(serialize_with = "serialize_nested")]
+    pub nested: Option<fn() -> Option<TypeRules>>,
+}
+
+impl FieldRules {
+    /// The rules of the field type, if the field is `#[validate]` and the type is derived.
+    pub fn nested(&self) -> Option<TypeRules> {
+        self.nested.and_then(|nested| nested())
+    }
+}
+
+impl PartialEq for FieldRules {
+    fn eq(&self, other: &Self) -> bool {
+        // The nested types are compared by name, because the rules of a recursive type are infinite.
+        self.key == other.key
+            && self.rules == other.rules
+            && self.nested().map(|rules| rules.name) == other.nested().map(|rules| rules.name)
+    }
+}
+
+fn serialize_nested<S>(
+    nested: &Option<fn() -> Option<TypeRules>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    // Only the name, as in `PartialEq`.
+    serde::Serialize::serialize(
+        &nested.and_then(|nested| nested()).map(|rules| rules.name),
+        serializer,
+    )
+}
+
+/// A validation and its parameters.
+///
+/// The limits of items (e.g. `maximum` of `Vec<i32>`) apply to each item, as serde_valid does.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum Rule {
+    Minimum(Number),
+    Maximum(Number),
+    ExclusiveMinimum(Number),
+    ExclusiveMaximum(Number),
+    MultipleOf(Number),
+    MinLength(usize),
+    MaxLength(usize),
+    Pattern(Pattern),
+    MinItems(usize),
+    MaxItems(usize),
+    UniqueItems,
+    MinProperties(usize),
+    MaxProperties(usize),
+    Enumerate(Vec<Literal>),
+    /// The path of the custom validator function (e.g. `"validate_name"`), `None` for a closure.
+    Custom(Option<&'static str>),
+    /// The name of the user-defined [`ValidationRule`](crate::validation::ValidationRule).
+    UserDefined(&'static str),
+}
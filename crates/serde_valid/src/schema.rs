@@ -0,0 +1,362 @@
+//! Runtime validation of JSON values with a JSON Schema.
+//!
+//! [`Validator`] compiles a JSON Schema once, and validates [`serde_json::Value`]s into the same
+//! [`validation::Errors`](crate::validation::Errors) as `#[derive(Validate)]`,
+//! so the errors can be formatted and localized in the same way.
+//!
+//! The supported subset of [draft 2020-12](https://json-schema.org/draft/2020-12/schema) is
+//!
+//! - `type`, `enum` and `const`,
+//! - `minimum`, `maximum`, `exclusiveMinimum`, `exclusiveMaximum` and `multipleOf`,
+//! - `minLength`, `maxLength` and `pattern`,
+//! - `items`, `minItems`, `maxItems` and `uniqueItems`,
+//! - `properties`, `required`, `additionalProperties`, `minProperties` and `maxProperties`,
+//! - `$ref` to a JSON pointer in the same document (e.g. `#/$defs/Address`),
+//! - the boolean schemas `true` and `false`.
+//!
+//! The other validation keywords (e.g. `allOf`, `prefixItems`) are rejected by [`Validator::new`],
+//! and the annotations (e.g. `title`, `description`) are ignored.
+//! As in JSON Schema, `minLength` and `maxLength` count the Unicode code points,
+//! and the values of `enum` and `const` are compared structurally.
+//!
+//! ```rust
+//! use serde_json::json;
+//! use serde_valid::schema::Validator;
+//!
+//! let validator = Validator::new(&json!({
+//!     "type": "object",
+//!     "properties": {
+//!         "name": { "type": "string", "maxLength": 4 },
+//!         "age": { "type": "integer", "minimum": 0 }
+//!     },
+//!     "required": ["name", "age"]
+//! }))
+//! .unwrap();
+//!
+//! assert!(validator.validate(&json!({ "name": "Alex", "age": 20 })).is_ok());
+//! let errors = validator.validate(&json!({ "name": "Alexander" })).unwrap_err();
+//!
+//! assert_eq!(
+//!     serde_json::to_value(&errors).unwrap(),
+//!     json!({
+//!         "errors": [],
+//!         "properties": {
+//!             "name": { "errors": ["The length of the value must be `<= 4`."] },
+//!             "age": { "errors": ["The property is required."] }
+//!         }
+//!     })
+//! );
+//! ```
+
+mod compiler;
+
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+use serde_json::{Number, Value};
+
+use crate::validation::error::{Format, FormatDefault};
+use crate::validation::{
+    json_eq, rule_error_by, ArrayErrors, Composited, Errors, IntoError, ObjectErrors,
+    ValidateExclusiveMaximum, ValidateExclusiveMinimum, ValidateMaxItems, ValidateMaxProperties,
+    ValidateMaximum, ValidateMinItems, ValidateMinProperties, ValidateMinimum, ValidateMultipleOf,
+    ValidatePattern, ValidateUniqueItems, ValidationRule, VecErrors,
+};
+use crate::{MaxLengthError, MinLengthError};
+use compiler::{InstanceType, Node};
+
+/// An error of the JSON Schema given to [`Validator::new`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SchemaError {
+    #[error("{pointer}: `{keyword}` must be {expected}.")]
+    InvalidKeyword {
+        pointer: String,
+        keyword: String,
+        expected: &'static str,
+    },
+
+    #[error("{pointer}: `{keyword}` is not supported.")]
+    UnsupportedKeyword { pointer: String, keyword: String },
+
+    #[error("{pointer}: `$ref` of `{reference}` can not be resolved.")]
+    UnresolvedReference { pointer: String, reference: String },
+
+    #[error("{pointer}: `$ref` refers to itself without validating any value.")]
+    CyclicReference { pointer: String },
+}
+
+/// A compiled JSON Schema.
+#[derive(Debug, Clone)]
+pub struct Validator {
+    nodes: Vec<Node>,
+}
+
+impl Validator {
+    /// Compile a JSON Schema.
+    pub fn new(schema: &Value) -> Result<Self, SchemaError> {
+        Ok(Self {
+            nodes: compiler::compile(schema)?,
+        })
+    }
+
+    /// Validate a value.
+    pub fn validate(&self, value: &Value) -> Result<(), Errors> {
+        let mut collected = Collected::default();
+        self.collect(0, value, &mut collected);
+        match collected.into_errors(Some(value)) {
+            Some(errors) => Err(errors),
+            None => Ok(()),
+        }
+    }
+
+    /// `true` if the value is valid.
+    pub fn is_valid(&self, value: &Value) -> bool {
+        self.validate(value).is_ok()
+    }
+
+    fn collect(&self, index: usize, value: &Value, collected: &mut Collected) {
+        let node = &self.nodes[index];
+
+        if node.deny {
+            collected.errors.push(crate::validation::Error::Custom(
+                "The value is not allowed.".to_string(),
+            ));
+            return;
+        }
+        if let Some(reference) = node.reference {
+            self.collect(reference, value, collected);
+        }
+        if !node.types.is_empty() && !node.types.iter().any(|type_| type_.matches(value)) {
+            collected
+                .errors
+                .push(crate::validation::Error::Custom(type_message(&node.types)));
+        }
+        if let Some(enumerate) = &node.enumerate {
+            if let Err(error) = Enumerate::validate(value, enumerate) {
+                collected.errors.push(rule_error_by::<Enumerate>(
+                    Composited::Single(error),
+                    Format::Default,
+                ));
+            }
+        }
+
+        match value {
+            Value::Number(number) => collect_number_errors(node, number, &mut collected.errors),
+            Value::String(string) => {
+                let errors = &mut collected.errors;
+                let length = string.chars().count();
+                if let Some(min_length) = node.min_length.filter(|min| length < *min) {
+                    push_error::<MinLengthError>(errors, Err(MinLengthError::new(min_length)));
+                }
+                if let Some(max_length) = node.max_length.filter(|max| length > *max) {
+                    push_error::<MaxLengthError>(errors, Err(MaxLengthError::new(max_length)));
+                }
+                if let Some(pattern) = &node.pattern {
+                    push_error(errors, string.validate_pattern(pattern));
+                }
+            }
+            Value::Array(array) => {
+                let errors = &mut collected.errors;
+                if let Some(min_items) = node.min_items {
+                    push_error(errors, array.validate_min_items(min_items));
+                }
+                if let Some(max_items) = node.max_items {
+                    push_error(errors, array.validate_max_items(max_items));
+                }
+                if node.unique_items {
+                    push_error(errors, array.as_slice().validate_unique_items());
+                }
+                if let Some(items) = node.items {
+                    for (item_index, item) in array.iter().enumerate() {
+                        self.collect(items, item, collected.items.entry(item_index).or_default());
+                    }
+                }
+            }
+            Value::Object(object) => {
+                let errors = &mut collected.errors;
+                if let Some(min_properties) = node.min_properties {
+                    push_error(errors, object.validate_min_properties(min_properties));
+                }
+                if let Some(max_properties) = node.max_properties {
+                    push_error(errors, object.validate_max_properties(max_properties));
+                }
+                for required in &node.required {
+                    if !object.contains_key(required) {
+                        collected
+                            .properties
+                            .entry(required.clone())
+                            .or_default()
+                            .errors
+                            .push(crate::validation::Error::Custom(
+                                "The property is required.".to_string(),
+                            ));
+                    }
+                }
+                for (key, property) in object {
+                    let schema = node
+                        .properties
+                        .get(key)
+                        .copied()
+                        .or(node.additional_properties);
+                    if let Some(schema) = schema {
+                        self.collect(
+                            schema,
+                            property,
+                            collected.properties.entry(key.clone()).or_default(),
+                        );
+                    }
+                }
+            }
+            Value::Null | Value::Bool(_) => {}
+        }
+    }
+}
+
+/// Validate a number with the `Validate*` trait of the narrowest type holding both numbers.
+macro_rules! validate_number {
+    ($number:expr, $limit:expr, $validate:ident) => {
+        match (
+            $number.as_i64(),
+            $limit.as_i64(),
+            $number.as_u64(),
+            $limit.as_u64(),
+        ) {
+            (Some(number), Some(limit), _, _) => number.$validate(limit),
+            (_, _, Some(number), Some(limit)) => number.$validate(limit),
+            _ => $number
+                .as_f64()
+                .unwrap_or(f64::NAN)
+                .$validate($limit.as_f64().unwrap_or(f64::NAN)),
+        }
+    };
+}
+
+fn collect_number_errors(node: &Node, number: &Number, errors: &mut VecErrors) {
+    if let Some(minimum) = &node.minimum {
+        push_error(errors, validate_number!(number, minimum, validate_minimum));
+    }
+    if let Some(maximum) = &node.maximum {
+        push_error(errors, validate_number!(number, maximum, validate_maximum));
+    }
+    if let Some(exclusive_minimum) = &node.exclusive_minimum {
+        push_error(
+            errors,
+            validate_number!(number, exclusive_minimum, validate_exclusive_minimum),
+        );
+    }
+    if let Some(exclusive_maximum) = &node.exclusive_maximum {
+        push_error(
+            errors,
+            validate_number!(number, exclusive_maximum, validate_exclusive_maximum),
+        );
+    }
+    if let Some(multiple_of) = &node.multiple_of {
+        push_error(
+            errors,
+            validate_number!(number, multiple_of, validate_multiple_of),
+        );
+    }
+}
+
+fn push_error<E>(errors: &mut VecErrors, result: Result<(), E>)
+where
+    Composited<E>: IntoError<E>,
+{
+    if let Err(error) = result {
+        errors.push(Composited::Single(error).into_error());
+    }
+}
+
+/// `enum` and `const`, whose values are compared structurally, where `1` equals `1.0`.
+struct Enumerate;
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct EnumerateError {
+    enumerate: Vec<Value>,
+}
+
+impl FormatDefault for EnumerateError {
+    fn format_default(&self) -> String {
+        let values = self
+            .enumerate
+            .iter()
+            .map(|value| match value {
+                Value::String(string) => string.clone(),
+                value => value.to_string(),
+            })
+            .collect::<Vec<_>>();
+        format!("The value must be in [{}].", values.join(", "))
+    }
+}
+
+impl ValidationRule for Enumerate {
+    type Value = Value;
+    type Param = Vec<Value>;
+    type Error = EnumerateError;
+
+    const NAME: &'static str = "enumerate";
+
+    fn validate(value: &Value, enumerate: &Vec<Value>) -> Result<(), EnumerateError> {
+        if enumerate.iter().any(|candidate| json_eq(candidate, value)) {
+            Ok(())
+        } else {
+            Err(EnumerateError {
+                enumerate: enumerate.clone(),
+            })
+        }
+    }
+}
+
+fn type_message(types: &[InstanceType]) -> String {
+    match types {
+        [type_] => format!("The type must be `{type_}`."),
+        _ => format!(
+            "The type must be in [{}].",
+            types
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Errors collected for a value, before they are shaped into [`Errors`].
+#[derive(Debug, Default)]
+struct Collected {
+    errors: VecErrors,
+    properties: IndexMap<String, Collected>,
+    items: IndexMap<usize, Collected>,
+}
+
+impl Collected {
+    fn into_errors(self, value: Option<&Value>) -> Option<Errors> {
+        let properties = self
+            .properties
+            .into_iter()
+            .filter_map(|(key, collected)| {
+                let property = value.and_then(|value| value.get(&key));
+                collected
+                    .into_errors(property)
+                    .map(|errors| (Cow::Owned(key), errors))
+            })
+            .collect::<IndexMap<_, _>>();
+        let items = self
+            .items
+            .into_iter()
+            .filter_map(|(index, collected)| {
+                let item = value.and_then(|value| value.get(index));
+                collected.into_errors(item).map(|errors| (index, errors))
+            })
+            .collect::<IndexMap<_, _>>();
+
+        if self.errors.is_empty() && properties.is_empty() && items.is_empty() {
+            return None;
+        }
+        Some(match value {
+            Some(Value::Object(_)) => Errors::Object(ObjectErrors::new(self.errors, properties)),
+            Some(Value::Array(_)) => Errors::Array(ArrayErrors::new(self.errors, items)),
+            _ => Errors::NewType(self.errors),
+        })
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use regex::Regex;
+use serde_json::{Number, Value};
+
+use super::SchemaError;
+
+/// Validation keywords which are not supported yet.
+const UNSUPPORTED_KEYWORDS: &[&str] = &[
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "not",
+    "if",
+    "then",
+    "else",
+    "dependentRequired",
+    "dependentSchemas",
+    "prefixItems",
+    "contains",
+    "minContains",
+    "maxContains",
+    "patternProperties",
+    "propertyNames",
+    "unevaluatedItems",
+    "unevaluatedProperties",
+    "$dynamicRef",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstanceType {
+    Null,
+    Boolean,
+    Object,
+    Array,
+    Number,
+    String,
+    Integer,
+}
+
+impl InstanceType {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "null" => Some(Self::Null),
+            "boolean" => Some(Self::Boolean),
+            "object" => Some(Self::Object),
+            "array" => Some(Self::Array),
+            "number" => Some(Self::Number),
+            "string" => Some(Self::String),
+            "integer" => Some(Self::Integer),
+            _ => None,
+        }
+    }
+
+    pub fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (Self::Null, Value::Null) => true,
+            (Self::Boolean, Value::Bool(_)) => true,
+            (Self::Object, Value::Object(_)) => true,
+            (Self::Array, Value::Array(_)) => true,
+            (Self::Number, Value::Number(_)) => true,
+            (Self::String, Value::String(_)) => true,
+            (Self::Integer, Value::Number(number)) => {
+                number.is_i64()
+                    || number.is_u64()
+                    || number.as_f64().is_some_and(|number| number.fract() == 0.0)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for InstanceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Object => "object",
+            Self::Array => "array",
+            Self::Number => "number",
+            Self::String => "string",
+            Self::Integer => "integer",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A compiled schema. Subschemas are indices of the other nodes.
+#[derive(Debug, Clone, Default)]
+pub struct Node {
+    pub deny: bool,
+    pub reference: Option<usize>,
+    pub types: Vec<InstanceType>,
+    pub enumerate: Option<Vec<Value>>,
+    pub minimum: Option<Number>,
+    pub maximum: Option<Number>,
+    pub exclusive_minimum: Option<Number>,
+    pub exclusive_maximum: Option<Number>,
+    pub multiple_of: Option<Number>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<Regex>,
+    pub items: Option<usize>,
+    pub min_items: Option<usize>,
+    pub max_items: Option<usize>,
+    pub unique_items: bool,
+    pub properties: IndexMap<String, usize>,
+    pub required: Vec<String>,
+    pub additional_properties: Option<usize>,
+    pub min_properties: Option<usize>,
+    pub max_properties: Option<usize>,
+}
+
+/// Compile a schema into nodes. The root schema is the first node.
+pub fn compile(schema: &Value) -> Result<Vec<Node>, SchemaError> {
+    let mut compiler = Compiler {
+        root: schema,
+        nodes: vec![],
+        indices: HashMap::new(),
+    };
+    compiler.compile(schema, String::new())?;
+    compiler.check_references()?;
+    Ok(compiler.nodes)
+}
+
+struct Compiler<'a> {
+    root: &'a Value,
+    nodes: Vec<Node>,
+    /// Indices of the compiled nodes by JSON pointer, for `$ref`.
+    indices: HashMap<String, usize>,
+}
+
+impl Compiler<'_> {
+    /// `$ref` validates the same value, so a chain of `$ref`s back to itself never ends.
+    fn check_references(&self) -> Result<(), SchemaError> {
+        for start in 0..self.nodes.len() {
+            let mut index = start;
+            for _ in 0..self.nodes.len() {
+                match self.nodes[index].reference {
+                    Some(reference) if reference == start => {
+                        let pointer = self
+                            .indices
+                            .iter()
+                            .find_map(|(pointer, index)| (*index == start).then(|| pointer.clone()))
+                            .unwrap_or_default();
+                        return Err(SchemaError::CyclicReference { pointer });
+                    }
+                    Some(reference) => index = reference,
+                    None => break,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn compile(&mut self, schema: &Value, pointer: String) -> Result<usize, SchemaError> {
+        if let Some(index) = self.indices.get(&pointer) {
+            return Ok(*index);
+        }
+        // Register the node first, so that recursive references terminate.
+        let index = self.nodes.len();
+        self.nodes.push(Node::default());
+        self.indices.insert(pointer.clone(), index);
+
+        let node = match schema {
+            Value::Bool(allow) => Node {
+                deny: !allow,
+                ..Default::default()
+            },
+            Value::Object(object) => self.compile_object(object, &pointer)?,
+            _ => {
+                return Err(SchemaError::InvalidKeyword {
+                    pointer,
+                    keyword: "schema".to_string(),
+                    expected: "an object or a boolean",
+                })
+            }
+        };
+        self.nodes[index] = node;
+        Ok(index)
+    }
+
+    fn compile_object(
+        &mut self,
+        object: &serde_json::Map<String, Value>,
+        pointer: &str,
+    ) -> Result<Node, SchemaError> {
+        let mut node = Node::default();
+
+        for (keyword, value) in object {
+            let keyword_pointer = format!(
+                "{pointer}/{}",
+                crate::validation::error::escape_pointer_token(keyword)
+            );
+            let invalid = |expected| SchemaError::InvalidKeyword {
+                pointer: pointer.to_string(),
+                keyword: keyword.clone(),
+                expected,
+            };
+
+            match keyword.as_str() {
+                "$ref" => {
+                    let reference = value.as_str().ok_or_else(|| invalid("a string"))?;
+                    let target = reference
+                        .strip_prefix('#')
+                        .and_then(|target| Some((target, self.root.pointer(target)?)));
+                    let Some((target, schema)) = target else {
+                        return Err(SchemaError::UnresolvedReference {
+                            pointer: pointer.to_string(),
+                            reference: reference.to_string(),
+                        });
+                    };
+                    node.reference = Some(self.compile(schema, target.to_string())?);
+                }
+                "type" => {
+                    let names = match value {
+                        Value::String(name) => vec![name.as_str()],
+                        Value::Array(names) => names
+                            .iter()
+                            .map(|name| name.as_str().ok_or_else(|| invalid("a type name")))
+                            .collect::<Result<_, _>>()?,
+                        _ => return Err(invalid("a type name or an array of type names")),
+                    };
+                    node.types = names
+                        .into_iter()
+                        .map(|name| {
+                            InstanceType::from_name(name).ok_or_else(|| invalid("a type name"))
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                "enum" => {
+                    let values = value.as_array().ok_or_else(|| invalid("an array"))?;
+                    node.enumerate = Some(values.clone());
+                }
+                "const" => node.enumerate = Some(vec![value.clone()]),
+                "minimum" => node.minimum = Some(number(value).ok_or_else(|| invalid("a number"))?),
+                "maximum" => node.maximum = Some(number(value).ok_or_else(|| invalid("a number"))?),
+                "exclusiveMinimum" => {
+                    node.exclusive_minimum = Some(number(value).ok_or_else(|| invalid("a number"))?)
+                }
+                "exclusiveMaximum" => {
+                    node.exclusive_maximum = Some(number(value).ok_or_else(|| invalid("a number"))?)
+                }
+                "multipleOf" => {
+                    node.multiple_of = Some(
+                        number(value)
+                            .filter(|number| number.as_f64().is_some_and(|number| number > 0.0))
+                            .ok_or_else(|| invalid("a positive number"))?,
+                    )
+                }
+                "minLength" => {
+                    node.min_length =
+                        Some(size(value).ok_or_else(|| invalid("a non-negative integer"))?)
+                }
+                "maxLength" => {
+                    node.max_length =
+                        Some(size(value).ok_or_else(|| invalid("a non-negative integer"))?)
+                }
+                "pattern" => {
+                    let pattern = value.as_str().ok_or_else(|| invalid("a string"))?;
+                    node.pattern =
+                        Some(Regex::new(pattern).map_err(|_| invalid("a regular expression"))?);
+                }
+                "items" => node.items = Some(self.compile(value, keyword_pointer)?),
+                "minItems" => {
+                    node.min_items =
+                        Some(size(value).ok_or_else(|| invalid("a non-negative integer"))?)
+                }
+                "maxItems" => {
+                    node.max_items =
+                        Some(size(value).ok_or_else(|| invalid("a non-negative integer"))?)
+                }
+                "uniqueItems" => {
+                    node.unique_items = value.as_bool().ok_or_else(|| invalid("a boolean"))?
+                }
+                "properties" => {
+                    let properties = value.as_object().ok_or_else(|| invalid("an object"))?;
+                    for (name, property) in properties {
+                        let property_pointer = format!(
+                            "{keyword_pointer}/{}",
+                            crate::validation::error::escape_pointer_token(name)
+                        );
+                        let index = self.compile(property, property_pointer)?;
+                        node.properties.insert(name.clone(), index);
+                    }
+                }
+                "required" => {
+                    node.required = value
+                        .as_array()
+                        .ok_or_else(|| invalid("an array of strings"))?
+                        .iter()
+                        .map(|name| {
+                            name.as_str()
+                                .map(ToString::to_string)
+                                .ok_or_else(|| invalid("an array of strings"))
+                        })
+                        .collect::<Result<_, _>>()?;
+                }
+                "additionalProperties" => {
+                    node.additional_properties = Some(self.compile(value, keyword_pointer)?)
+                }
+                "minProperties" => {
+                    node.min_properties =
+                        Some(size(value).ok_or_else(|| invalid("a non-negative integer"))?)
+                }
+                "maxProperties" => {
+                    node.max_properties =
+                        Some(size(value).ok_or_else(|| invalid("a non-negative integer"))?)
+                }
+                "$defs" | "definitions" => {
+                    // Compiled when referenced.
+                }
+                keyword if UNSUPPORTED_KEYWORDS.contains(&keyword) => {
+                    return Err(SchemaError::UnsupportedKeyword {
+                        pointer: pointer.to_string(),
+                        keyword: keyword.to_string(),
+                    })
+                }
+                _ => {
+                    // Annotations and unknown keywords.
+                }
+            }
+        }
+
+        Ok(node)
+    }
+}
+
+fn number(value: &Value) -> Option<Number> {
+    value.as_number().cloned()
+}
+
+fn size(value: &Value) -> Option<usize> {
+    value.as_u64().and_then(|size| usize::try_from(size).ok())
+}
@@ -0,0 +1,326 @@
+//! Source locations of errors.
+//!
+//! The `*_with_spans` functions (e.g. [`FromJsonStr::from_json_str_with_spans`](crate::json::FromJsonStr::from_json_str_with_spans))
+//! map each validation error back to the value it belongs to in the source text.
+//!
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_valid::Validate;
+//! use serde_valid::json::FromJsonStr;
+//!
+//! #[derive(Debug, Validate, Deserialize)]
+//! struct Config {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//! }
+//!
+//! let source = "{\n  \"val\": 12\n}";
+//! let err = Config::from_json_str_with_spans(source).unwrap_err();
+//!
+//! let span = err.labels()[0].span.unwrap();
+//! assert_eq!((span.line, span.column), (2, 10));
+//! assert_eq!(
+//!     err.to_string(),
+//!     "/val at 2:10: The number must be `<= 10`."
+//! );
+//! ```
+mod json;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "yaml")]
+mod yaml;
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+pub(crate) use json::locate_json;
+#[cfg(feature = "toml")]
+pub(crate) use toml::locate_toml;
+#[cfg(feature = "yaml")]
+pub(crate) use yaml::locate_yaml;
+
+/// Spans of every value in a document, keyed by JSON Pointer.
+pub(crate) type SpanMap = HashMap<String, Range<usize>>;
+
+/// Location of a value in the source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offset of the start.
+    pub offset: usize,
+
+    /// Length in bytes.
+    pub len: usize,
+
+    /// Line number, starting from 1.
+    pub line: usize,
+
+    /// Column number in characters, starting from 1.
+    pub column: usize,
+}
+
+impl Span {
+    pub(crate) fn new(source: &str, range: Range<usize>) -> Self {
+        let offset = floor_char_boundary(source, range.start.min(source.len()));
+        let end = floor_char_boundary(source, range.end.clamp(offset, source.len()));
+        let line_start = source[..offset].rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            offset,
+            len: end - offset,
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+        }
+    }
+
+    /// Create a span from a one-based line and column (in characters).
+    pub(crate) fn from_line_column(source: &str, line: usize, column: usize) -> Option<Self> {
+        let line_start = if line <= 1 {
+            0
+        } else {
+            source
+                .match_indices('\n')
+                .nth(line - 2)
+                .map(|(index, _)| index + 1)?
+        };
+        let offset = source[line_start..]
+            .char_indices()
+            .nth(column.saturating_sub(1))
+            .map_or(source.len(), |(index, _)| line_start + index);
+
+        Some(Self::new(source, offset..offset))
+    }
+}
+
+fn floor_char_boundary(source: &str, mut index: usize) -> usize {
+    while !source.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// An error message with the location of the value it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Label {
+    /// JSON Pointer of the value. The root value has an empty pointer.
+    pub pointer: String,
+
+    pub message: String,
+
+    /// `None` if the value could not be located in the source.
+    pub span: Option<Span>,
+}
+
+/// [`crate::Error`] with the source locations of its errors.
+#[derive(Debug)]
+pub struct SpannedError<E>
+where
+    E: 'static + std::error::Error,
+{
+    error: Box<crate::Error<E>>,
+    labels: Vec<Label>,
+    source: String,
+}
+
+impl<E> SpannedError<E>
+where
+    E: 'static + std::error::Error,
+{
+    /// Locate the errors in the source.
+    ///
+    /// `locate_values` returns the spans of the values of the document, and
+    /// `locate_deserialize_error` the span of a deserialize error.
+    pub(crate) fn new(
+        error: crate::Error<E>,
+        source: &str,
+        locate_values: impl FnOnce(&str) -> Option<SpanMap>,
+        locate_deserialize_error: impl FnOnce(&E) -> Option<Span>,
+    ) -> Self {
+        let labels = match &error {
+            crate::Error::DeserializeError(deserialize_error) => vec![Label {
+                pointer: String::new(),
+                message: deserialize_error.to_string(),
+                span: locate_deserialize_error(deserialize_error),
+            }],
+            crate::Error::ValidationError(errors) => {
+                let spans = locate_values(source).unwrap_or_default();
+                errors
+                    .to_remote()
+                    .flatten()
+                    .into_iter()
+                    .map(|(pointer, error)| Label {
+                        span: find_span(&spans, &pointer).map(|range| Span::new(source, range)),
+                        pointer,
+                        message: error.message.clone(),
+                    })
+                    .collect()
+            }
+        };
+
+        Self {
+            error: Box::new(error),
+            labels,
+            source: source.to_owned(),
+        }
+    }
+
+    pub fn error(&self) -> &crate::Error<E> {
+        &self.error
+    }
+
+    pub fn into_error(self) -> crate::Error<E> {
+        *self.error
+    }
+
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// The source text which the spans point into.
+    pub fn source_text(&self) -> &str {
+        &self.source
+    }
+
+    /// Render the errors with snippets of the source.
+    ///
+    /// ```text
+    ///   × The number must be `<= 10`.
+    ///    ╭─[/val 2:10]
+    ///  2 │   "val": 12
+    ///    ·          ──
+    ///    ╰────
+    /// ```
+    pub fn render(&self) -> String {
+        self.labels
+            .iter()
+            .map(|label| render_label(label, &self.source))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Find the span of the pointer, or of its nearest located parent.
+fn find_span(spans: &SpanMap, pointer: &str) -> Option<Range<usize>> {
+    let mut pointer = pointer;
+    loop {
+        if let Some(range) = spans.get(pointer) {
+            return Some(range.clone());
+        }
+        pointer = &pointer[..pointer.rfind('/')?];
+    }
+}
+
+fn render_label(label: &Label, source: &str) -> String {
+    let path = if label.pointer.is_empty() {
+        "(root)"
+    } else {
+        &label.pointer
+    };
+    let Some(span) = label.span else {
+        return format!("  × {}\n   at {path}", label.message);
+    };
+
+    let line_text = source.lines().nth(span.line - 1).unwrap_or_default();
+    let width = span.line.to_string().len();
+    let gutter = " ".repeat(width);
+    let underline_len = source
+        .get(span.offset..span.offset + span.len)
+        .unwrap_or_default()
+        .lines()
+        .next()
+        .map_or(0, |text| text.chars().count())
+        .max(1);
+
+    [
+        format!("  × {}", label.message),
+        format!(" {gutter} ╭─[{path} {}:{}]", span.line, span.column),
+        format!(" {} │ {line_text}", span.line),
+        format!(
+            " {gutter} · {}{}",
+            " ".repeat(span.column - 1),
+            "─".repeat(underline_len)
+        ),
+        format!(" {gutter} ╰────"),
+    ]
+    .join("\n")
+}
+
+impl<E> std::fmt::Display for SpannedError<E>
+where
+    E: 'static + std::error::Error,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let crate::Error::DeserializeError(error) = self.error.as_ref() {
+            return write!(f, "{error}");
+        }
+
+        for (index, label) in self.labels.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            let path = if label.pointer.is_empty() {
+                "(root)"
+            } else {
+                &label.pointer
+            };
+            match label.span {
+                Some(span) => write!(
+                    f,
+                    "{path} at {}:{}: {}",
+                    span.line, span.column, label.message
+                )?,
+                None => write!(f, "{path}: {}", label.message)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<E> std::error::Error for SpannedError<E>
+where
+    E: 'static + std::error::Error,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.error.as_ref())
+    }
+}
+
+impl<E> From<SpannedError<E>> for crate::Error<E>
+where
+    E: 'static + std::error::Error,
+{
+    fn from(error: SpannedError<E>) -> Self {
+        error.into_error()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_line_and_column() {
+        let source = "a\nbcé\nd";
+
+        assert_eq!(
+            Span::new(source, 4..6),
+            Span {
+                offset: 4,
+                len: 2,
+                line: 2,
+                column: 3
+            }
+        );
+        assert_eq!(
+            Span::from_line_column(source, 3, 1),
+            Some(Span::new(source, 7..7))
+        );
+    }
+
+    #[test]
+    fn find_span_of_parent() {
+        let spans = SpanMap::from([(String::new(), 0..10), ("/a".to_string(), 2..5)]);
+
+        assert_eq!(find_span(&spans, "/a/b/0"), Some(2..5));
+        assert_eq!(find_span(&spans, "/c"), Some(0..10));
+    }
+}
@@ -0,0 +1,146 @@
+use super::SpanMap;
+use crate::validation::error::escape_pointer_token;
+
+/// Locate every value of a JSON document.
+///
+/// Returns `None` if the source is not valid JSON.
+pub(crate) fn locate_json(source: &str) -> Option<SpanMap> {
+    let mut scanner = Scanner {
+        source,
+        bytes: source.as_bytes(),
+        position: 0,
+        spans: SpanMap::new(),
+    };
+    scanner.value(String::new())?;
+    Some(scanner.spans)
+}
+
+struct Scanner<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    position: usize,
+    spans: SpanMap,
+}
+
+impl Scanner<'_> {
+    fn value(&mut self, pointer: String) -> Option<()> {
+        self.skip_whitespace();
+        let start = self.position;
+        match self.peek()? {
+            b'{' => self.object(&pointer)?,
+            b'[' => self.array(&pointer)?,
+            b'"' => {
+                self.string()?;
+            }
+            _ => {
+                while self.peek().is_some_and(|byte| {
+                    !matches!(byte, b',' | b']' | b'}') && !byte.is_ascii_whitespace()
+                }) {
+                    self.position += 1;
+                }
+                if self.position == start {
+                    return None;
+                }
+            }
+        }
+        self.spans.insert(pointer, start..self.position);
+        Some(())
+    }
+
+    fn object(&mut self, pointer: &str) -> Option<()> {
+        self.expect(b'{')?;
+        self.skip_whitespace();
+        if self.peek()? == b'}' {
+            self.position += 1;
+            return Some(());
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            self.value(format!("{pointer}/{}", escape_pointer_token(&key)))?;
+            self.skip_whitespace();
+            match self.next()? {
+                b',' => continue,
+                b'}' => return Some(()),
+                _ => return None,
+            }
+        }
+    }
+
+    fn array(&mut self, pointer: &str) -> Option<()> {
+        self.expect(b'[')?;
+        self.skip_whitespace();
+        if self.peek()? == b']' {
+            self.position += 1;
+            return Some(());
+        }
+        for index in 0.. {
+            self.value(format!("{pointer}/{index}"))?;
+            self.skip_whitespace();
+            match self.next()? {
+                b',' => continue,
+                b']' => break,
+                _ => return None,
+            }
+        }
+        Some(())
+    }
+
+    /// Scan a string literal and return its unescaped value.
+    fn string(&mut self) -> Option<String> {
+        let start = self.position;
+        self.expect(b'"')?;
+        loop {
+            match self.next()? {
+                b'\\' => self.position += 1,
+                b'"' => break,
+                _ => {}
+            }
+        }
+        serde_json::from_str(self.source.get(start..self.position)?).ok()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        (self.next()? == byte).then_some(())
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.position += 1;
+        Some(byte)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_json_values() {
+        let source = r#"{ "a": [1, {"b/c": "x\"y"}], "d": null }"#;
+        let spans = locate_json(source).unwrap();
+
+        assert_eq!(spans[""], 0..source.len());
+        assert_eq!(&source[spans["/a"].clone()], r#"[1, {"b/c": "x\"y"}]"#);
+        assert_eq!(&source[spans["/a/0"].clone()], "1");
+        assert_eq!(&source[spans["/a/1/b~1c"].clone()], r#""x\"y""#);
+        assert_eq!(&source[spans["/d"].clone()], "null");
+    }
+
+    #[test]
+    fn locate_invalid_json() {
+        assert_eq!(locate_json(r#"{ "a": "#), None);
+    }
+}
@@ -0,0 +1,93 @@
+use std::ops::Range;
+
+use super::SpanMap;
+use crate::validation::error::escape_pointer_token;
+
+/// Locate every value of a TOML document.
+///
+/// Returns `None` if the source is not valid TOML.
+pub(crate) fn locate_toml(source: &str) -> Option<SpanMap> {
+    let document = toml_edit::ImDocument::parse(source).ok()?;
+    let mut spans = SpanMap::new();
+    table(
+        document.as_table(),
+        String::new(),
+        0..source.len(),
+        &mut spans,
+    );
+    spans.insert(String::new(), 0..source.len());
+    Some(spans)
+}
+
+/// Tables without a header have no span of their own, so they fall back to the span of their key.
+fn table(table: &toml_edit::Table, pointer: String, fallback: Range<usize>, spans: &mut SpanMap) {
+    for (key, child) in table.iter() {
+        let key_span = table.get_key_value(key).and_then(|(key, _)| key.span());
+        let child_pointer = format!("{pointer}/{}", escape_pointer_token(key));
+        match child {
+            toml_edit::Item::Value(value) => self::value(value, child_pointer, spans),
+            toml_edit::Item::Table(child) => self::table(
+                child,
+                child_pointer,
+                child.span().or(key_span).unwrap_or(fallback.clone()),
+                spans,
+            ),
+            toml_edit::Item::ArrayOfTables(array) => {
+                for (index, child) in array.iter().enumerate() {
+                    self::table(
+                        child,
+                        format!("{child_pointer}/{index}"),
+                        child.span().unwrap_or(fallback.clone()),
+                        spans,
+                    );
+                }
+                if let Some(span) = array.span().or(key_span) {
+                    spans.insert(child_pointer, span);
+                }
+            }
+            toml_edit::Item::None => {}
+        }
+    }
+    spans.insert(pointer, table.span().unwrap_or(fallback));
+}
+
+fn value(value: &toml_edit::Value, pointer: String, spans: &mut SpanMap) {
+    match value {
+        toml_edit::Value::Array(array) => {
+            for (index, child) in array.iter().enumerate() {
+                self::value(child, format!("{pointer}/{index}"), spans);
+            }
+        }
+        toml_edit::Value::InlineTable(table) => {
+            for (key, child) in table.iter() {
+                self::value(
+                    child,
+                    format!("{pointer}/{}", escape_pointer_token(key)),
+                    spans,
+                );
+            }
+        }
+        _ => {}
+    }
+    if let Some(span) = value.span() {
+        spans.insert(pointer, span);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_toml_values() {
+        let source = "a = [1, { b = \"x\" }]\n\n[c]\nd = 2\n\n[[e]]\nf = 3\n";
+        let spans = locate_toml(source).unwrap();
+
+        assert_eq!(spans[""], 0..source.len());
+        assert_eq!(source[spans["/a/0"].clone()].trim(), "1");
+        assert_eq!(source[spans["/a/1/b"].clone()].trim(), "\"x\"");
+        assert!(source[spans["/c"].clone()].starts_with("[c]"));
+        assert_eq!(source[spans["/c/d"].clone()].trim(), "2");
+        assert_eq!(source[spans["/e/0/f"].clone()].trim(), "3");
+    }
+}
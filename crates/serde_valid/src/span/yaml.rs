@@ -0,0 +1,249 @@
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+
+use super::SpanMap;
+use crate::validation::error::escape_pointer_token;
+
+/// Locate every value of the first document of a YAML stream.
+///
+/// The parser only marks where a node starts, so the end of a scalar is
+/// found by scanning the source, and a collection ends at the next token.
+///
+/// Returns `None` if the source is not valid YAML.
+pub(crate) fn locate_yaml(source: &str) -> Option<SpanMap> {
+    let mut locator = Locator {
+        source,
+        char_offsets: source.char_indices().map(|(offset, _)| offset).collect(),
+        stack: vec![],
+        spans: SpanMap::new(),
+        document_count: 0,
+    };
+    Parser::new_from_str(source)
+        .load(&mut locator, false)
+        .ok()?;
+    Some(locator.spans)
+}
+
+/// `pointer` is `None` for the nodes in a mapping key, which have no JSON Pointer.
+enum Node {
+    Mapping {
+        pointer: Option<String>,
+        start: usize,
+        key: Key,
+    },
+    Sequence {
+        pointer: Option<String>,
+        start: usize,
+        index: usize,
+    },
+}
+
+enum Key {
+    Expected,
+    Scalar(String),
+    /// A collection or an alias as the key, whose value is skipped.
+    Complex,
+}
+
+struct Locator<'a> {
+    source: &'a str,
+    char_offsets: Vec<usize>,
+    stack: Vec<Node>,
+    spans: SpanMap,
+    document_count: usize,
+}
+
+impl Locator<'_> {
+    /// Marker index is counted in characters.
+    fn offset(&self, mark: Marker) -> usize {
+        self.char_offsets
+            .get(mark.index())
+            .copied()
+            .unwrap_or(self.source.len())
+    }
+
+    /// Whether the next node is a mapping key.
+    fn is_key(&self) -> bool {
+        matches!(
+            self.stack.last(),
+            Some(Node::Mapping {
+                key: Key::Expected,
+                ..
+            })
+        )
+    }
+
+    /// Set the key of the mapping, which the next node is the value of.
+    fn set_key(&mut self, new_key: Key) {
+        if let Some(Node::Mapping { key, .. }) = self.stack.last_mut() {
+            *key = new_key;
+        }
+    }
+
+    /// Pointer of the next value, or `None` if the value has no pointer.
+    fn next_pointer(&mut self) -> Option<String> {
+        match self.stack.last_mut() {
+            None => Some(String::new()),
+            Some(Node::Mapping { pointer, key, .. }) => {
+                match (pointer, std::mem::replace(key, Key::Expected)) {
+                    (Some(pointer), Key::Scalar(key)) => {
+                        Some(format!("{pointer}/{}", escape_pointer_token(&key)))
+                    }
+                    _ => None,
+                }
+            }
+            Some(Node::Sequence { pointer, index, .. }) => {
+                *index += 1;
+                pointer
+                    .as_ref()
+                    .map(|pointer| format!("{pointer}/{}", *index - 1))
+            }
+        }
+    }
+
+    /// Pointer of the next collection, or `None` if it is a mapping key or has no pointer.
+    fn next_collection_pointer(&mut self) -> Option<String> {
+        if self.is_key() {
+            self.set_key(Key::Complex);
+            None
+        } else {
+            self.next_pointer()
+        }
+    }
+
+    fn scalar_end(&self, start: usize, value: &str, style: TScalarStyle) -> usize {
+        let rest = &self.source[start..];
+        let end = match style {
+            TScalarStyle::SingleQuoted => {
+                let mut quotes = rest.match_indices('\'').map(|(index, _)| index).skip(1);
+                let mut end = None;
+                while let Some(index) = quotes.next() {
+                    if !rest[index + 1..].starts_with('\'') {
+                        end = Some(index);
+                        break;
+                    }
+                    quotes.next();
+                }
+                end
+            }
+            TScalarStyle::DoubleQuoted => {
+                let mut escaped = false;
+                rest.char_indices().skip(1).find_map(|(index, c)| {
+                    match (escaped, c) {
+                        (false, '\\') => escaped = true,
+                        (false, '"') => return Some(index),
+                        _ => escaped = false,
+                    }
+                    None
+                })
+            }
+            TScalarStyle::Plain if rest.starts_with(value) => {
+                return start + value.len();
+            }
+            _ => None,
+        };
+        match end {
+            Some(end) => start + end + 1,
+            None => start + rest.find('\n').unwrap_or(rest.len()),
+        }
+    }
+
+    fn end_node(&mut self, mark: Marker) {
+        let mut end = self.offset(mark);
+        if self.source[end..].starts_with([']', '}']) {
+            end += 1;
+        }
+        let (pointer, start) = match self.stack.pop() {
+            Some(Node::Mapping {
+                pointer: Some(pointer),
+                start,
+                ..
+            })
+            | Some(Node::Sequence {
+                pointer: Some(pointer),
+                start,
+                ..
+            }) => (pointer, start),
+            _ => return,
+        };
+        let end = start + self.source[start..end.max(start)].trim_end().len();
+        self.spans.insert(pointer, start..end);
+    }
+}
+
+impl MarkedEventReceiver for Locator<'_> {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        if self.document_count > 1 {
+            return;
+        }
+        match event {
+            Event::DocumentStart => self.document_count += 1,
+            Event::Scalar(value, style, ..) => {
+                if self.is_key() {
+                    self.set_key(Key::Scalar(value));
+                } else if let Some(pointer) = self.next_pointer() {
+                    let start = self.offset(mark);
+                    let end = self.scalar_end(start, &value, style);
+                    self.spans.insert(pointer, start..end);
+                }
+            }
+            Event::Alias(_) => {
+                if self.is_key() {
+                    self.set_key(Key::Complex);
+                } else if let Some(pointer) = self.next_pointer() {
+                    let start = self.offset(mark);
+                    let end = self.source[start..]
+                        .find(|c: char| c.is_whitespace() || matches!(c, ',' | ']' | '}'))
+                        .map_or(self.source.len(), |len| start + len);
+                    self.spans.insert(pointer, start..end);
+                }
+            }
+            Event::MappingStart(..) => {
+                let pointer = self.next_collection_pointer();
+                self.stack.push(Node::Mapping {
+                    pointer,
+                    start: self.offset(mark),
+                    key: Key::Expected,
+                });
+            }
+            Event::SequenceStart(..) => {
+                let pointer = self.next_collection_pointer();
+                self.stack.push(Node::Sequence {
+                    pointer,
+                    start: self.offset(mark),
+                    index: 0,
+                });
+            }
+            Event::MappingEnd | Event::SequenceEnd => self.end_node(mark),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_yaml_values() {
+        let source = "a:\n  - 1\n  - { b: 'x''y' }\nc: \"d\\\"e\"\nf: g # comment\n";
+        let spans = locate_yaml(source).unwrap();
+
+        assert_eq!(&source[spans["/a/0"].clone()], "1");
+        assert_eq!(&source[spans["/a/1"].clone()], "{ b: 'x''y' }");
+        assert_eq!(&source[spans["/a/1/b"].clone()], "'x''y'");
+        assert_eq!(&source[spans["/c"].clone()], "\"d\\\"e\"");
+        assert_eq!(&source[spans["/f"].clone()], "g");
+    }
+
+    #[test]
+    fn skip_values_of_complex_keys() {
+        let source = "{ a: 1, ? [x, y] : { b: 2 }, c: 3 }";
+        let spans = locate_yaml(source).unwrap();
+
+        assert_eq!(&source[spans[""].clone()], source);
+        assert_eq!(&source[spans["/a"].clone()], "1");
+        assert_eq!(&source[spans["/c"].clone()], "3");
+        assert_eq!(spans.len(), 3);
+    }
+}
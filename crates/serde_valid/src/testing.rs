@@ -0,0 +1,191 @@
+//! Assertions of validation results, comparing the errors by path and [`ErrorKind`].
+//!
+//! Unlike the rendered JSON of the errors, the assertions do not depend on the order
+//! of the errors or on their messages.
+//!
+//! ```rust
+//! use serde_valid::testing::{assert_errors_eq, assert_invalid, assert_valid, ErrorKind};
+//! use serde_valid::Validate;
+//!
+//! #[derive(Validate)]
+//! struct Data {
+//!     #[validate(maximum = 10)]
+//!     val: i32,
+//!     #[validate(pattern = "^[a-z]+$")]
+//!     names: Vec<String>,
+//! }
+//!
+//! assert_valid!(Data { val: 10, names: vec!["a".to_string()] });
+//!
+//! let data = Data { val: 11, names: vec!["a".to_string(), "B".to_string()] };
+//!
+//! assert_invalid!(data, "/val" => ErrorKind::Maximum);
+//! assert_errors_eq!(
+//!     data,
+//!     [("/names/1", ErrorKind::Pattern), ("/val", ErrorKind::Maximum)]
+//! );
+//! ```
+//!
+//! On failure, the missing and the unexpected errors are listed with the actual errors:
+//!
+//! ```text
+//! validation errors are not equal to the expected ones
+//!   missing:
+//!     /val: minimum
+//!   unexpected:
+//!     /val: maximum
+//!   errors:
+//!     /val: The number must be `<= 10`.
+//! ```
+
+use crate::validation::error::Style;
+pub use crate::validation::ErrorKind;
+use crate::validation::Errors;
+pub use crate::{assert_errors_eq, assert_invalid, assert_valid};
+
+/// Every error with the JSON Pointer of the value it belongs to, and its kind.
+pub fn error_kinds(errors: &Errors) -> Vec<(String, ErrorKind)> {
+    errors
+        .flatten()
+        .into_iter()
+        .map(|(pointer, error)| (pointer, error.kind()))
+        .collect()
+}
+
+/// Assert that the value passes `validate()`.
+#[macro_export]
+macro_rules! assert_valid {
+    ($value:expr $(,)?) => {
+        $crate::testing::__assert_valid(&$value)
+    };
+}
+
+/// Assert that the value fails `validate()`, with at least the given errors.
+///
+/// The errors are given as `path => kind`, where `kind` is an [`ErrorKind`].
+#[macro_export]
+macro_rules! assert_invalid {
+    ($value:expr $(,)?) => {
+        $crate::testing::__assert_invalid(&$value, &[])
+    };
+    ($value:expr, $($path:expr => $kind:expr),+ $(,)?) => {
+        $crate::testing::__assert_invalid(&$value, &[$(($path, $kind)),+])
+    };
+}
+
+/// Assert that the errors of `validate()` are exactly the given `(path, kind)` pairs, in any order.
+#[macro_export]
+macro_rules! assert_errors_eq {
+    ($value:expr, [$(($path:expr, $kind:expr)),* $(,)?] $(,)?) => {
+        $crate::testing::__assert_errors_eq(&$value, &[$(($path, $kind)),*])
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_valid<T>(value: &T)
+where
+    T: crate::Validate + ?Sized,
+{
+    if let Err(errors) = value.validate() {
+        panic!(
+            "expected the value to be valid\n  errors:\n{}",
+            indented_errors(&errors)
+        );
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_invalid<T>(value: &T, expected: &[(&str, ErrorKind)])
+where
+    T: crate::Validate + ?Sized,
+{
+    let Err(errors) = value.validate() else {
+        panic!("expected the value to be invalid, but it passed the validation");
+    };
+    let actual = error_kinds(&errors);
+    let (missing, _) = difference(&actual, expected);
+    if !missing.is_empty() {
+        panic!(
+            "validation errors do not contain the expected ones\n  missing:\n{}  errors:\n{}",
+            indented_kinds(&missing),
+            indented_errors(&errors)
+        );
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn __assert_errors_eq<T>(value: &T, expected: &[(&str, ErrorKind)])
+where
+    T: crate::Validate + ?Sized,
+{
+    let errors = match value.validate() {
+        Ok(()) => Errors::NewType(vec![]),
+        Err(errors) => errors,
+    };
+    let actual = error_kinds(&errors);
+    let (missing, unexpected) = difference(&actual, expected);
+    if !missing.is_empty() || !unexpected.is_empty() {
+        let mut message = "validation errors are not equal to the expected ones\n".to_string();
+        if !missing.is_empty() {
+            message += &format!("  missing:\n{}", indented_kinds(&missing));
+        }
+        if !unexpected.is_empty() {
+            message += &format!("  unexpected:\n{}", indented_kinds(&unexpected));
+        }
+        panic!("{message}  errors:\n{}", indented_errors(&errors));
+    }
+}
+
+type PathKind<'a> = (&'a str, ErrorKind);
+
+/// The expected errors missing from `actual`, and the errors of `actual` not expected.
+fn difference<'a>(
+    actual: &'a [(String, ErrorKind)],
+    expected: &[(&'a str, ErrorKind)],
+) -> (Vec<PathKind<'a>>, Vec<PathKind<'a>>) {
+    let mut unexpected = actual
+        .iter()
+        .map(|(path, kind)| (path.as_str(), *kind))
+        .collect::<Vec<_>>();
+    let missing = expected
+        .iter()
+        .filter(
+            |error| match unexpected.iter().position(|actual| actual == *error) {
+                Some(index) => {
+                    unexpected.remove(index);
+                    false
+                }
+                None => true,
+            },
+        )
+        .copied()
+        .collect();
+    (missing, unexpected)
+}
+
+fn indented_kinds(kinds: &[PathKind]) -> String {
+    kinds
+        .iter()
+        .map(|(path, kind)| format!("    {}: {kind}\n", display_path(path)))
+        .collect()
+}
+
+fn indented_errors(errors: &Errors) -> String {
+    errors
+        .display()
+        .style(Style::List)
+        .to_string()
+        .lines()
+        .map(|line| format!("    {line}\n"))
+        .collect()
+}
+
+fn display_path(path: &str) -> &str {
+    match path {
+        "" => "(root)",
+        _ => path,
+    }
+}
@@ -0,0 +1,156 @@
+use crate::validation::Errors;
+
+/// A value which has passed the validation.
+///
+/// `Valid<T>` can only be constructed by validating the value, with [`Valid::new`]
+/// or by deserialization, so a function taking `Valid<T>` needs no validation of its own.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::json::{json, FromJsonValue};
+/// use serde_valid::{Valid, Validate};
+///
+/// #[derive(Debug, Deserialize, Validate)]
+/// struct Data {
+///     #[validate(maximum = 10)]
+///     val: i32,
+/// }
+///
+/// fn handle(data: Valid<Data>) -> i32 {
+///     data.val
+/// }
+///
+/// assert_eq!(handle(Valid::new(Data { val: 10 }).unwrap()), 10);
+/// assert!(Valid::new(Data { val: 11 }).is_err());
+///
+/// let data = Valid::<Data>::from_json_value(json!({ "val": 10 })).unwrap();
+/// assert_eq!(handle(data), 10);
+/// ```
+///
+/// Deserialization validates the value and reports the errors as a serde error,
+/// so `Valid<T>` can be nested in other deserialized types.
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_valid::json::{json, FromJsonValue};
+/// use serde_valid::{Valid, Validate};
+///
+/// #[derive(Debug, Deserialize, Validate)]
+/// struct Data {
+///     #[validate(maximum = 10)]
+///     val: i32,
+/// }
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Request {
+///     data: Valid<Data>,
+/// }
+///
+/// let err = serde_json::from_value::<Request>(json!({ "data": { "val": 11 } })).unwrap_err();
+///
+/// assert_eq!(
+///     err.to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "val": { "errors": ["The number must be `<= 10`."] }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Valid<T>(T);
+
+impl<T> Valid<T>
+where
+    T: crate::Validate,
+{
+    /// Validate the value.
+    pub fn new(value: T) -> Result<Self, Errors> {
+        value.validate()?;
+        Ok(Self(value))
+    }
+}
+
+impl<T> Valid<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for Valid<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Valid<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> crate::Validate for Valid<T>
+where
+    T: crate::Validate,
+{
+    fn validate(&self) -> Result<(), Errors> {
+        self.0.validate()
+    }
+
+    fn __validate_limited(
+        &self,
+        groups: &crate::Groups<'_>,
+        limit: &crate::ErrorLimit,
+    ) -> Result<(), Errors> {
+        self.0.__validate_limited(groups, limit)
+    }
+
+    fn __validate_rules() -> Option<crate::rules::TypeRules> {
+        T::__validate_rules()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T> crate::AsyncValidate for Valid<T>
+where
+    T: crate::AsyncValidate,
+{
+    async fn __validate_async_limited(
+        &self,
+        concurrency: usize,
+        groups: &crate::Groups<'_>,
+        limit: &crate::ErrorLimit,
+    ) -> Result<(), Errors> {
+        self.0
+            .__validate_async_limited(concurrency, groups, limit)
+            .await
+    }
+}
+
+impl<T> serde::Serialize for Valid<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for Valid<T>
+where
+    T: serde::Deserialize<'de> + crate::Validate,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Self::new(T::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
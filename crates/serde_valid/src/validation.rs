@@ -5,6 +5,7 @@ pub mod error;
 mod generic;
 mod numeric;
 mod object;
+mod rule;
 mod string;
 
 use crate::{
@@ -13,11 +14,13 @@ use crate::{
     MultipleOfError, PatternError,
 };
 pub use composited::Composited;
+pub use custom::FieldErrors;
 
+pub(crate) use array::json_eq;
 pub use array::{ValidateMaxItems, ValidateMinItems, ValidateUniqueItems};
 pub use error::{
-    ArrayErrors, Error, Errors, IntoError, ItemErrorsMap, ItemVecErrorsMap, ObjectErrors,
-    PropertyErrorsMap, PropertyVecErrorsMap, VecErrors,
+    ArrayErrors, Error, ErrorKind, Errors, IntoError, ItemErrorsMap, ItemVecErrorsMap,
+    ObjectErrors, PropertyErrorsMap, PropertyVecErrorsMap, RemoteError, VecErrors,
 };
 pub use generic::ValidateEnumerate;
 use indexmap::IndexMap;
@@ -26,6 +29,7 @@ pub use numeric::{
     ValidateMultipleOf,
 };
 pub use object::{ValidateMaxProperties, ValidateMinProperties};
+pub use rule::{rule_error_by, Leaf, Nested, RuleError, ValidateCompositedRule, ValidationRule};
 pub use serde_valid_literal::{Literal, Number, Pattern};
 pub use string::{ValidateMaxLength, ValidateMinLength, ValidatePattern};
 
@@ -44,6 +48,20 @@ macro_rules! impl_composited_validation_1args {
                     &self,
                     $limit: $limit_type
                 ) -> Result<(), Composited<$Error>>;
+
+                /// The validation counting the errors in `error_limit`, which stops when the limit is reached.
+                #[doc(hidden)]
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: $limit_type,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), Composited<$Error>> {
+                    let result = self.$validate_composited_method($limit);
+                    if let Err(error) = &result {
+                        error_limit.add(error.count());
+                    }
+                    result
+                }
             }
 
             impl<T> $ValidateCompositedTrait for T
@@ -67,22 +85,19 @@ macro_rules! impl_composited_validation_1args {
                     &self,
                     $limit: $limit_type,
                 ) -> Result<(), Composited<$Error>> {
-                    let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                        .iter()
-                        .enumerate()
-                        .filter_map(
-                            |(index, item)| match item.$validate_composited_method($limit) {
-                                Ok(_) => None,
-                                Err(error) => Some((index, error)),
-                            },
-                        )
-                        .collect();
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
+                    self.[<$validate_composited_method _limited>]($limit, &crate::ErrorLimit::unlimited())
+                }
+
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: $limit_type,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), Composited<$Error>> {
+                    composited_items(
+                        self.iter(),
+                        |item, error_limit| item.[<$validate_composited_method _limited>]($limit, error_limit),
+                        error_limit,
+                    )
                 }
             }
 
@@ -94,22 +109,19 @@ macro_rules! impl_composited_validation_1args {
                     &self,
                     $limit: $limit_type,
                 ) -> Result<(), Composited<$Error>> {
-                    let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                        .iter()
-                        .enumerate()
-                        .filter_map(
-                            |(index, item)| match item.$validate_composited_method($limit) {
-                                Ok(_) => None,
-                                Err(error) => Some((index, error)),
-                            },
-                        )
-                        .collect();
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
+                    self.[<$validate_composited_method _limited>]($limit, &crate::ErrorLimit::unlimited())
+                }
+
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: $limit_type,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), Composited<$Error>> {
+                    composited_items(
+                        self.iter(),
+                        |item, error_limit| item.[<$validate_composited_method _limited>]($limit, error_limit),
+                        error_limit,
+                    )
                 }
             }
 
@@ -126,6 +138,17 @@ macro_rules! impl_composited_validation_1args {
                         None => Ok(()),
                     }
                 }
+
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: $limit_type,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), Composited<$Error>> {
+                    match self {
+                        Some(value) => value.[<$validate_composited_method _limited>]($limit, error_limit),
+                        None => Ok(()),
+                    }
+                }
             }
         }
     };
@@ -158,22 +181,19 @@ macro_rules! impl_composited_validation_1args {
                     &self,
                     $limit: $limit_type,
                 ) -> Result<(), Composited<$Error>> {
-                    let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                        .iter()
-                        .enumerate()
-                        .filter_map(
-                            |(index, (_key, value))| match value.$validate_composited_method($limit) {
-                                Ok(_) => None,
-                                Err(error) => Some((index, error)),
-                            },
-                        )
-                        .collect();
-
-                    if errors.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(Composited::Array(errors))
-                    }
+                    self.[<$validate_composited_method _limited>]($limit, &crate::ErrorLimit::unlimited())
+                }
+
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: $limit_type,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), Composited<$Error>> {
+                    composited_items(
+                        self.values(),
+                        |value, error_limit| value.[<$validate_composited_method _limited>]($limit, error_limit),
+                        error_limit,
+                    )
                 }
             }
         }
@@ -186,112 +206,154 @@ macro_rules! impl_composited_validation_1args {
             ) -> Result<(), Composited<$Error:ty>>;
         }
     ) => {
-        pub trait $ValidateCompositedTrait<T> {
-            fn $validate_composited_method(
-                &self,
-                limit: T,
-            ) -> Result<(), crate::validation::Composited<$Error>>;
-        }
-
-        impl<T, U> $ValidateCompositedTrait<T> for Vec<U>
-        where
-            T: Copy,
-            U: $ValidateCompositedTrait<T>,
-        {
-            fn $validate_composited_method(
-                &self,
-                $limit: T,
-            ) -> Result<(), crate::validation::Composited<$Error>> {
-                let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                    .iter()
-                    .enumerate()
-                    .filter_map(
-                        |(index, item)| match item.$validate_composited_method($limit) {
-                            Ok(_) => None,
-                            Err(error) => Some((index, error)),
-                        },
-                    )
-                    .collect();
+        paste::paste! {
+            pub trait $ValidateCompositedTrait<T> {
+                fn $validate_composited_method(
+                    &self,
+                    limit: T,
+                ) -> Result<(), crate::validation::Composited<$Error>>;
 
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(Composited::Array(errors))
+                /// The validation counting the errors in `error_limit`, which stops when the limit is reached.
+                #[doc(hidden)]
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: T,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), crate::validation::Composited<$Error>> {
+                    let result = self.$validate_composited_method($limit);
+                    if let Err(error) = &result {
+                        error_limit.add(error.count());
+                    }
+                    result
                 }
             }
-        }
 
-        impl<T, K, V> $ValidateCompositedTrait<T> for std::collections::HashMap<K, V>
-        where
-            T: Copy,
-            V: $ValidateCompositedTrait<T>,
-        {
-            fn $validate_composited_method(&self, $limit: T) -> Result<(), Composited<$Error>> {
-                let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(index, (_key, value))| {
-                        match value.$validate_composited_method($limit) {
-                            Ok(_) => None,
-                            Err(error) => Some((index, error)),
-                        }
-                    })
-                    .collect();
-
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(Composited::Array(errors))
+            impl<T, U> $ValidateCompositedTrait<T> for Vec<U>
+            where
+                T: Copy,
+                U: $ValidateCompositedTrait<T>,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: T,
+                ) -> Result<(), crate::validation::Composited<$Error>> {
+                    self.[<$validate_composited_method _limited>]($limit, &crate::ErrorLimit::unlimited())
+                }
+
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: T,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), crate::validation::Composited<$Error>> {
+                    composited_items(
+                        self.iter(),
+                        |item, error_limit| item.[<$validate_composited_method _limited>]($limit, error_limit),
+                        error_limit,
+                    )
                 }
             }
-        }
 
-        impl<T, U, const N: usize> $ValidateCompositedTrait<T> for [U; N]
-        where
-            T: Copy,
-            U: $ValidateCompositedTrait<T>,
-        {
-            fn $validate_composited_method(
-                &self,
-                $limit: T,
-            ) -> Result<(), crate::validation::Composited<$Error>> {
-                let errors: IndexMap<usize, crate::validation::Composited<$Error>> = self
-                    .iter()
-                    .enumerate()
-                    .filter_map(
-                        |(index, item)| match item.$validate_composited_method($limit) {
-                            Ok(_) => None,
-                            Err(error) => Some((index, error)),
-                        },
+            impl<T, K, V> $ValidateCompositedTrait<T> for std::collections::HashMap<K, V>
+            where
+                T: Copy,
+                V: $ValidateCompositedTrait<T>,
+            {
+                fn $validate_composited_method(&self, $limit: T) -> Result<(), Composited<$Error>> {
+                    self.[<$validate_composited_method _limited>]($limit, &crate::ErrorLimit::unlimited())
+                }
+
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: T,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), Composited<$Error>> {
+                    composited_items(
+                        self.values(),
+                        |value, error_limit| value.[<$validate_composited_method _limited>]($limit, error_limit),
+                        error_limit,
                     )
-                    .collect();
+                }
+            }
 
-                if errors.is_empty() {
-                    Ok(())
-                } else {
-                    Err(Composited::Array(errors))
+            impl<T, U, const N: usize> $ValidateCompositedTrait<T> for [U; N]
+            where
+                T: Copy,
+                U: $ValidateCompositedTrait<T>,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    $limit: T,
+                ) -> Result<(), crate::validation::Composited<$Error>> {
+                    self.[<$validate_composited_method _limited>]($limit, &crate::ErrorLimit::unlimited())
+                }
+
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: T,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), crate::validation::Composited<$Error>> {
+                    composited_items(
+                        self.iter(),
+                        |item, error_limit| item.[<$validate_composited_method _limited>]($limit, error_limit),
+                        error_limit,
+                    )
                 }
             }
-        }
 
-        impl<T, U> $ValidateCompositedTrait<T> for Option<U>
-        where
-            T: Copy,
-            U: $ValidateCompositedTrait<T>,
-        {
-            fn $validate_composited_method(
-                &self,
-                limit: T,
-            ) -> Result<(), crate::validation::Composited<$Error>> {
-                match self {
-                    Some(value) => value.$validate_composited_method(limit),
-                    None => Ok(()),
+            impl<T, U> $ValidateCompositedTrait<T> for Option<U>
+            where
+                T: Copy,
+                U: $ValidateCompositedTrait<T>,
+            {
+                fn $validate_composited_method(
+                    &self,
+                    limit: T,
+                ) -> Result<(), crate::validation::Composited<$Error>> {
+                    match self {
+                        Some(value) => value.$validate_composited_method(limit),
+                        None => Ok(()),
+                    }
+                }
+
+                fn [<$validate_composited_method _limited>](
+                    &self,
+                    $limit: T,
+                    error_limit: &crate::ErrorLimit,
+                ) -> Result<(), crate::validation::Composited<$Error>> {
+                    match self {
+                        Some(value) => value.[<$validate_composited_method _limited>]($limit, error_limit),
+                        None => Ok(()),
+                    }
                 }
             }
         }
     };
 }
 
+/// The composited errors of the items, stopping when the limit of the errors is reached.
+fn composited_items<'a, T: 'a, E>(
+    items: impl Iterator<Item = &'a T>,
+    mut validate: impl FnMut(&'a T, &crate::ErrorLimit) -> Result<(), Composited<E>>,
+    error_limit: &crate::ErrorLimit,
+) -> Result<(), Composited<E>> {
+    let mut errors = IndexMap::new();
+
+    for (index, item) in items.enumerate() {
+        if error_limit.is_reached() {
+            break;
+        }
+        if let Err(error) = validate(item, error_limit) {
+            errors.insert(index, error);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Composited::Array(errors))
+    }
+}
+
 macro_rules! impl_generic_composited_validation_1args {
     (
         $ErrorType:ident,
@@ -4,6 +4,7 @@ mod unique_items;
 
 pub use max_items::ValidateMaxItems;
 pub use min_items::ValidateMinItems;
+pub(crate) use unique_items::json_eq;
 pub use unique_items::ValidateUniqueItems;
 
 use crate::{MaxItemsError, MinItemsError};
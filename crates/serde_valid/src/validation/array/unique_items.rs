@@ -81,6 +81,39 @@ where
     }
 }
 
+/// The items are compared as JSON Schema does, where e.g. `1` and `1.0` are equal.
+impl ValidateUniqueItems for [serde_json::Value] {
+    fn validate_unique_items(&self) -> Result<(), crate::UniqueItemsError> {
+        let is_unique = self
+            .iter()
+            .enumerate()
+            .all(|(index, item)| !self[index + 1..].iter().any(|other| json_eq(item, other)));
+        if is_unique {
+            Ok(())
+        } else {
+            Err(crate::UniqueItemsError {})
+        }
+    }
+}
+
+/// Equality of JSON values, where the numbers are equal if their values are.
+pub(crate) fn json_eq(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    match (a, b) {
+        (serde_json::Value::Number(a), serde_json::Value::Number(b)) => {
+            a == b || a.as_f64() == b.as_f64()
+        }
+        (serde_json::Value::Array(a), serde_json::Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| json_eq(a, b))
+        }
+        (serde_json::Value::Object(a), serde_json::Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get(key).is_some_and(|b| json_eq(a, b)))
+        }
+        (a, b) => a == b,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +132,16 @@ mod tests {
     fn test_validate_array_unique_items_is_false() {
         assert!(ValidateUniqueItems::validate_unique_items(&[1, 2, 3, 3]).is_err());
     }
+
+    #[test]
+    fn test_validate_array_unique_items_json_values() {
+        let values = vec![serde_json::json!({"a": 1}), serde_json::json!({"a": 2})];
+        assert!(ValidateUniqueItems::validate_unique_items(values.as_slice()).is_ok());
+
+        let values = vec![serde_json::json!([1]), serde_json::json!([1])];
+        assert!(ValidateUniqueItems::validate_unique_items(values.as_slice()).is_err());
+
+        let values = vec![serde_json::json!(1), serde_json::json!(1.0)];
+        assert!(ValidateUniqueItems::validate_unique_items(values.as_slice()).is_err());
+    }
 }
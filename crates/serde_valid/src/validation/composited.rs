@@ -28,6 +28,16 @@ pub enum Composited<Error> {
     Array(IndexMap<usize, Composited<Error>>),
 }
 
+impl<Error> Composited<Error> {
+    /// The number of the errors.
+    pub fn count(&self) -> usize {
+        match self {
+            Composited::Single(_) => 1,
+            Composited::Array(array) => array.values().map(Composited::count).sum(),
+        }
+    }
+}
+
 macro_rules! impl_into_error {
     ($ErrorType:ident) => {
         paste::paste! {
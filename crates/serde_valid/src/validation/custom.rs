@@ -1,3 +1,11 @@
+mod field_errors;
+
+use crate::validation::error::Format;
+use crate::validation::{ArrayErrors, Errors, ObjectErrors};
+#[doc(hidden)]
+pub use field_errors::extend_field_errors;
+pub use field_errors::FieldErrors;
+
 /// This function is used to avoid [rustc(E0282)](https://doc.rust-lang.org/error_codes/E0282.html) error in `#[validate(custom = ...)]` validator on the struct.
 #[inline]
 pub fn wrap_closure_validation<T: ?Sized, M: IntoVecErrors>(
@@ -7,6 +15,35 @@ pub fn wrap_closure_validation<T: ?Sized, M: IntoVecErrors>(
     f(data).map_err(|e| e.into_vec_errors())
 }
 
+/// [`wrap_closure_validation`] of the struct with `#[validate(context = ...)]`.
+#[inline]
+pub fn wrap_closure_validation_with_context<T: ?Sized, C: ?Sized, M: IntoVecErrors>(
+    data: &T,
+    context: &C,
+    f: impl FnOnce(&T, &C) -> Result<(), M>,
+) -> Result<(), Vec<crate::validation::Error>> {
+    f(data, context).map_err(|e| e.into_vec_errors())
+}
+
+/// This function is used to call the validator returned by `#[validate(custom = f(...))]` on the struct.
+#[inline]
+pub fn wrap_call_validation<T: ?Sized, M: IntoVecErrors>(
+    data: &T,
+    f: impl FnOnce(&T) -> Result<(), M>,
+) -> Result<(), Vec<crate::validation::Error>> {
+    f(data).map_err(|e| e.into_vec_errors())
+}
+
+/// [`wrap_call_validation`] of the struct with `#[validate(context = ...)]`.
+#[inline]
+pub fn wrap_call_validation_with_context<T: ?Sized, C: ?Sized, M: IntoVecErrors>(
+    data: &T,
+    context: &C,
+    f: impl FnOnce(&T, &C) -> Result<(), M>,
+) -> Result<(), Vec<crate::validation::Error>> {
+    f(data, context).map_err(|e| e.into_vec_errors())
+}
+
 #[inline]
 pub fn wrap_into_vec_errors<M: IntoVecErrors>(
     result: Result<(), M>,
@@ -14,6 +51,76 @@ pub fn wrap_into_vec_errors<M: IntoVecErrors>(
     result.map_err(|e| e.into_vec_errors())
 }
 
+/// The errors of a custom validator with the message of `#[validate(custom = ..., message = ...)]`.
+///
+/// Every error is replaced by the message, and `message_fn` takes the original error.
+/// The errors of the fields in [`FieldErrors`] are replaced where they are.
+pub fn custom_errors_by(
+    errors: Vec<crate::validation::Error>,
+    format: Format<crate::validation::Error>,
+) -> Vec<crate::validation::Error> {
+    match format {
+        Format::Default => errors,
+        format => errors
+            .into_iter()
+            .map(|error| custom_error_by(error, &format))
+            .collect(),
+    }
+}
+
+fn custom_error_by(
+    error: crate::validation::Error,
+    format: &Format<crate::validation::Error>,
+) -> crate::validation::Error {
+    match (error, format) {
+        (error, Format::Default) => error,
+        (crate::validation::Error::Items(array), _) => {
+            crate::validation::Error::Items(ArrayErrors::new(
+                custom_errors_by(array.errors, format.clone()),
+                array
+                    .items
+                    .into_iter()
+                    .map(|(index, errors)| (index, nested_custom_errors_by(errors, format)))
+                    .collect(),
+            ))
+        }
+        (crate::validation::Error::Properties(object), _) => {
+            crate::validation::Error::Properties(ObjectErrors::new(
+                custom_errors_by(object.errors, format.clone()),
+                object
+                    .properties
+                    .into_iter()
+                    .map(|(property, errors)| (property, nested_custom_errors_by(errors, format)))
+                    .collect(),
+            ))
+        }
+        (_, Format::Message(message)) => crate::validation::Error::Custom(message.clone()),
+        (error, Format::MessageFn(message_fn)) => {
+            crate::validation::Error::Custom(message_fn(&error))
+        }
+        #[cfg(feature = "fluent")]
+        (_, Format::Fluent(message)) => crate::validation::Error::Fluent(message.clone()),
+    }
+}
+
+fn nested_custom_errors_by(errors: Errors, format: &Format<crate::validation::Error>) -> Errors {
+    match errors {
+        Errors::Array(array) => {
+            match custom_error_by(crate::validation::Error::Items(array), format) {
+                crate::validation::Error::Items(array) => Errors::Array(array),
+                _ => unreachable!(),
+            }
+        }
+        Errors::Object(object) => {
+            match custom_error_by(crate::validation::Error::Properties(object), format) {
+                crate::validation::Error::Properties(object) => Errors::Object(object),
+                _ => unreachable!(),
+            }
+        }
+        Errors::NewType(errors) => Errors::NewType(custom_errors_by(errors, format.clone())),
+    }
+}
+
 pub trait IntoVecErrors {
     fn into_vec_errors(self) -> Vec<crate::validation::Error>;
 }
@@ -51,6 +158,24 @@ mod test {
         assert!(wrap_closure_validation(&-1i32, single_error).is_err());
     }
 
+    #[test]
+    fn test_custom_call_validation() {
+        fn maximum(max: i32) -> impl FnOnce(&i32) -> Result<(), crate::validation::Error> {
+            move |data: &i32| {
+                if *data <= max {
+                    Ok(())
+                } else {
+                    Err(crate::validation::Error::Custom(format!(
+                        "Value must be less than or equal to {max}"
+                    )))
+                }
+            }
+        }
+
+        assert!(wrap_call_validation(&10i32, maximum(10)).is_ok());
+        assert!(wrap_call_validation(&11i32, maximum(10)).is_err());
+    }
+
     #[test]
     fn test_custom_fn_multiple_errors() {
         fn multiple_errors(data: &i32) -> Result<(), Vec<crate::validation::Error>> {
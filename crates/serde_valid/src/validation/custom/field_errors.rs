@@ -0,0 +1,189 @@
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+
+use super::IntoVecErrors;
+use crate::validation::{
+    ArrayErrors, Error, Errors, ObjectErrors, PropertyVecErrorsMap, VecErrors,
+};
+
+/// Errors of a struct-level custom validation, attached to the fields of the struct.
+///
+/// The field names are the names of the Rust fields, which are renamed by `#[serde(rename = ...)]`.
+/// The other segments of [`FieldErrors::at_path`] are the keys of the errors as they are.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::validation::{Error, FieldErrors};
+/// use serde_valid::Validate;
+///
+/// fn period_validation(period: &Period) -> Result<(), FieldErrors> {
+///     let mut errors = FieldErrors::new();
+///     if period.end <= period.start {
+///         errors = errors.at("end", Error::Custom("end must be after start.".to_owned()));
+///     }
+///     errors.into_result()
+/// }
+///
+/// #[derive(serde::Deserialize, Validate)]
+/// #[validate(custom = period_validation)]
+/// struct Period {
+///     start: i32,
+///     #[serde(rename = "endAt")]
+///     end: i32,
+/// }
+///
+/// assert_eq!(
+///     Period { start: 2, end: 1 }.validate().unwrap_err().to_string(),
+///     json!({
+///         "errors": [],
+///         "properties": {
+///             "endAt": { "errors": ["end must be after start."] }
+///         }
+///     })
+///     .to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FieldErrors {
+    errors: VecErrors,
+    properties: PropertyVecErrorsMap<Error>,
+}
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the errors of the struct itself.
+    pub fn root(mut self, errors: impl IntoVecErrors) -> Self {
+        self.errors.extend(errors.into_vec_errors());
+        self
+    }
+
+    /// Add the errors of the field.
+    pub fn at(mut self, field: impl Into<Cow<'static, str>>, errors: impl IntoVecErrors) -> Self {
+        self.properties
+            .entry(field.into())
+            .or_default()
+            .extend(errors.into_vec_errors());
+        self
+    }
+
+    /// Add the errors at the JSON Pointer ([RFC 6901](https://www.rfc-editor.org/rfc/rfc6901)),
+    /// e.g. `"/items/3/price"`, where the numbers are the indexes of arrays,
+    /// or the keys of maps when the errors are merged with the ones of the properties.
+    pub fn at_path(self, path: &str, errors: impl IntoVecErrors) -> Self {
+        if path.is_empty() {
+            return self.root(errors);
+        }
+
+        let mut tokens = path
+            .strip_prefix('/')
+            .unwrap_or(path)
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect::<Vec<_>>();
+
+        let field = tokens.remove(0);
+        let errors = errors.into_vec_errors();
+        match nested_errors(&tokens, errors) {
+            Errors::NewType(errors) => self.at(field, errors),
+            Errors::Array(array) => self.at(field, Error::Items(array)),
+            Errors::Object(object) => self.at(field, Error::Properties(object)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.properties.is_empty()
+    }
+
+    /// `Ok(())` if there are no errors.
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+/// The errors of the fields are in [`Error::Properties`].
+impl IntoVecErrors for FieldErrors {
+    fn into_vec_errors(self) -> Vec<Error> {
+        let mut errors = self.errors;
+        if !self.properties.is_empty() {
+            errors.push(Error::Properties(ObjectErrors::new(
+                vec![],
+                self.properties
+                    .into_iter()
+                    .map(|(field, errors)| (field, Errors::NewType(errors)))
+                    .collect(),
+            )));
+        }
+        errors
+    }
+}
+
+fn nested_errors(tokens: &[String], errors: VecErrors) -> Errors {
+    match tokens.split_first() {
+        None => Errors::NewType(errors),
+        Some((token, rest)) => {
+            let nested = nested_errors(rest, errors);
+            match token.parse::<usize>() {
+                Ok(index) => {
+                    Errors::Array(ArrayErrors::new(vec![], IndexMap::from([(index, nested)])))
+                }
+                Err(_) => Errors::Object(ObjectErrors::new(
+                    vec![],
+                    IndexMap::from([(Cow::Owned(token.to_owned()), nested)]),
+                )),
+            }
+        }
+    }
+}
+
+/// Move the errors of [`FieldErrors`] from the errors of the struct to the errors of its fields.
+#[doc(hidden)]
+pub fn extend_field_errors(
+    rule_vec_errors: &mut VecErrors,
+    property_vec_errors_map: &mut PropertyVecErrorsMap<Error>,
+    rename: impl Fn(&str) -> Option<Cow<'static, str>>,
+) {
+    for error in std::mem::take(rule_vec_errors) {
+        let Error::Properties(object) = error else {
+            rule_vec_errors.push(error);
+            continue;
+        };
+
+        rule_vec_errors.extend(object.errors);
+        for (field, errors) in object.properties {
+            let field = rename(&field).unwrap_or(field);
+            property_vec_errors_map
+                .entry(field)
+                .or_default()
+                .extend(into_vec_errors(errors));
+        }
+    }
+}
+
+fn into_vec_errors(errors: Errors) -> VecErrors {
+    match errors {
+        Errors::NewType(errors) => errors,
+        Errors::Array(array) if array.items.is_empty() => array.errors,
+        Errors::Array(array) => {
+            let mut errors = array.errors;
+            errors.push(Error::Items(ArrayErrors::new(vec![], array.items)));
+            errors
+        }
+        Errors::Object(object) if object.properties.is_empty() => object.errors,
+        Errors::Object(object) => {
+            let mut errors = object.errors;
+            errors.push(Error::Properties(ObjectErrors::new(
+                vec![],
+                object.properties,
+            )));
+            errors
+        }
+    }
+}
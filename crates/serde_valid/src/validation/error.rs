@@ -1,9 +1,11 @@
 mod array_erros;
+mod display;
 mod errors;
 mod format;
 mod into_error;
 mod message;
 mod object_errors;
+mod remote_error;
 
 use std::borrow::Cow;
 
@@ -13,12 +15,14 @@ pub use crate::error::{
     MinimumError, MultipleOfError, PatternError, UniqueItemsError,
 };
 pub use array_erros::ArrayErrors;
+pub use display::{escape_pointer_token, ErrorsDisplay, NestedError, NestedErrors, Style};
 pub use errors::Errors;
 pub use format::{Format, FormatDefault};
 use indexmap::IndexMap;
 pub use into_error::IntoError;
 pub use message::Message;
 pub use object_errors::ObjectErrors;
+pub use remote_error::RemoteError;
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
 #[serde(untagged)]
@@ -79,6 +83,11 @@ pub enum Error {
     #[serde(serialize_with = "serialize_error_message")]
     Enumerate(Message<EnumerateError>),
 
+    /// The error of a [`ValidationRule`](crate::validation::ValidationRule).
+    #[error("{0}")]
+    #[serde(serialize_with = "serialize_error_message")]
+    Rule(Message<crate::validation::RuleError>),
+
     #[error("{0}")]
     #[serde(serialize_with = "serialize_error_message")]
     Custom(String),
@@ -95,6 +104,140 @@ pub enum Error {
     Fluent(crate::fluent::Message),
 }
 
+impl Error {
+    /// The kind of the validation that raised this error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Minimum(_) => ErrorKind::Minimum,
+            Self::Maximum(_) => ErrorKind::Maximum,
+            Self::ExclusiveMinimum(_) => ErrorKind::ExclusiveMinimum,
+            Self::ExclusiveMaximum(_) => ErrorKind::ExclusiveMaximum,
+            Self::MultipleOf(_) => ErrorKind::MultipleOf,
+            Self::MinLength(_) => ErrorKind::MinLength,
+            Self::MaxLength(_) => ErrorKind::MaxLength,
+            Self::Pattern(_) => ErrorKind::Pattern,
+            Self::MinItems(_) => ErrorKind::MinItems,
+            Self::MaxItems(_) => ErrorKind::MaxItems,
+            Self::UniqueItems(_) => ErrorKind::UniqueItems,
+            Self::MinProperties(_) => ErrorKind::MinProperties,
+            Self::MaxProperties(_) => ErrorKind::MaxProperties,
+            Self::Enumerate(_) => ErrorKind::Enumerate,
+            Self::Rule(_) => ErrorKind::Rule,
+            Self::Custom(_) => ErrorKind::Custom,
+            Self::Items(_) => ErrorKind::Items,
+            Self::Properties(_) => ErrorKind::Properties,
+            #[cfg(feature = "fluent")]
+            Self::Fluent(_) => ErrorKind::Fluent,
+        }
+    }
+
+    /// The name of the validation that raised this error, e.g. `"maximum"` or `"min_length"`.
+    ///
+    /// For the errors of a [`ValidationRule`](crate::validation::ValidationRule), the name of the rule.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Rule(message) => message.error().name(),
+            _ => self.kind().code(),
+        }
+    }
+
+    /// The parameters of the validation that raised this error, e.g. `{"maximum": 10}`.
+    pub fn params(&self) -> serde_json::Map<String, serde_json::Value> {
+        match self {
+            Self::Minimum(message) => error_params(message.error()),
+            Self::Maximum(message) => error_params(message.error()),
+            Self::ExclusiveMinimum(message) => error_params(message.error()),
+            Self::ExclusiveMaximum(message) => error_params(message.error()),
+            Self::MultipleOf(message) => error_params(message.error()),
+            Self::MinLength(message) => error_params(message.error()),
+            Self::MaxLength(message) => error_params(message.error()),
+            Self::Pattern(message) => error_params(message.error()),
+            Self::MinItems(message) => error_params(message.error()),
+            Self::MaxItems(message) => error_params(message.error()),
+            Self::UniqueItems(message) => error_params(message.error()),
+            Self::MinProperties(message) => error_params(message.error()),
+            Self::MaxProperties(message) => error_params(message.error()),
+            Self::Enumerate(message) => error_params(message.error()),
+            Self::Rule(message) => message.error().params().clone(),
+            Self::Custom(_) | Self::Items(_) | Self::Properties(_) => serde_json::Map::new(),
+            #[cfg(feature = "fluent")]
+            Self::Fluent(message) => message.params(),
+        }
+    }
+}
+
+/// The kind of an [`Error`], without its parameters and message.
+///
+/// The kinds depend on the features, e.g. `Fluent` with `fluent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    Minimum,
+    Maximum,
+    ExclusiveMinimum,
+    ExclusiveMaximum,
+    MultipleOf,
+    MinLength,
+    MaxLength,
+    Pattern,
+    MinItems,
+    MaxItems,
+    UniqueItems,
+    MinProperties,
+    MaxProperties,
+    Enumerate,
+    Rule,
+    Custom,
+    Items,
+    Properties,
+    #[cfg(feature = "fluent")]
+    Fluent,
+}
+
+impl ErrorKind {
+    /// The name of the validation, e.g. `"maximum"` or `"min_length"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Minimum => "minimum",
+            Self::Maximum => "maximum",
+            Self::ExclusiveMinimum => "exclusive_minimum",
+            Self::ExclusiveMaximum => "exclusive_maximum",
+            Self::MultipleOf => "multiple_of",
+            Self::MinLength => "min_length",
+            Self::MaxLength => "max_length",
+            Self::Pattern => "pattern",
+            Self::MinItems => "min_items",
+            Self::MaxItems => "max_items",
+            Self::UniqueItems => "unique_items",
+            Self::MinProperties => "min_properties",
+            Self::MaxProperties => "max_properties",
+            Self::Enumerate => "enumerate",
+            Self::Rule => "rule",
+            Self::Custom => "custom",
+            Self::Items => "items",
+            Self::Properties => "properties",
+            #[cfg(feature = "fluent")]
+            Self::Fluent => "fluent",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+fn error_params<T>(error: &T) -> serde_json::Map<String, serde_json::Value>
+where
+    T: serde::Serialize,
+{
+    match serde_json::to_value(error) {
+        Ok(serde_json::Value::Object(params)) => params,
+        _ => serde_json::Map::new(),
+    }
+}
+
 fn serialize_error_message<T, S>(message: &T, serializer: S) -> Result<S::Ok, S::Error>
 where
     T: std::fmt::Display,
@@ -23,6 +23,27 @@ where
     }
 }
 
+impl<'de, E> serde::Deserialize<'de> for ArrayErrors<E>
+where
+    E: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(bound = "E: serde::Deserialize<'de>")]
+        struct ArrayErrorsRepr<E> {
+            #[serde(default)]
+            errors: VecErrors<E>,
+            items: ItemErrorsMap<E>,
+        }
+
+        let ArrayErrorsRepr { errors, items } = ArrayErrorsRepr::deserialize(deserializer)?;
+        Ok(Self::new(errors, items))
+    }
+}
+
 impl<E> ArrayErrors<E> {
     pub fn new(errors: VecErrors<E>, items: ItemErrorsMap<E>) -> Self {
         Self { errors, items }
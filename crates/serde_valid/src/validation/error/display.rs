@@ -0,0 +1,389 @@
+use super::{ArrayErrors, Errors, ObjectErrors, VecErrors};
+
+/// Rendering style of [`ErrorsDisplay`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Style {
+    /// Compact JSON, same as `Errors::to_string()`.
+    #[default]
+    Json,
+
+    /// Indented tree view.
+    ///
+    /// ```text
+    /// - The value is invalid.
+    /// val:
+    ///   - The number must be `<= 10`.
+    /// items:
+    ///   0:
+    ///     - The length of the value must be `<= 2`.
+    /// ```
+    Tree,
+
+    /// One line per error, prefixed by the JSON Pointer of the value.
+    ///
+    /// ```text
+    /// (root): The value is invalid.
+    /// /val: The number must be `<= 10`.
+    /// /items/0: The length of the value must be `<= 2`.
+    /// ```
+    List,
+}
+
+/// Renderer of [`Errors`], created by [`Errors::display`].
+///
+/// ```rust
+/// use serde_valid::Validate;
+/// use serde_valid::validation::error::Style;
+///
+/// #[derive(Validate)]
+/// struct Data {
+///     #[validate(maximum = 10)]
+///     val: i32,
+///     #[validate(max_length = 2)]
+///     items: Vec<String>,
+/// }
+///
+/// let errors = Data { val: 11, items: vec!["abc".to_string()] }
+///     .validate()
+///     .unwrap_err();
+///
+/// assert_eq!(
+///     errors.display().style(Style::List).to_string(),
+///     "/val: The number must be `<= 10`.\n/items/0: The length of the value must be `<= 2`."
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorsDisplay<'a, E> {
+    errors: &'a Errors<E>,
+    style: Style,
+    #[cfg(feature = "colored")]
+    colored: bool,
+}
+
+impl<'a, E> ErrorsDisplay<'a, E> {
+    pub fn new(errors: &'a Errors<E>) -> Self {
+        Self {
+            errors,
+            style: Style::default(),
+            #[cfg(feature = "colored")]
+            colored: false,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Highlight the paths and messages with ANSI escape codes.
+    ///
+    /// Only affects [`Style::Tree`] and [`Style::List`].
+    #[cfg(feature = "colored")]
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+
+    fn paint_path(&self, path: &str) -> String {
+        #[cfg(feature = "colored")]
+        if self.colored {
+            return format!("\x1b[1;36m{path}\x1b[0m");
+        }
+        path.to_string()
+    }
+
+    fn paint_message(&self, message: &str) -> String {
+        #[cfg(feature = "colored")]
+        if self.colored {
+            return format!("\x1b[31m{message}\x1b[0m");
+        }
+        message.to_string()
+    }
+}
+
+/// The errors of the items or the properties nested in an error,
+/// as [`Error::Items`](crate::validation::Error::Items)
+/// and [`Error::Properties`](crate::validation::Error::Properties).
+pub enum NestedErrors<'a, E> {
+    Items(&'a ArrayErrors<E>),
+    Properties(&'a ObjectErrors<E>),
+}
+
+/// An error which can nest the errors of the items or the properties,
+/// which [`Errors::flatten`] and [`ErrorsDisplay`] expand at their own paths.
+pub trait NestedError: Sized {
+    fn nested_errors(&self) -> Option<NestedErrors<'_, Self>> {
+        None
+    }
+}
+
+impl NestedError for crate::validation::Error {
+    fn nested_errors(&self) -> Option<NestedErrors<'_, Self>> {
+        match self {
+            Self::Items(items) => Some(NestedErrors::Items(items)),
+            Self::Properties(properties) => Some(NestedErrors::Properties(properties)),
+            _ => None,
+        }
+    }
+}
+
+impl NestedError for super::RemoteError {}
+
+impl<E> Errors<E> {
+    /// Render the errors in another [`Style`] than the default JSON.
+    pub fn display(&self) -> ErrorsDisplay<'_, E> {
+        ErrorsDisplay::new(self)
+    }
+}
+
+impl<E> Errors<E>
+where
+    E: NestedError,
+{
+    /// Every error with the JSON Pointer of the value it belongs to.
+    ///
+    /// The errors of the root value have an empty pointer.
+    pub fn flatten(&self) -> Vec<(String, &E)> {
+        let mut flatten = vec![];
+        flatten_errors(self, String::new(), &mut flatten);
+        flatten
+    }
+}
+
+fn flatten_errors<'a, E: NestedError>(
+    errors: &'a Errors<E>,
+    pointer: String,
+    flatten: &mut Vec<(String, &'a E)>,
+) {
+    let (vec_errors, children) = split_errors(errors);
+    flatten.extend(vec_errors.into_iter().map(|error| (pointer.clone(), error)));
+    for (key, child) in children {
+        flatten_errors(
+            child,
+            format!("{pointer}/{}", escape_pointer_token(&key)),
+            flatten,
+        );
+    }
+}
+
+/// The errors of the value itself and the errors of its children,
+/// including the children of the nested errors.
+fn split_errors<E: NestedError>(errors: &Errors<E>) -> (Vec<&E>, Vec<(String, &Errors<E>)>) {
+    let mut vec_errors = vec![];
+    let mut children = vec![];
+    match errors {
+        Errors::Array(array) => split_array_errors(array, &mut vec_errors, &mut children),
+        Errors::Object(object) => split_object_errors(object, &mut vec_errors, &mut children),
+        Errors::NewType(errors) => split_vec_errors(errors, &mut vec_errors, &mut children),
+    }
+    (vec_errors, children)
+}
+
+fn split_vec_errors<'a, E: NestedError>(
+    errors: &'a VecErrors<E>,
+    vec_errors: &mut Vec<&'a E>,
+    children: &mut Vec<(String, &'a Errors<E>)>,
+) {
+    for error in errors {
+        match error.nested_errors() {
+            Some(NestedErrors::Items(array)) => split_array_errors(array, vec_errors, children),
+            Some(NestedErrors::Properties(object)) => {
+                split_object_errors(object, vec_errors, children)
+            }
+            None => vec_errors.push(error),
+        }
+    }
+}
+
+fn split_array_errors<'a, E: NestedError>(
+    array: &'a ArrayErrors<E>,
+    vec_errors: &mut Vec<&'a E>,
+    children: &mut Vec<(String, &'a Errors<E>)>,
+) {
+    split_vec_errors(&array.errors, vec_errors, children);
+    children.extend(
+        array
+            .items
+            .iter()
+            .map(|(index, item)| (index.to_string(), item)),
+    );
+}
+
+fn split_object_errors<'a, E: NestedError>(
+    object: &'a ObjectErrors<E>,
+    vec_errors: &mut Vec<&'a E>,
+    children: &mut Vec<(String, &'a Errors<E>)>,
+) {
+    split_vec_errors(&object.errors, vec_errors, children);
+    children.extend(
+        object
+            .properties
+            .iter()
+            .map(|(property, item)| (property.to_string(), item)),
+    );
+}
+
+/// Escape a reference token of JSON Pointer ([RFC 6901](https://www.rfc-editor.org/rfc/rfc6901)).
+pub fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+impl<E> ErrorsDisplay<'_, E>
+where
+    E: std::fmt::Display + NestedError,
+{
+    fn fmt_tree(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        errors: &Errors<E>,
+        depth: usize,
+        first_line: &mut bool,
+    ) -> std::fmt::Result {
+        let (vec_errors, children) = split_errors(errors);
+
+        let indent = "  ".repeat(depth);
+        for error in vec_errors {
+            self.fmt_line(f, first_line)?;
+            write!(f, "{indent}- {}", self.paint_message(&error.to_string()))?;
+        }
+        for (key, child) in children {
+            self.fmt_line(f, first_line)?;
+            write!(f, "{indent}{}:", self.paint_path(&key))?;
+            self.fmt_tree(f, child, depth + 1, first_line)?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_list(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first_line = true;
+        for (pointer, error) in self.errors.flatten() {
+            self.fmt_line(f, &mut first_line)?;
+            let path = if pointer.is_empty() {
+                "(root)"
+            } else {
+                &pointer
+            };
+            write!(
+                f,
+                "{}: {}",
+                self.paint_path(path),
+                self.paint_message(&error.to_string())
+            )?;
+        }
+        Ok(())
+    }
+
+    fn fmt_line(&self, f: &mut std::fmt::Formatter<'_>, first_line: &mut bool) -> std::fmt::Result {
+        if *first_line {
+            *first_line = false;
+            Ok(())
+        } else {
+            writeln!(f)
+        }
+    }
+}
+
+impl<E> std::fmt::Display for ErrorsDisplay<'_, E>
+where
+    E: serde::Serialize + std::fmt::Display + NestedError,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.style {
+            Style::Json => std::fmt::Display::fmt(self.errors, f),
+            Style::Tree => self.fmt_tree(f, self.errors, 0, &mut true),
+            Style::List => self.fmt_list(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::error::Format;
+    use crate::MaximumError;
+    use indexmap::IndexMap;
+    use std::borrow::Cow;
+
+    fn errors() -> Errors {
+        Errors::Object(ObjectErrors::new(
+            vec![crate::validation::Error::Custom(
+                "The value is invalid.".to_string(),
+            )],
+            IndexMap::from([
+                (
+                    Cow::from("val"),
+                    Errors::NewType(vec![crate::validation::Error::Maximum(
+                        Format::Default.into_message(MaximumError::new(10)),
+                    )]),
+                ),
+                (
+                    Cow::from("a/b"),
+                    Errors::Array(ArrayErrors::new(
+                        vec![],
+                        IndexMap::from([(
+                            0,
+                            Errors::NewType(vec![crate::validation::Error::Custom(
+                                "The item is invalid.".to_string(),
+                            )]),
+                        )]),
+                    )),
+                ),
+            ]),
+        ))
+    }
+
+    #[test]
+    fn display_json_is_default() {
+        let errors = errors();
+
+        assert_eq!(errors.display().to_string(), errors.to_string());
+    }
+
+    #[test]
+    fn display_tree() {
+        assert_eq!(
+            errors().display().style(Style::Tree).to_string(),
+            [
+                "- The value is invalid.",
+                "val:",
+                "  - The number must be `<= 10`.",
+                "a/b:",
+                "  0:",
+                "    - The item is invalid.",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn display_list() {
+        assert_eq!(
+            errors().display().style(Style::List).to_string(),
+            [
+                "(root): The value is invalid.",
+                "/val: The number must be `<= 10`.",
+                "/a~1b/0: The item is invalid.",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[cfg(feature = "colored")]
+    #[test]
+    fn display_colored_list() {
+        let errors =
+            Errors::<crate::validation::Error>::NewType(vec![crate::validation::Error::Custom(
+                "The value is invalid.".to_string(),
+            )]);
+
+        assert_eq!(
+            errors
+                .display()
+                .style(Style::List)
+                .colored(true)
+                .to_string(),
+            "\x1b[1;36m(root)\x1b[0m: \x1b[31mThe value is invalid.\x1b[0m"
+        );
+    }
+}
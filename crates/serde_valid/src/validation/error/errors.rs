@@ -1,4 +1,4 @@
-use super::{ArrayErrors, ObjectErrors, VecErrors};
+use super::{ArrayErrors, ItemErrorsMap, ObjectErrors, PropertyErrorsMap, VecErrors};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum Errors<E = crate::validation::Error> {
@@ -30,6 +30,50 @@ where
     }
 }
 
+impl<'de, E> serde::Deserialize<'de> for Errors<E>
+where
+    E: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(bound = "E: serde::Deserialize<'de>")]
+        struct ErrorsRepr<E> {
+            #[serde(default)]
+            errors: VecErrors<E>,
+            properties: Option<PropertyErrorsMap<E>>,
+            items: Option<ItemErrorsMap<E>>,
+        }
+
+        match ErrorsRepr::deserialize(deserializer)? {
+            ErrorsRepr {
+                errors,
+                properties: Some(properties),
+                items: None,
+            } => Ok(Self::Object(ObjectErrors::new(errors, properties))),
+            ErrorsRepr {
+                errors,
+                properties: None,
+                items: Some(items),
+            } => Ok(Self::Array(ArrayErrors::new(errors, items))),
+            ErrorsRepr {
+                errors,
+                properties: None,
+                items: None,
+            } => Ok(Self::NewType(errors)),
+            ErrorsRepr {
+                properties: Some(_),
+                items: Some(_),
+                ..
+            } => Err(serde::de::Error::custom(
+                "errors cannot have both `properties` and `items`",
+            )),
+        }
+    }
+}
+
 impl<E> Errors<E>
 where
     E: Clone,
@@ -49,8 +93,12 @@ where
                         };
                     }
                 }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
+                Errors::Object(b) => {
+                    let a = ArrayErrors::new(
+                        std::mem::take(&mut a.errors),
+                        std::mem::take(&mut a.items),
+                    );
+                    *self = Errors::Object(ObjectErrors::from(a).merge(b));
                 }
                 Errors::NewType(errors) => {
                     a.errors.extend(errors);
@@ -61,16 +109,32 @@ where
                     a.extend(b.errors);
                     *self = Errors::Array(ArrayErrors::new(a.to_vec(), b.items));
                 }
-                Errors::Object(_) => {
-                    unreachable!("conflict Array and Object in serde_valid::validation::Errors")
+                Errors::Object(b) => {
+                    a.extend(b.errors);
+                    *self = Errors::Object(ObjectErrors::new(a.to_vec(), b.properties));
                 }
                 Errors::NewType(b) => {
                     a.extend(b);
                 }
             },
-            Errors::Object(_) => {
-                unimplemented!("Object does not support yet.")
-            }
+            Errors::Object(a) => match other {
+                Errors::Array(b) => self.merge(Errors::Object(b.into())),
+                Errors::Object(b) => {
+                    a.errors.extend(b.errors);
+
+                    for (property, errors) in b.properties {
+                        match a.properties.get_mut(&property) {
+                            Some(a_errors) => a_errors.merge(errors),
+                            None => {
+                                a.properties.insert(property, errors);
+                            }
+                        };
+                    }
+                }
+                Errors::NewType(errors) => {
+                    a.errors.extend(errors);
+                }
+            },
         }
     }
 }
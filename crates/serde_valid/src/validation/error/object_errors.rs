@@ -1,6 +1,6 @@
 use serde::ser::SerializeStruct;
 
-use super::{PropertyErrorsMap, VecErrors};
+use super::{ArrayErrors, PropertyErrorsMap, VecErrors};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub struct ObjectErrors<E = crate::validation::Error> {
@@ -23,12 +23,67 @@ where
     }
 }
 
+impl<'de, E> serde::Deserialize<'de> for ObjectErrors<E>
+where
+    E: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(bound = "E: serde::Deserialize<'de>")]
+        struct ObjectErrorsRepr<E> {
+            #[serde(default)]
+            errors: VecErrors<E>,
+            properties: PropertyErrorsMap<E>,
+        }
+
+        let ObjectErrorsRepr { errors, properties } = ObjectErrorsRepr::deserialize(deserializer)?;
+        Ok(Self::new(errors, properties))
+    }
+}
+
 impl<E> ObjectErrors<E> {
     pub fn new(errors: VecErrors<E>, properties: PropertyErrorsMap<E>) -> Self {
         Self { errors, properties }
     }
 }
 
+/// The indexes of the items are the keys of the properties,
+/// which are the same tokens of the JSON Pointer.
+impl<E> From<ArrayErrors<E>> for ObjectErrors<E> {
+    fn from(array_errors: ArrayErrors<E>) -> Self {
+        Self::new(
+            array_errors.errors,
+            array_errors
+                .items
+                .into_iter()
+                .map(|(index, errors)| (index.to_string().into(), errors))
+                .collect(),
+        )
+    }
+}
+
+impl<E> ObjectErrors<E>
+where
+    E: Clone,
+{
+    pub fn merge(mut self, other: ObjectErrors<E>) -> Self {
+        self.errors.extend(other.errors);
+
+        for (property, errors) in other.properties {
+            match self.properties.get_mut(&property) {
+                Some(self_errors) => self_errors.merge(errors),
+                None => {
+                    self.properties.insert(property, errors);
+                }
+            };
+        }
+        self
+    }
+}
+
 impl<E> std::fmt::Display for ObjectErrors<E>
 where
     E: std::fmt::Display + serde::Serialize,
@@ -0,0 +1,255 @@
+use super::{ArrayErrors, Errors, ObjectErrors, VecErrors};
+
+/// Validation error received from another service.
+///
+/// `RemoteError` keeps the `code`, `params` and `message` of a [`crate::validation::Error`],
+/// so a client can re-localize or re-map the errors it receives.
+///
+/// Use [`Errors::to_remote`] to send the structured format, and deserialize it
+/// on the other side as `Errors<RemoteError>`.
+///
+/// ```rust
+/// use serde_json::json;
+/// use serde_valid::Validate;
+/// use serde_valid::validation::{Errors, RemoteError};
+///
+/// #[derive(Validate)]
+/// struct Data {
+///     #[validate(maximum = 10)]
+///     val: i32,
+/// }
+///
+/// let body = serde_json::to_string(
+///     &Data { val: 12 }.validate().unwrap_err().to_remote()
+/// ).unwrap();
+///
+/// let errors: Errors<RemoteError> = serde_json::from_str(&body).unwrap();
+///
+/// let Errors::Object(object) = errors else { unreachable!() };
+/// let Errors::NewType(val_errors) = &object.properties["val"] else { unreachable!() };
+///
+/// assert_eq!(val_errors[0].code.as_deref(), Some("maximum"));
+/// assert_eq!(val_errors[0].params["maximum"], json!(10));
+/// assert_eq!(val_errors[0].message, "The number must be `<= 10`.");
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct RemoteError {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    pub params: serde_json::Map<String, serde_json::Value>,
+    pub message: String,
+}
+
+impl RemoteError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            code: None,
+            params: serde_json::Map::new(),
+            message: message.into(),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RemoteError {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum RemoteErrorRepr {
+            Message(String),
+            Structured {
+                #[serde(default)]
+                code: Option<String>,
+                #[serde(default)]
+                params: serde_json::Map<String, serde_json::Value>,
+                message: String,
+            },
+        }
+
+        Ok(match RemoteErrorRepr::deserialize(deserializer)? {
+            RemoteErrorRepr::Message(message) => RemoteError::new(message),
+            RemoteErrorRepr::Structured {
+                code,
+                params,
+                message,
+            } => RemoteError {
+                code,
+                params,
+                message,
+            },
+        })
+    }
+}
+
+impl From<&crate::validation::Error> for RemoteError {
+    fn from(error: &crate::validation::Error) -> Self {
+        Self {
+            code: Some(error.code().to_string()),
+            params: error.params(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Errors<crate::validation::Error> {
+    /// Convert to the structured format, where each error keeps its `code` and `params`.
+    pub fn to_remote(&self) -> Errors<RemoteError> {
+        match self {
+            Errors::Array(array) => Errors::Array(array.to_remote()),
+            Errors::Object(object) => Errors::Object(object.to_remote()),
+            Errors::NewType(vec_errors) => match split_nested_errors(vec_errors) {
+                (errors, _, Some(object)) => Errors::Object(ObjectErrors::new(
+                    errors.into_iter().chain(object.errors).collect(),
+                    object.properties,
+                )),
+                (errors, Some(array), None) => Errors::Array(ArrayErrors::new(
+                    errors.into_iter().chain(array.errors).collect(),
+                    array.items,
+                )),
+                (errors, None, None) => Errors::NewType(errors),
+            },
+        }
+    }
+}
+
+impl ArrayErrors<crate::validation::Error> {
+    /// Convert to the structured format, where each error keeps its `code` and `params`.
+    pub fn to_remote(&self) -> ArrayErrors<RemoteError> {
+        let (errors, nested_array, _) = split_nested_errors(&self.errors);
+        let array = ArrayErrors::new(
+            errors,
+            self.items
+                .iter()
+                .map(|(index, errors)| (*index, errors.to_remote()))
+                .collect(),
+        );
+
+        match nested_array {
+            Some(nested_array) => array.merge(nested_array),
+            None => array,
+        }
+    }
+}
+
+impl ObjectErrors<crate::validation::Error> {
+    /// Convert to the structured format, where each error keeps its `code` and `params`.
+    pub fn to_remote(&self) -> ObjectErrors<RemoteError> {
+        let (errors, _, nested_object) = split_nested_errors(&self.errors);
+        let mut object = ObjectErrors::new(
+            errors,
+            self.properties
+                .iter()
+                .map(|(property, errors)| (property.clone(), errors.to_remote()))
+                .collect(),
+        );
+
+        if let Some(nested_object) = nested_object {
+            object.errors.extend(nested_object.errors);
+            object.properties.extend(nested_object.properties);
+        }
+        object
+    }
+}
+
+/// Split the leaf errors from the nested `Items` and `Properties` errors.
+#[allow(clippy::type_complexity)]
+fn split_nested_errors(
+    vec_errors: &VecErrors<crate::validation::Error>,
+) -> (
+    VecErrors<RemoteError>,
+    Option<ArrayErrors<RemoteError>>,
+    Option<ObjectErrors<RemoteError>>,
+) {
+    let mut errors = vec![];
+    let mut array: Option<ArrayErrors<RemoteError>> = None;
+    let mut object: Option<ObjectErrors<RemoteError>> = None;
+
+    for error in vec_errors {
+        match error {
+            crate::validation::Error::Items(items) => {
+                let items = items.to_remote();
+                array = Some(match array {
+                    Some(array) => array.merge(items),
+                    None => items,
+                });
+            }
+            crate::validation::Error::Properties(properties) => {
+                let properties = properties.to_remote();
+                match object.as_mut() {
+                    Some(object) => {
+                        object.errors.extend(properties.errors);
+                        object.properties.extend(properties.properties);
+                    }
+                    None => object = Some(properties),
+                }
+            }
+            _ => errors.push(RemoteError::from(error)),
+        }
+    }
+
+    (errors, array, object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::error::Format;
+    use crate::MaximumError;
+    use indexmap::IndexMap;
+    use serde_json::json;
+
+    #[test]
+    fn remote_error_from_message_string() -> crate::tests::Result<()> {
+        let error: RemoteError = serde_json::from_value(json!("The value is invalid."))?;
+
+        assert_eq!(error, RemoteError::new("The value is invalid."));
+
+        Ok(())
+    }
+
+    #[test]
+    fn remote_errors_round_trip() -> crate::tests::Result<()> {
+        let errors = Errors::NewType(vec![crate::validation::Error::Items(ArrayErrors::new(
+            vec![],
+            IndexMap::from([(
+                1,
+                Errors::NewType(vec![crate::validation::Error::Maximum(
+                    Format::Default.into_message(MaximumError::new(3)),
+                )]),
+            )]),
+        ))]);
+
+        let remote = errors.to_remote();
+        let value = serde_json::to_value(&remote)?;
+
+        assert_eq!(
+            value,
+            json!({
+                "errors": [],
+                "items": {
+                    "1": {
+                        "errors": [{
+                            "code": "maximum",
+                            "params": { "maximum": 3 },
+                            "message": "The number must be `<= 3`."
+                        }]
+                    }
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Errors<RemoteError>>(value)?.to_string(),
+            remote.to_string()
+        );
+
+        Ok(())
+    }
+}
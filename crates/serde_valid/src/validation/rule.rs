@@ -0,0 +1,309 @@
+use crate::validation::error::{Format, FormatDefault, Message};
+use crate::validation::{ArrayErrors, Composited, Error, Errors};
+use indexmap::IndexMap;
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+
+/// User-defined validation, used as `#[validate(rule(Name))]` or `#[validate(rule(Name = param))]`.
+///
+/// Like the built-in validations, the rule supports `message`, `message_fn` and `fluent`,
+/// and validates the items of `Vec`, arrays, `Option` and `HashMap` values.
+///
+/// # Examples
+/// ```rust
+/// use serde_valid::validation::ValidationRule;
+/// use serde_valid::validation::error::FormatDefault;
+/// use serde_valid::Validate;
+///
+/// struct MaxBytes;
+///
+/// #[derive(Clone, serde::Serialize)]
+/// struct MaxBytesError {
+///     max_bytes: usize,
+/// }
+///
+/// impl FormatDefault for MaxBytesError {
+///     fn format_default(&self) -> String {
+///         format!("The value must be at most {} bytes.", self.max_bytes)
+///     }
+/// }
+///
+/// impl ValidationRule for MaxBytes {
+///     type Value = str;
+///     type Param = usize;
+///     type Error = MaxBytesError;
+///
+///     const NAME: &'static str = "max_bytes";
+///
+///     fn validate(value: &str, max_bytes: &usize) -> Result<(), MaxBytesError> {
+///         if value.len() <= *max_bytes {
+///             Ok(())
+///         } else {
+///             Err(MaxBytesError { max_bytes: *max_bytes })
+///         }
+///     }
+/// }
+///
+/// #[derive(Validate)]
+/// struct Data {
+///     #[validate(rule(MaxBytes = 4))]
+///     names: Vec<String>,
+/// }
+///
+/// assert!(Data { names: vec!["name".to_owned()] }.validate().is_ok());
+/// assert!(Data { names: vec!["名前".to_owned()] }.validate().is_err());
+/// ```
+pub trait ValidationRule {
+    /// The type of the values, e.g. `str` for `String` and `&str` fields.
+    type Value: ?Sized;
+
+    /// The parameter of `rule(Name = param)`, which is `()` for `rule(Name)`.
+    type Param;
+
+    /// The error, whose [`FormatDefault`] is the default message
+    /// and whose fields are the parameters of [`Error::params`].
+    type Error: FormatDefault + serde::Serialize + Clone;
+
+    /// The name of the rule, which is the code of the errors.
+    const NAME: &'static str;
+
+    fn validate(value: &Self::Value, param: &Self::Param) -> Result<(), Self::Error>;
+}
+
+/// The error of a [`ValidationRule`].
+#[derive(Debug, Clone)]
+pub struct RuleError {
+    name: &'static str,
+    params: Box<serde_json::Map<String, serde_json::Value>>,
+    message: String,
+}
+
+impl RuleError {
+    /// The name of the rule.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The fields of the error of the rule.
+    pub fn params(&self) -> &serde_json::Map<String, serde_json::Value> {
+        &self.params
+    }
+}
+
+impl FormatDefault for RuleError {
+    fn format_default(&self) -> String {
+        self.message.clone()
+    }
+}
+
+/// The value validated by [`ValidationRule::validate`] itself.
+#[doc(hidden)]
+pub struct Leaf;
+
+/// The items validated by [`ValidationRule::validate`], through the containers of `I`.
+#[doc(hidden)]
+pub struct Nested<I>(PhantomData<I>);
+
+/// Composited validation of a [`ValidationRule`], where `I` tells the value from the containers of the values.
+pub trait ValidateCompositedRule<R: ValidationRule, I> {
+    fn validate_composited_rule(&self, param: &R::Param) -> Result<(), Composited<R::Error>>;
+
+    /// The validation counting the errors in `error_limit`, which stops when the limit is reached.
+    #[doc(hidden)]
+    fn validate_composited_rule_limited(
+        &self,
+        param: &R::Param,
+        error_limit: &crate::ErrorLimit,
+    ) -> Result<(), Composited<R::Error>> {
+        let result = self.validate_composited_rule(param);
+        if let Err(error) = &result {
+            error_limit.add(error.count());
+        }
+        result
+    }
+}
+
+impl<R, T> ValidateCompositedRule<R, Leaf> for T
+where
+    R: ValidationRule,
+    T: Borrow<R::Value> + ?Sized,
+{
+    fn validate_composited_rule(&self, param: &R::Param) -> Result<(), Composited<R::Error>> {
+        R::validate(self.borrow(), param).map_err(Composited::Single)
+    }
+}
+
+impl<R, T, I> ValidateCompositedRule<R, Nested<I>> for Vec<T>
+where
+    R: ValidationRule,
+    T: ValidateCompositedRule<R, I>,
+{
+    fn validate_composited_rule(&self, param: &R::Param) -> Result<(), Composited<R::Error>> {
+        <Self as ValidateCompositedRule<R, Nested<I>>>::validate_composited_rule_limited(
+            self,
+            param,
+            &crate::ErrorLimit::unlimited(),
+        )
+    }
+
+    fn validate_composited_rule_limited(
+        &self,
+        param: &R::Param,
+        error_limit: &crate::ErrorLimit,
+    ) -> Result<(), Composited<R::Error>> {
+        composited_rule_items(self.iter(), param, error_limit)
+    }
+}
+
+impl<R, T, I, const N: usize> ValidateCompositedRule<R, Nested<I>> for [T; N]
+where
+    R: ValidationRule,
+    T: ValidateCompositedRule<R, I>,
+{
+    fn validate_composited_rule(&self, param: &R::Param) -> Result<(), Composited<R::Error>> {
+        <Self as ValidateCompositedRule<R, Nested<I>>>::validate_composited_rule_limited(
+            self,
+            param,
+            &crate::ErrorLimit::unlimited(),
+        )
+    }
+
+    fn validate_composited_rule_limited(
+        &self,
+        param: &R::Param,
+        error_limit: &crate::ErrorLimit,
+    ) -> Result<(), Composited<R::Error>> {
+        composited_rule_items(self.iter(), param, error_limit)
+    }
+}
+
+impl<R, K, V, I> ValidateCompositedRule<R, Nested<I>> for std::collections::HashMap<K, V>
+where
+    R: ValidationRule,
+    V: ValidateCompositedRule<R, I>,
+{
+    fn validate_composited_rule(&self, param: &R::Param) -> Result<(), Composited<R::Error>> {
+        <Self as ValidateCompositedRule<R, Nested<I>>>::validate_composited_rule_limited(
+            self,
+            param,
+            &crate::ErrorLimit::unlimited(),
+        )
+    }
+
+    fn validate_composited_rule_limited(
+        &self,
+        param: &R::Param,
+        error_limit: &crate::ErrorLimit,
+    ) -> Result<(), Composited<R::Error>> {
+        composited_rule_items(self.values(), param, error_limit)
+    }
+}
+
+impl<R, T, I> ValidateCompositedRule<R, Nested<I>> for Option<T>
+where
+    R: ValidationRule,
+    T: ValidateCompositedRule<R, I>,
+{
+    fn validate_composited_rule(&self, param: &R::Param) -> Result<(), Composited<R::Error>> {
+        match self {
+            Some(value) => {
+                <T as ValidateCompositedRule<R, I>>::validate_composited_rule(value, param)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn validate_composited_rule_limited(
+        &self,
+        param: &R::Param,
+        error_limit: &crate::ErrorLimit,
+    ) -> Result<(), Composited<R::Error>> {
+        match self {
+            Some(value) => <T as ValidateCompositedRule<R, I>>::validate_composited_rule_limited(
+                value,
+                param,
+                error_limit,
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+fn composited_rule_items<'a, R, T, I>(
+    items: impl Iterator<Item = &'a T>,
+    param: &R::Param,
+    error_limit: &crate::ErrorLimit,
+) -> Result<(), Composited<R::Error>>
+where
+    R: ValidationRule,
+    T: ValidateCompositedRule<R, I> + 'a,
+{
+    let mut errors = IndexMap::new();
+    for (index, item) in items.enumerate() {
+        if error_limit.is_reached() {
+            break;
+        }
+        if let Err(error) = <T as ValidateCompositedRule<R, I>>::validate_composited_rule_limited(
+            item,
+            param,
+            error_limit,
+        ) {
+            errors.insert(index, error);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(Composited::Array(errors))
+    }
+}
+
+/// Convert the composited error of the rule `R` into [`Error`] with the message format.
+#[doc(hidden)]
+pub fn rule_error_by<R: ValidationRule>(
+    error: Composited<R::Error>,
+    format: Format<R::Error>,
+) -> Error {
+    match error {
+        Composited::Single(single) => Error::Rule(rule_message::<R>(single, format)),
+        Composited::Array(array) => Error::Items(ArrayErrors::new(
+            Vec::with_capacity(0),
+            array
+                .into_iter()
+                .map(|(index, error)| {
+                    (
+                        index,
+                        Errors::NewType(vec![rule_error_by::<R>(error, format.clone())]),
+                    )
+                })
+                .collect::<IndexMap<_, _>>(),
+        )),
+    }
+}
+
+fn rule_message<R: ValidationRule>(
+    error: R::Error,
+    format: Format<R::Error>,
+) -> Message<RuleError> {
+    let params = match serde_json::to_value(&error) {
+        Ok(serde_json::Value::Object(params)) => params,
+        _ => serde_json::Map::new(),
+    };
+    let format = match format {
+        Format::Default => Format::Default,
+        Format::Message(message) => Format::Message(message),
+        Format::MessageFn(message_fn) => Format::Message(message_fn(&error)),
+        #[cfg(feature = "fluent")]
+        Format::Fluent(message) => Format::Fluent(message),
+    };
+
+    Message::new(
+        RuleError {
+            name: R::NAME,
+            params: Box::new(params),
+            message: error.format_default(),
+        },
+        format,
+    )
+}
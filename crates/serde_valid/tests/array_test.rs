@@ -1,4 +1,5 @@
 use serde_json::json;
+use serde_valid::validation::error::Style;
 use serde_valid::Validate;
 
 #[test]
@@ -71,3 +72,52 @@ fn items_err_message() {
         .to_string()
     );
 }
+
+#[test]
+fn nested_items_errors_are_flattened() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 1)]
+        vals: Vec<Vec<String>>,
+        #[validate(maximum = 10)]
+        deep_vals: Vec<Vec<Vec<i32>>>,
+    }
+
+    let s = TestStruct {
+        vals: vec![vec!["a".to_owned(), "abc".to_owned()]],
+        deep_vals: vec![vec![], vec![vec![1, 11]]],
+    };
+    let errors = s.validate().unwrap_err();
+
+    assert_eq!(
+        errors
+            .flatten()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>(),
+        vec!["/vals/0/1", "/deep_vals/1/0/1"]
+    );
+    assert_eq!(
+        errors.display().style(Style::List).to_string(),
+        [
+            "/vals/0/1: The length of the value must be `<= 1`.",
+            "/deep_vals/1/0/1: The number must be `<= 10`.",
+        ]
+        .join("\n")
+    );
+    assert_eq!(
+        errors.display().style(Style::Tree).to_string(),
+        [
+            "vals:",
+            "  0:",
+            "    1:",
+            "      - The length of the value must be `<= 1`.",
+            "deep_vals:",
+            "  1:",
+            "    0:",
+            "      1:",
+            "        - The number must be `<= 10`.",
+        ]
+        .join("\n")
+    );
+}
@@ -0,0 +1,391 @@
+#[cfg(feature = "async")]
+mod tests {
+    use futures_executor::block_on;
+    use serde_json::json;
+    use serde_valid::{AsyncValidate, Validate, ValidateOptions};
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::{Context, Poll};
+
+    const TAKEN_USERNAMES: [&str; 2] = ["alice", "bob"];
+
+    /// Return `Pending` once, as the requests to a store do.
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    async fn username_not_taken(username: &str) -> Result<(), serde_valid::validation::Error> {
+        YieldNow(false).await;
+        if TAKEN_USERNAMES.contains(&username) {
+            Err(serde_valid::validation::Error::Custom(
+                "username is taken.".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(asynchronous)]
+    struct User {
+        #[validate(min_length = 3)]
+        #[validate(custom_async = username_not_taken)]
+        username: String,
+        #[validate(maximum = 150)]
+        age: u8,
+    }
+
+    #[test]
+    fn async_validation_is_ok() {
+        let user = User {
+            username: "carol".to_owned(),
+            age: 20,
+        };
+
+        assert!(block_on(user.validate_async()).is_ok());
+    }
+
+    #[test]
+    fn async_validation_is_err() {
+        let user = User {
+            username: "bob".to_owned(),
+            age: 200,
+        };
+
+        assert_eq!(
+            serde_json::to_value(block_on(user.validate_async()).unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "username": { "errors": ["username is taken."] },
+                    "age": { "errors": ["The number must be `<= 150`."] }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn async_validation_runs_sync_rules_first() {
+        let user = User {
+            username: "al".to_owned(),
+            age: 20,
+        };
+
+        assert_eq!(
+            block_on(user.validate_async()).unwrap_err().to_string(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "username": { "errors": ["The length of the value must be `>= 3`."] }
+                }
+            })
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn sync_validation_skips_async_validators() {
+        let user = User {
+            username: "bob".to_owned(),
+            age: 20,
+        };
+
+        assert!(user.validate().is_ok());
+    }
+
+    #[test]
+    fn async_validation_of_nested_fields() {
+        #[derive(Validate)]
+        #[validate(asynchronous)]
+        struct Team {
+            #[validate(min_items = 1)]
+            #[validate]
+            members: Vec<User>,
+            #[validate]
+            leader: Option<User>,
+        }
+
+        let team = Team {
+            members: vec![
+                User {
+                    username: "carol".to_owned(),
+                    age: 20,
+                },
+                User {
+                    username: "alice".to_owned(),
+                    age: 20,
+                },
+            ],
+            leader: Some(User {
+                username: "bob".to_owned(),
+                age: 20,
+            }),
+        };
+
+        assert_eq!(
+            serde_json::to_value(block_on(team.validate_async()).unwrap_err()).unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "members": {
+                        "errors": [],
+                        "items": {
+                            "1": {
+                                "errors": [],
+                                "properties": {
+                                    "username": { "errors": ["username is taken."] }
+                                }
+                            }
+                        }
+                    },
+                    "leader": {
+                        "errors": [],
+                        "properties": {
+                            "username": { "errors": ["username is taken."] }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn async_validation_has_same_errors_as_sync_validation() {
+        #[derive(Validate)]
+        #[validate(asynchronous)]
+        struct Data {
+            #[validate(maximum = 10)]
+            val: i32,
+            #[validate]
+            items: Vec<Item>,
+        }
+
+        #[derive(Validate)]
+        #[validate(asynchronous)]
+        struct Item(#[validate(min_length = 1)] String);
+
+        let data = Data {
+            val: 11,
+            items: vec![Item("".to_owned()), Item("item".to_owned())],
+        };
+
+        assert_eq!(
+            serde_json::to_value(block_on(data.validate_async()).unwrap_err()).unwrap(),
+            serde_json::to_value(data.validate().unwrap_err()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn async_validation_of_enum() {
+        #[derive(Validate)]
+        #[validate(asynchronous)]
+        enum Account {
+            User {
+                #[validate(custom_async = username_not_taken)]
+                username: String,
+            },
+            Guest(#[validate] Option<User>),
+        }
+
+        let taken = Account::User {
+            username: "alice".to_owned(),
+        };
+        let guest = Account::Guest(None);
+
+        assert_eq!(
+            block_on(taken.validate_async()).unwrap_err().to_string(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "username": { "errors": ["username is taken."] }
+                }
+            })
+            .to_string()
+        );
+        assert!(block_on(guest.validate_async()).is_ok());
+    }
+
+    #[test]
+    fn async_validation_with_bounded_concurrency() {
+        static RUNNING: AtomicUsize = AtomicUsize::new(0);
+        static MAX_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+        async fn counted(_: &u32) -> Result<(), serde_valid::validation::Error> {
+            let running = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+            MAX_RUNNING.fetch_max(running, Ordering::SeqCst);
+            YieldNow(false).await;
+            RUNNING.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        #[derive(Validate)]
+        #[validate(asynchronous)]
+        struct Item(#[validate(custom_async = counted)] u32);
+
+        let items = (0..10).map(Item).collect::<Vec<_>>();
+
+        assert!(block_on(items.validate_async_with_concurrency(3)).is_ok());
+        assert_eq!(MAX_RUNNING.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn async_validation_of_manual_validate() {
+        struct Manual;
+
+        impl Validate for Manual {
+            fn validate(&self) -> Result<(), serde_valid::validation::Errors> {
+                Err(serde_valid::validation::Errors::NewType(vec![
+                    serde_valid::validation::Error::Custom("manual.".to_owned()),
+                ]))
+            }
+        }
+
+        impl AsyncValidate for Manual {}
+
+        assert!(block_on(Manual.validate_async()).is_err());
+    }
+
+    #[test]
+    fn sync_derive_of_non_sync_type() {
+        use std::cell::Cell;
+
+        #[derive(Validate)]
+        struct Cached {
+            #[validate(maximum = 10)]
+            val: i32,
+            checked: Cell<bool>,
+        }
+
+        let cached = Cached {
+            val: 11,
+            checked: Cell::new(false),
+        };
+        cached.checked.set(true);
+
+        assert!(cached.validate().is_err());
+    }
+
+    #[test]
+    fn sync_derive_of_nested_manual_validate() {
+        struct Manual;
+
+        impl Validate for Manual {
+            fn validate(&self) -> Result<(), serde_valid::validation::Errors> {
+                Ok(())
+            }
+        }
+
+        #[derive(Validate)]
+        struct Data {
+            #[validate]
+            manual: Manual,
+        }
+
+        assert!(Data { manual: Manual }.validate().is_ok());
+    }
+
+    #[test]
+    fn async_validation_with_groups() {
+        #[derive(PartialEq)]
+        enum Group {
+            Create,
+        }
+
+        impl serde_valid::ValidationGroup for Group {}
+
+        #[derive(Validate)]
+        #[validate(asynchronous)]
+        struct Member {
+            #[validate(min_length = 3)]
+            #[validate(custom_async = username_not_taken, groups = [Group::Create])]
+            username: String,
+            #[validate]
+            profile: Profile,
+        }
+
+        #[derive(Validate)]
+        #[validate(asynchronous)]
+        struct Profile {
+            #[validate(maximum = 150, groups = [Group::Create])]
+            age: u8,
+        }
+
+        let member = Member {
+            username: "alice".to_owned(),
+            profile: Profile { age: 200 },
+        };
+
+        assert!(block_on(member.validate_async()).is_ok());
+        assert_eq!(
+            serde_json::to_value(
+                block_on(member.validate_groups_async(&[Group::Create])).unwrap_err()
+            )
+            .unwrap(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "username": { "errors": ["username is taken."] },
+                    "profile": {
+                        "errors": [],
+                        "properties": {
+                            "age": { "errors": ["The number must be `<= 150`."] }
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn async_validation_with_options() {
+        #[derive(Validate)]
+        #[validate(asynchronous)]
+        struct Member(#[validate(custom_async = username_not_taken)] String);
+
+        let members = (0..10)
+            .map(|_| Member("alice".to_owned()))
+            .collect::<Vec<_>>();
+
+        let errors = block_on(members.validate_async_with_options(&ValidateOptions::fail_fast()))
+            .unwrap_err();
+        let errors = errors.flatten();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "/0");
+
+        let errors = block_on(members.validate_async_with_options(&ValidateOptions::max_errors(3)))
+            .unwrap_err();
+        assert_eq!(errors.flatten().len(), 3);
+    }
+
+    #[test]
+    fn async_validation_is_send() {
+        fn assert_send<T: Send>(future: T) -> T {
+            future
+        }
+
+        let users = vec![User {
+            username: "bob".to_owned(),
+            age: 20,
+        }];
+
+        assert!(block_on(assert_send(users[0].validate_async())).is_err());
+        assert!(block_on(assert_send(users.validate_async_with_concurrency(1))).is_err());
+        assert!(block_on(assert_send(
+            users.validate_async_with_options(&ValidateOptions::fail_fast())
+        ))
+        .is_err());
+    }
+}
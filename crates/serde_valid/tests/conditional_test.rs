@@ -0,0 +1,199 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+#[derive(Debug, PartialEq)]
+enum Kind {
+    Short,
+    Long,
+}
+
+#[derive(Validate)]
+struct Item {
+    kind: Kind,
+    #[validate(max_length = 4, when = self.kind == Kind::Short)]
+    #[validate(min_length = 1)]
+    description: String,
+}
+
+#[test]
+fn when_condition_holds() {
+    let item = Item {
+        kind: Kind::Short,
+        description: "long text".to_owned(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(item.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "description": { "errors": ["The length of the value must be `<= 4`."] }
+            }
+        })
+    );
+}
+
+#[test]
+fn when_condition_does_not_hold() {
+    let item = Item {
+        kind: Kind::Long,
+        description: "long text".to_owned(),
+    };
+
+    assert!(item.validate().is_ok());
+}
+
+#[test]
+fn when_condition_does_not_change_other_rules() {
+    let item = Item {
+        kind: Kind::Long,
+        description: "".to_owned(),
+    };
+
+    assert!(item.validate().is_err());
+}
+
+#[test]
+fn when_with_custom_message_and_groups() {
+    #[derive(PartialEq)]
+    enum Group {
+        Update,
+    }
+
+    impl serde_valid::ValidationGroup for Group {}
+
+    #[derive(Validate)]
+    struct TestStruct {
+        enabled: bool,
+        #[validate(maximum = 10, message = "too large.", when = self.enabled, groups = [Group::Update])]
+        val: i32,
+    }
+
+    let disabled = TestStruct {
+        enabled: false,
+        val: 11,
+    };
+    let enabled = TestStruct {
+        enabled: true,
+        val: 11,
+    };
+
+    assert!(disabled.validate_groups(&[Group::Update]).is_ok());
+    assert!(enabled.validate().is_ok());
+    assert_eq!(
+        enabled
+            .validate_groups(&[Group::Update])
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": { "val": { "errors": ["too large."] } }
+        })
+        .to_string()
+    );
+}
+
+fn is_disabled(config: &Config) -> bool {
+    !config.enabled
+}
+
+#[derive(Validate)]
+struct Config {
+    enabled: bool,
+    #[validate(skip_if = is_disabled)]
+    #[validate(minimum = 1)]
+    #[validate(maximum = 10)]
+    workers: u32,
+    #[validate(skip_if = is_disabled)]
+    #[validate]
+    server: Server,
+}
+
+#[derive(Validate)]
+struct Server {
+    #[validate(min_length = 1)]
+    host: String,
+}
+
+#[test]
+fn skip_if_skips_all_rules_of_field() {
+    let config = Config {
+        enabled: false,
+        workers: 0,
+        server: Server {
+            host: "".to_owned(),
+        },
+    };
+
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn skip_if_does_not_skip() {
+    let config = Config {
+        enabled: true,
+        workers: 11,
+        server: Server {
+            host: "".to_owned(),
+        },
+    };
+
+    assert_eq!(
+        serde_json::to_value(config.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "workers": { "errors": ["The number must be `<= 10`."] },
+                "server": {
+                    "errors": [],
+                    "properties": {
+                        "host": { "errors": ["The length of the value must be `>= 1`."] }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn skip_if_closure() {
+    #[derive(Validate)]
+    struct TestStruct(
+        bool,
+        #[validate(skip_if = |s: &TestStruct| s.0)]
+        #[validate(maximum = 10)]
+        i32,
+    );
+
+    assert!(TestStruct(true, 11).validate().is_ok());
+    assert!(TestStruct(false, 11).validate().is_err());
+}
+
+#[test]
+fn skip_if_in_enum_variant() {
+    fn is_draft(value: &Document) -> bool {
+        matches!(value, Document::Page { draft: true, .. })
+    }
+
+    #[derive(Validate)]
+    enum Document {
+        Page {
+            draft: bool,
+            #[validate(skip_if = is_draft)]
+            #[validate(min_length = 1)]
+            title: String,
+        },
+    }
+
+    let draft = Document::Page {
+        draft: true,
+        title: "".to_owned(),
+    };
+    let published = Document::Page {
+        draft: false,
+        title: "".to_owned(),
+    };
+
+    assert!(draft.validate().is_ok());
+    assert!(published.validate().is_err());
+}
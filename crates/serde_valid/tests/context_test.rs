@@ -0,0 +1,270 @@
+use serde_json::json;
+use serde_valid::rules::ValidateRules;
+use serde_valid::{Validate, ValidateWithContext};
+
+struct AppContext {
+    tenant_ids: Vec<u32>,
+    max_amount: u32,
+}
+
+fn app_context() -> AppContext {
+    AppContext {
+        tenant_ids: vec![1, 2],
+        max_amount: 100,
+    }
+}
+
+fn known_tenant(
+    tenant_id: &u32,
+    context: &AppContext,
+) -> Result<(), serde_valid::validation::Error> {
+    if context.tenant_ids.contains(tenant_id) {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "unknown tenant.".to_owned(),
+        ))
+    }
+}
+
+fn amount_in_limit(
+    order: &Order,
+    context: &AppContext,
+) -> Result<(), serde_valid::validation::Error> {
+    if order.amount <= context.max_amount {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "amount over the limit.".to_owned(),
+        ))
+    }
+}
+
+#[derive(Validate)]
+#[validate(context = AppContext)]
+#[validate(custom = amount_in_limit)]
+struct Order {
+    #[validate(custom = known_tenant)]
+    tenant_id: u32,
+    #[validate(minimum = 1)]
+    amount: u32,
+}
+
+#[test]
+fn context_validation_is_ok() {
+    let order = Order {
+        tenant_id: 1,
+        amount: 100,
+    };
+
+    assert!(order.validate_with(&app_context()).is_ok());
+}
+
+#[test]
+fn context_validation_is_err() {
+    let order = Order {
+        tenant_id: 3,
+        amount: 101,
+    };
+
+    assert_eq!(
+        order.validate_with(&app_context()).unwrap_err().to_string(),
+        json!({
+            "errors": ["amount over the limit."],
+            "properties": {
+                "tenant_id": { "errors": ["unknown tenant."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_validation_runs_other_validations() {
+    let order = Order {
+        tenant_id: 1,
+        amount: 0,
+    };
+
+    assert_eq!(
+        order.validate_with(&app_context()).unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "amount": { "errors": ["The number must be `>= 1`."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_closure_validation() {
+    #[derive(Validate)]
+    #[validate(context = AppContext)]
+    #[validate(custom = |s: &TestStruct, context: &AppContext| known_tenant(&s.0, context))]
+    struct TestStruct(
+        #[validate(custom = |val: &u32, context: &AppContext| known_tenant(val, context))] u32,
+    );
+
+    assert!(TestStruct(2).validate_with(&app_context()).is_ok());
+    assert_eq!(
+        TestStruct(3)
+            .validate_with(&app_context())
+            .unwrap_err()
+            .to_string(),
+        json!({ "errors": ["unknown tenant.", "unknown tenant."] }).to_string()
+    );
+}
+
+#[test]
+fn context_call_validation() {
+    fn amount_in_rate(
+        rate: u32,
+    ) -> impl FnOnce(&TestStruct, &AppContext) -> Result<(), serde_valid::validation::Error> {
+        move |s: &TestStruct, context: &AppContext| {
+            if s.amount * rate <= context.max_amount {
+                Ok(())
+            } else {
+                Err(serde_valid::validation::Error::Custom(
+                    "amount over the rate.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = AppContext)]
+    #[validate(custom = amount_in_rate(10))]
+    struct TestStruct {
+        amount: u32,
+    }
+
+    assert!(TestStruct { amount: 10 }
+        .validate_with(&app_context())
+        .is_ok());
+    assert_eq!(
+        TestStruct { amount: 11 }
+            .validate_with(&app_context())
+            .unwrap_err()
+            .to_string(),
+        json!({ "errors": ["amount over the rate."], "properties": {} }).to_string()
+    );
+}
+
+#[test]
+fn context_is_forwarded_to_nested_fields() {
+    #[derive(Validate)]
+    #[validate(context = AppContext)]
+    struct Batch {
+        #[validate]
+        orders: Vec<Order>,
+        #[validate]
+        first: Option<Box<Order>>,
+        #[validate]
+        note: Note,
+    }
+
+    #[derive(Validate)]
+    struct Note {
+        #[validate(max_length = 4)]
+        text: String,
+    }
+
+    let batch = Batch {
+        orders: vec![
+            Order {
+                tenant_id: 1,
+                amount: 1,
+            },
+            Order {
+                tenant_id: 3,
+                amount: 1,
+            },
+        ],
+        first: Some(Box::new(Order {
+            tenant_id: 2,
+            amount: 1,
+        })),
+        note: Note {
+            text: "note".to_owned(),
+        },
+    };
+
+    assert_eq!(
+        batch.validate_with(&app_context()).unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "orders": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "tenant_id": { "errors": ["unknown tenant."] }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn context_enum_validation() {
+    #[derive(Validate)]
+    #[validate(context = AppContext)]
+    enum TestEnum {
+        Named {
+            #[validate(custom = known_tenant)]
+            tenant_id: u32,
+        },
+        NewType(#[validate] Order),
+    }
+
+    assert!(TestEnum::Named { tenant_id: 1 }
+        .validate_with(&app_context())
+        .is_ok());
+    assert!(TestEnum::Named { tenant_id: 3 }
+        .validate_with(&app_context())
+        .is_err());
+    assert!(TestEnum::NewType(Order {
+        tenant_id: 3,
+        amount: 1
+    })
+    .validate_with(&app_context())
+    .is_err());
+}
+
+#[test]
+fn validate_types_ignore_context() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+    }
+
+    assert!(TestStruct { val: 10 }.validate_with(&()).is_ok());
+    assert!(TestStruct { val: 11 }
+        .validate_with(&app_context())
+        .is_err());
+}
+
+#[test]
+fn context_validate_rules() {
+    #[derive(Validate)]
+    #[validate(context = AppContext)]
+    struct Batch {
+        #[validate]
+        orders: Vec<Order>,
+    }
+
+    let rules = Batch::validate_rules();
+    let nested = rules.fields[0].nested().unwrap();
+
+    assert_eq!(nested.name, "Order");
+    assert_eq!(nested.rules.len(), 1);
+}
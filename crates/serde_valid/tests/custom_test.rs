@@ -337,3 +337,203 @@ fn filed_custom_validation_using_self() {
         .to_string()
     );
 }
+
+fn positive_and_even(val: &i32) -> Result<(), Vec<serde_valid::validation::Error>> {
+    let mut errors = vec![];
+    if *val <= 0 {
+        errors.push(serde_valid::validation::Error::Custom(
+            "not positive.".to_owned(),
+        ));
+    }
+    if val % 2 != 0 {
+        errors.push(serde_valid::validation::Error::Custom(
+            "not even.".to_owned(),
+        ));
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[test]
+fn custom_validation_with_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom = positive_and_even, message = "invalid value.")]
+        val: i32,
+        #[validate(custom(positive_and_even), message = "invalid value.")]
+        val2: i32,
+    }
+
+    let s = TestStruct { val: -1, val2: 2 };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": { "errors": ["invalid value.", "invalid value."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn custom_validation_with_message_fn() {
+    fn wrap_message(error: &serde_valid::validation::Error) -> String {
+        format!("invalid value: {error}")
+    }
+
+    #[derive(Validate)]
+    #[validate(custom = |s| positive_and_even(&s.val), message_fn = wrap_message)]
+    struct TestStruct {
+        val: i32,
+    }
+
+    let s = TestStruct { val: 3 };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["invalid value: not even."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn custom_validation_of_enum_with_message() {
+    #[derive(Validate)]
+    #[validate(custom = |_| positive_and_even(&-1), message = "invalid enum.")]
+    enum TestEnum {
+        Variant(#[validate(minimum = 0)] i32),
+    }
+
+    assert_eq!(
+        TestEnum::Variant(0).validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["invalid enum.", "invalid enum."]
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn named_struct_custom_call_is_ok() {
+    fn check_limits(
+        min: i32,
+        max: i32,
+    ) -> impl FnOnce(&TestStruct) -> Result<(), serde_valid::validation::Error> {
+        move |s: &TestStruct| {
+            if min <= s.low && s.high <= max {
+                Ok(())
+            } else {
+                Err(serde_valid::validation::Error::Custom(format!(
+                    "the values must be in [{min}, {max}]."
+                )))
+            }
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(custom = check_limits(10, 20))]
+    struct TestStruct {
+        low: i32,
+        high: i32,
+    }
+
+    assert!(TestStruct { low: 10, high: 20 }.validate().is_ok());
+    assert_eq!(
+        TestStruct { low: 5, high: 20 }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": ["the values must be in [10, 20]."],
+            "properties": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn unnamed_struct_custom_call_vec_errors_is_err() {
+    fn check_limits(
+        min: i32,
+        max: i32,
+    ) -> impl FnOnce(&TestStruct) -> Result<(), Vec<serde_valid::validation::Error>> {
+        move |s: &TestStruct| {
+            let errors = [s.0, s.1]
+                .into_iter()
+                .filter(|val| !(min..=max).contains(val))
+                .map(|val| {
+                    serde_valid::validation::Error::Custom(format!("{val} is out of range."))
+                })
+                .collect::<Vec<_>>();
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(custom = check_limits(10, 20), message_fn = wrap_message)]
+    struct TestStruct(i32, i32);
+
+    fn wrap_message(error: &serde_valid::validation::Error) -> String {
+        format!("invalid: {error}")
+    }
+
+    assert!(TestStruct(10, 20).validate().is_ok());
+    assert_eq!(
+        TestStruct(5, 25).validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["invalid: 5 is out of range.", "invalid: 25 is out of range."],
+            "items": {}
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn enum_custom_call_is_err() {
+    fn check_limits(
+        min: i32,
+        max: i32,
+    ) -> impl FnOnce(&TestEnum) -> Result<(), serde_valid::validation::Error> {
+        move |s: &TestEnum| {
+            let (TestEnum::Named { val } | TestEnum::NewType(val)) = s;
+            if (min..=max).contains(val) {
+                Ok(())
+            } else {
+                Err(serde_valid::validation::Error::Custom(
+                    "out of range.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(custom = check_limits(10, 20))]
+    enum TestEnum {
+        Named { val: i32 },
+        NewType(i32),
+    }
+
+    assert!(TestEnum::Named { val: 15 }.validate().is_ok());
+    assert_eq!(
+        TestEnum::Named { val: 5 }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        json!({ "errors": ["out of range."], "properties": {} }).to_string()
+    );
+    assert_eq!(
+        TestEnum::NewType(25).validate().unwrap_err().to_string(),
+        json!({ "errors": ["out of range."] }).to_string()
+    );
+}
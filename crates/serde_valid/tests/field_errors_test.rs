@@ -0,0 +1,284 @@
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::validation::{ArrayErrors, Error, Errors, FieldErrors, ObjectErrors};
+use serde_valid::Validate;
+use std::collections::HashMap;
+
+fn custom_error(message: &str) -> Error {
+    Error::Custom(message.to_owned())
+}
+
+#[derive(Deserialize, Validate)]
+#[validate(custom = period_validation)]
+struct Period {
+    start: i32,
+    #[serde(rename = "endAt")]
+    #[validate(maximum = 10)]
+    end: i32,
+}
+
+fn period_validation(period: &Period) -> Result<(), FieldErrors> {
+    if period.end <= period.start {
+        Err(FieldErrors::new().at("end", custom_error("end must be after start.")))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn field_errors_is_ok() {
+    assert!(Period { start: 1, end: 2 }.validate().is_ok());
+}
+
+#[test]
+fn field_errors_at_renamed_field() {
+    assert_eq!(
+        Period { start: 12, end: 11 }
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "endAt": {
+                    "errors": ["The number must be `<= 10`.", "end must be after start."]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn field_errors_with_root_errors() {
+    #[derive(Validate)]
+    #[validate(custom = |s| if s.val > 0 {
+        Ok(())
+    } else {
+        Err(FieldErrors::new()
+            .root(custom_error("invalid data."))
+            .at("val", vec![custom_error("first."), custom_error("second.")]))
+    })]
+    struct TestStruct {
+        val: i32,
+    }
+
+    assert_eq!(
+        TestStruct { val: 0 }.validate().unwrap_err().to_string(),
+        json!({
+            "errors": ["invalid data."],
+            "properties": {
+                "val": { "errors": ["first.", "second."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn field_errors_at_path() {
+    #[derive(Validate)]
+    struct Item {
+        #[validate(min_length = 1)]
+        name: String,
+        price: i32,
+    }
+
+    fn total_validation(order: &Order) -> Result<(), FieldErrors> {
+        let mut errors = FieldErrors::new();
+        for (index, item) in order.items.iter().enumerate() {
+            if item.price > order.budget {
+                errors = errors.at_path(
+                    &format!("/items/{index}/price"),
+                    custom_error("over the budget."),
+                );
+            }
+        }
+        errors.into_result()
+    }
+
+    #[derive(Validate)]
+    #[validate(custom = total_validation)]
+    struct Order {
+        #[validate]
+        items: Vec<Item>,
+        budget: i32,
+    }
+
+    let s = Order {
+        items: vec![
+            Item {
+                name: "apple".to_owned(),
+                price: 1,
+            },
+            Item {
+                name: "".to_owned(),
+                price: 100,
+            },
+            Item {
+                name: "lemon".to_owned(),
+                price: 200,
+            },
+        ],
+        budget: 10,
+    };
+
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "items": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "name": { "errors": ["The length of the value must be `>= 1`."] },
+                                "price": { "errors": ["over the budget."] }
+                            }
+                        },
+                        "2": {
+                            "errors": [],
+                            "properties": {
+                                "price": { "errors": ["over the budget."] }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn field_errors_at_path_of_map() {
+    #[derive(Validate)]
+    struct Item {
+        #[validate(min_length = 1)]
+        name: String,
+        price: i32,
+    }
+
+    fn total_validation(order: &Order) -> Result<(), FieldErrors> {
+        let mut errors = FieldErrors::new();
+        for (key, item) in order.items.iter() {
+            if item.price > order.budget {
+                errors = errors.at_path(
+                    &format!("/items/{key}/price"),
+                    custom_error("over the budget."),
+                );
+            }
+        }
+        errors.into_result()
+    }
+
+    #[derive(Validate)]
+    #[validate(custom = total_validation)]
+    struct Order {
+        #[validate]
+        items: HashMap<String, Item>,
+        budget: i32,
+    }
+
+    let s = Order {
+        items: HashMap::from([
+            (
+                "1".to_owned(),
+                Item {
+                    name: "".to_owned(),
+                    price: 100,
+                },
+            ),
+            (
+                "2".to_owned(),
+                Item {
+                    name: "lemon".to_owned(),
+                    price: 200,
+                },
+            ),
+        ]),
+        budget: 10,
+    };
+
+    let mut paths = s
+        .validate()
+        .unwrap_err()
+        .flatten()
+        .into_iter()
+        .map(|(path, error)| (path, error.to_string()))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    assert_eq!(
+        paths,
+        vec![
+            (
+                "/items/1/name".to_owned(),
+                "The length of the value must be `>= 1`.".to_owned()
+            ),
+            ("/items/1/price".to_owned(), "over the budget.".to_owned()),
+            ("/items/2/price".to_owned(), "over the budget.".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn errors_merge_items_into_properties() {
+    let mut errors = Errors::Object(ObjectErrors::new(
+        vec![],
+        IndexMap::from([(
+            "1".into(),
+            Errors::NewType(vec![custom_error("of the property.")]),
+        )]),
+    ));
+    errors.merge(Errors::Array(ArrayErrors::new(
+        vec![],
+        IndexMap::from([
+            (1, Errors::NewType(vec![custom_error("of the item.")])),
+            (2, Errors::NewType(vec![custom_error("of the item.")])),
+        ]),
+    )));
+
+    assert_eq!(
+        serde_json::to_value(errors).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "1": { "errors": ["of the property.", "of the item."] },
+                "2": { "errors": ["of the item."] }
+            }
+        })
+    );
+}
+
+#[test]
+fn field_errors_with_custom_message() {
+    #[derive(Validate)]
+    #[validate(
+        custom = |s| match s.vals.iter().position(|val| val % 2 == 0) {
+            Some(index) => Err(FieldErrors::new().at_path(&format!("/vals/{index}"), custom_error("invalid."))),
+            None => Ok(()),
+        },
+        message = "invalid value."
+    )]
+    struct TestStruct {
+        vals: Vec<i32>,
+    }
+
+    assert_eq!(
+        serde_json::to_value(TestStruct { vals: vec![1, 2] }.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": [],
+                    "items": {
+                        "1": { "errors": ["invalid value."] }
+                    }
+                }
+            }
+        })
+    );
+}
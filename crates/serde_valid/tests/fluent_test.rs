@@ -51,4 +51,42 @@ mod tests {
             .to_string()
         );
     }
+
+    #[test]
+    fn fluent_custom_error() {
+        fn not_empty(val: &str) -> Result<(), serde_valid::validation::Error> {
+            if val.is_empty() {
+                Err(serde_valid::validation::Error::Custom("empty.".to_owned()))
+            } else {
+                Ok(())
+            }
+        }
+
+        #[derive(Debug, Deserialize, Validate)]
+        struct Test {
+            #[validate(custom = not_empty, fluent("intro", name = "taro"))]
+            a: String,
+        }
+
+        let test = Test { a: "".to_owned() };
+        let a = test
+            .validate()
+            .unwrap_err()
+            .localize(&get_bundle("intro = Welcome, { $name }."));
+
+        assert_eq!(
+            a.to_string(),
+            json!({
+                "errors": [],
+                "properties": {
+                    "a": {
+                        "errors": [
+                            "Welcome, \u{2068}taro\u{2069}."
+                        ]
+                    }
+                }
+            })
+            .to_string()
+        );
+    }
 }
@@ -0,0 +1,185 @@
+use serde_json::json;
+use serde_valid::{Validate, ValidateWithContext, ValidationGroup};
+
+#[derive(Debug, PartialEq)]
+enum Group {
+    Create,
+    Update,
+    Delete,
+}
+
+impl ValidationGroup for Group {}
+
+#[derive(Validate)]
+struct User {
+    #[validate(min_length = 12, groups = [Group::Create])]
+    #[validate(max_length = 32)]
+    password: String,
+    #[validate(pattern = r"^[a-z]+$", message = "lowercase only.", groups = [Group::Create, Group::Update])]
+    name: String,
+    #[validate]
+    profile: Option<Profile>,
+}
+
+#[derive(Validate)]
+struct Profile {
+    #[validate(maximum = 150, groups = [Group::Update])]
+    age: u8,
+}
+
+fn user() -> User {
+    User {
+        password: "short".to_owned(),
+        name: "Name".to_owned(),
+        profile: Some(Profile { age: 200 }),
+    }
+}
+
+#[test]
+fn groups_rules_are_skipped_without_groups() {
+    assert!(user().validate().is_ok());
+    assert!(user().validate_groups::<Group>(&[]).is_ok());
+    assert!(user().validate_groups(&[Group::Delete]).is_ok());
+}
+
+#[test]
+fn groups_rules_of_selected_group() {
+    assert_eq!(
+        serde_json::to_value(user().validate_groups(&[Group::Create]).unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "password": { "errors": ["The length of the value must be `>= 12`."] },
+                "name": { "errors": ["lowercase only."] }
+            }
+        })
+    );
+}
+
+#[test]
+fn groups_are_forwarded_to_nested_fields() {
+    assert_eq!(
+        user()
+            .validate_groups(&[Group::Update])
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "name": { "errors": ["lowercase only."] },
+                "profile": {
+                    "errors": [],
+                    "properties": {
+                        "age": { "errors": ["The number must be `<= 150`."] }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn ungrouped_rules_always_run() {
+    let user = User {
+        password: "p".repeat(33),
+        name: "name".to_owned(),
+        profile: None,
+    };
+
+    assert!(user.validate().is_err());
+    assert!(user.validate_groups(&[Group::Create]).is_err());
+    assert!(user.validate_groups(&[Group::Update]).is_err());
+}
+
+#[test]
+fn groups_of_unnamed_fields() {
+    #[derive(Validate)]
+    struct TestStruct(
+        #[validate(maximum = 10, groups = [Group::Create])] i32,
+        #[validate(minimum = 0)] i32,
+    );
+
+    assert!(TestStruct(11, 0).validate().is_ok());
+    assert!(TestStruct(11, 0).validate_groups(&[Group::Create]).is_err());
+    assert!(TestStruct(10, -1).validate().is_err());
+}
+
+#[test]
+fn groups_of_vec_items() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        profiles: Vec<Profile>,
+    }
+
+    let s = TestStruct {
+        profiles: vec![Profile { age: 1 }, Profile { age: 151 }],
+    };
+
+    assert!(s.validate().is_ok());
+    assert!(s.validate_groups(&[Group::Update]).is_err());
+}
+
+#[test]
+fn groups_of_other_group_types_are_not_selected() {
+    #[derive(PartialEq)]
+    enum OtherGroup {
+        Create,
+    }
+
+    impl ValidationGroup for OtherGroup {}
+
+    assert!(user().validate_groups(&[OtherGroup::Create]).is_ok());
+}
+
+#[test]
+fn groups_with_context() {
+    struct MaxAge(u8);
+
+    fn age_in_limit(age: &u8, max_age: &MaxAge) -> Result<(), serde_valid::validation::Error> {
+        if *age <= max_age.0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "too old.".to_owned(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = MaxAge)]
+    struct Member {
+        #[validate(custom = age_in_limit, groups = [Group::Create])]
+        age: u8,
+        #[validate]
+        profile: Profile,
+    }
+
+    let member = Member {
+        age: 100,
+        profile: Profile { age: 200 },
+    };
+
+    assert!(member.validate_with(&MaxAge(20)).is_ok());
+    assert_eq!(
+        serde_json::to_value(
+            member
+                .validate_groups_with(&MaxAge(20), &[Group::Create, Group::Update])
+                .unwrap_err()
+        )
+        .unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "age": { "errors": ["too old."] },
+                "profile": {
+                    "errors": [],
+                    "properties": {
+                        "age": { "errors": ["The number must be `<= 150`."] }
+                    }
+                }
+            }
+        })
+    );
+}
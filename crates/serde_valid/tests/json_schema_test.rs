@@ -0,0 +1,280 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json_schema::JsonSchema;
+use serde_valid::Validate;
+
+#[test]
+fn json_schema_of_named_struct() {
+    /// A test struct.
+    #[derive(Deserialize, Validate, JsonSchema)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    struct TestStruct {
+        /// The value.
+        #[validate(minimum = 0.0)]
+        #[validate(exclusive_maximum = 10.5)]
+        #[validate(multiple_of = 0.5)]
+        max_value: f64,
+        #[serde(rename = "tag_list")]
+        #[validate(min_items = 1)]
+        #[validate(unique_items)]
+        #[validate(pattern = "^[a-z]+$")]
+        tags: Vec<String>,
+        #[validate(enumerate = ["a", "b"])]
+        #[validate(custom = |_| Ok::<(), serde_valid::validation::Error>(()))]
+        kind: Option<String>,
+        #[serde(default)]
+        count: u32,
+        #[serde(skip)]
+        cache: Vec<u8>,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "description": "A test struct.",
+            "type": "object",
+            "properties": {
+                "maxValue": {
+                    "description": "The value.",
+                    "type": "number",
+                    "minimum": 0.0,
+                    "exclusiveMaximum": 10.5,
+                    "multipleOf": 0.5
+                },
+                "tag_list": {
+                    "type": "array",
+                    "items": { "type": "string", "pattern": "^[a-z]+$" },
+                    "minItems": 1,
+                    "uniqueItems": true
+                },
+                "kind": {
+                    "anyOf": [
+                        { "type": "string", "enum": ["a", "b"] },
+                        { "type": "null" }
+                    ]
+                },
+                "count": { "type": "integer", "minimum": 0 }
+            },
+            "required": ["maxValue", "tag_list"],
+            "additionalProperties": false
+        })
+    );
+}
+
+#[test]
+fn json_schema_of_nested_struct() {
+    #[derive(Validate, JsonSchema)]
+    struct Parent {
+        #[validate]
+        child: Child,
+        #[validate]
+        children: Vec<Child>,
+    }
+
+    #[derive(Validate, JsonSchema)]
+    struct Child {
+        #[validate(max_length = 4)]
+        name: String,
+        parent: Option<Box<Parent>>,
+    }
+
+    assert_eq!(
+        Parent::json_schema(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "child": { "$ref": "#/$defs/Child" },
+                "children": { "type": "array", "items": { "$ref": "#/$defs/Child" } }
+            },
+            "required": ["child", "children"],
+            "$defs": {
+                "Child": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string", "maxLength": 4 },
+                        "parent": {
+                            "anyOf": [{ "$ref": "#/$defs/Parent" }, { "type": "null" }]
+                        }
+                    },
+                    "required": ["name"]
+                },
+                "Parent": {
+                    "type": "object",
+                    "properties": {
+                        "child": { "$ref": "#/$defs/Child" },
+                        "children": { "type": "array", "items": { "$ref": "#/$defs/Child" } }
+                    },
+                    "required": ["child", "children"]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn json_schema_of_unnamed_struct() {
+    #[derive(Validate, JsonSchema)]
+    struct NewType(#[validate(maximum = 10)] u8);
+
+    #[derive(Validate, JsonSchema)]
+    struct Tuple(#[validate(min_length = 1)] String, NewType);
+
+    assert_eq!(
+        Tuple::json_schema(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "array",
+            "prefixItems": [
+                { "type": "string", "minLength": 1 },
+                { "$ref": "#/$defs/NewType" }
+            ],
+            "minItems": 2,
+            "maxItems": 2,
+            "$defs": {
+                "NewType": { "type": "integer", "minimum": 0, "maximum": 10 }
+            }
+        })
+    );
+}
+
+#[test]
+fn json_schema_of_enum() {
+    #[derive(Deserialize, Validate, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    enum Shape {
+        Point,
+        Circle {
+            #[validate(exclusive_minimum = 0.0)]
+            radius: f64,
+        },
+        Square(#[validate(exclusive_minimum = 0.0)] f64),
+    }
+
+    assert_eq!(
+        Shape::json_schema(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "oneOf": [
+                { "const": "point" },
+                {
+                    "type": "object",
+                    "properties": {
+                        "circle": {
+                            "type": "object",
+                            "properties": {
+                                "radius": { "type": "number", "exclusiveMinimum": 0.0 }
+                            },
+                            "required": ["radius"]
+                        }
+                    },
+                    "required": ["circle"],
+                    "additionalProperties": false
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "square": { "type": "number", "exclusiveMinimum": 0.0 }
+                    },
+                    "required": ["square"],
+                    "additionalProperties": false
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn json_schema_of_tagged_enum() {
+    #[derive(Deserialize, Validate, JsonSchema)]
+    #[serde(tag = "type")]
+    enum Internal {
+        A {
+            #[validate(maximum = 1)]
+            val: i32,
+        },
+    }
+
+    #[derive(Deserialize, Validate, JsonSchema)]
+    #[serde(untagged)]
+    enum Untagged {
+        A(#[validate(maximum = 1)] i32),
+        B(String),
+    }
+
+    assert_eq!(
+        Internal::json_schema()["oneOf"],
+        json!([{
+            "type": "object",
+            "properties": {
+                "type": { "const": "A" },
+                "val": { "type": "integer", "maximum": 1 }
+            },
+            "required": ["type", "val"]
+        }])
+    );
+    assert_eq!(
+        Untagged::json_schema()["anyOf"],
+        json!([
+            { "type": "integer", "maximum": 1 },
+            { "type": "string" }
+        ])
+    );
+}
+
+#[test]
+fn json_schema_of_generic_struct() {
+    #[derive(Validate, JsonSchema)]
+    struct Page<T> {
+        #[validate(max_items = 10)]
+        items: Vec<T>,
+    }
+
+    assert_eq!(
+        Option::<Page<bool>>::json_schema(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "anyOf": [{ "$ref": "#/$defs/Page_bool" }, { "type": "null" }],
+            "$defs": {
+                "Page_bool": {
+                    "type": "object",
+                    "properties": {
+                        "items": {
+                            "type": "array",
+                            "items": { "type": "boolean" },
+                            "maxItems": 10
+                        }
+                    },
+                    "required": ["items"]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn json_schema_skips_conditional_and_grouped_validations() {
+    #[derive(PartialEq)]
+    enum Group {
+        Create,
+    }
+
+    impl serde_valid::ValidationGroup for Group {}
+
+    #[derive(Deserialize, Validate, JsonSchema)]
+    struct TestStruct {
+        #[validate(max_length = 32)]
+        #[validate(min_length = 12, groups = [Group::Create])]
+        #[validate(pattern = "^[a-z]+$", when = self.strict)]
+        password: String,
+        strict: bool,
+    }
+
+    assert_eq!(
+        TestStruct::json_schema()["properties"]["password"],
+        json!({ "type": "string", "maxLength": 32 })
+    );
+}
@@ -0,0 +1,257 @@
+use serde::Deserialize;
+use serde_valid::json::FromJsonStr;
+use serde_valid::{Validate, ValidateOptions, ValidateWithContext, ValidationGroup};
+
+fn is_positive(value: &i32) -> Result<(), serde_valid::validation::Error> {
+    if *value > 0 {
+        Ok(())
+    } else {
+        Err(serde_valid::validation::Error::Custom(
+            "not positive.".to_owned(),
+        ))
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    #[validate(multiple_of = 2)]
+    values: Vec<i32>,
+    #[validate(custom = is_positive)]
+    value: i32,
+    #[validate]
+    children: Vec<Child>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+struct Child {
+    #[validate(max_length = 1)]
+    name: String,
+}
+
+fn test_struct() -> TestStruct {
+    TestStruct {
+        values: vec![11; 100],
+        value: 0,
+        children: (0..100)
+            .map(|_| Child {
+                name: "ab".to_owned(),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn options_default_reports_all_errors() {
+    let errors = test_struct()
+        .validate_with_options(&ValidateOptions::default())
+        .unwrap_err();
+
+    assert_eq!(errors.flatten().len(), 301);
+    assert_eq!(
+        errors.flatten().len(),
+        test_struct().validate().unwrap_err().flatten().len()
+    );
+}
+
+#[test]
+fn options_fail_fast() {
+    let errors = test_struct()
+        .validate_with_options(&ValidateOptions::fail_fast())
+        .unwrap_err();
+
+    let errors = errors.flatten();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, "/values/0");
+}
+
+#[test]
+fn options_max_errors() {
+    for max_errors in [1, 50, 100, 150, 250] {
+        let errors = test_struct()
+            .validate_with_options(&ValidateOptions::max_errors(max_errors))
+            .unwrap_err();
+
+        assert_eq!(errors.flatten().len(), max_errors);
+    }
+}
+
+#[test]
+fn options_max_errors_in_nested_fields() {
+    let errors = test_struct()
+        .validate_with_options(&ValidateOptions::max_errors(205))
+        .unwrap_err();
+    let paths = errors
+        .flatten()
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+
+    assert_eq!(paths.len(), 205);
+    assert!(paths.contains(&"/value".to_owned()));
+    assert_eq!(
+        paths
+            .iter()
+            .filter(|path| path.starts_with("/children/"))
+            .collect::<Vec<_>>(),
+        vec![
+            "/children/0/name",
+            "/children/1/name",
+            "/children/2/name",
+            "/children/3/name"
+        ]
+    );
+}
+
+#[test]
+fn options_of_valid_value() {
+    let s = TestStruct {
+        values: vec![2],
+        value: 1,
+        children: vec![],
+    };
+
+    assert!(s
+        .validate_with_options(&ValidateOptions::fail_fast())
+        .is_ok());
+}
+
+#[test]
+fn options_of_vec() {
+    let values = (0..10)
+        .map(|_| Child {
+            name: "ab".to_owned(),
+        })
+        .collect::<Vec<_>>();
+
+    let errors = values
+        .validate_with_options(&ValidateOptions::max_errors(3))
+        .unwrap_err();
+
+    assert_eq!(errors.flatten().len(), 3);
+}
+
+#[test]
+fn options_from_json_str() {
+    let err = TestStruct::from_json_str_with_options(
+        r#"{ "values": [11, 12, 13], "value": 0, "children": [] }"#,
+        &ValidateOptions::fail_fast(),
+    )
+    .unwrap_err();
+
+    assert_eq!(err.as_validation_errors().unwrap().flatten().len(), 1);
+}
+
+#[test]
+fn options_with_groups() {
+    #[derive(PartialEq)]
+    enum Group {
+        Create,
+    }
+
+    impl ValidationGroup for Group {}
+
+    #[derive(Validate)]
+    struct User {
+        #[validate(min_length = 12, groups = [Group::Create])]
+        #[validate(max_length = 4)]
+        password: String,
+        #[validate]
+        children: Vec<Child>,
+    }
+
+    let user = User {
+        password: "short".to_owned(),
+        children: (0..3)
+            .map(|_| Child {
+                name: "ab".to_owned(),
+            })
+            .collect(),
+    };
+
+    assert_eq!(
+        user.validate_with_options(&ValidateOptions::default())
+            .unwrap_err()
+            .flatten()
+            .len(),
+        4
+    );
+    assert_eq!(
+        user.validate_with_options(&ValidateOptions::default().with_groups(&[Group::Create]))
+            .unwrap_err()
+            .flatten()
+            .len(),
+        5
+    );
+
+    let errors = user
+        .validate_with_options(&ValidateOptions::fail_fast().with_groups(&[Group::Create]))
+        .unwrap_err();
+    let errors = errors.flatten();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, "/password");
+}
+
+#[test]
+fn options_with_context() {
+    struct MaxValue(i32);
+
+    fn value_in_limit(
+        value: &i32,
+        context: &MaxValue,
+    ) -> Result<(), serde_valid::validation::Error> {
+        if *value <= context.0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom(
+                "value over the limit.".to_owned(),
+            ))
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(context = MaxValue)]
+    struct Values {
+        #[validate(custom = value_in_limit)]
+        first: i32,
+        #[validate(custom = value_in_limit)]
+        second: i32,
+        #[validate]
+        children: Vec<Child>,
+    }
+
+    let values = Values {
+        first: 11,
+        second: 12,
+        children: (0..10)
+            .map(|_| Child {
+                name: "ab".to_owned(),
+            })
+            .collect(),
+    };
+
+    assert_eq!(
+        values
+            .validate_with(&MaxValue(10))
+            .unwrap_err()
+            .flatten()
+            .len(),
+        12
+    );
+
+    let errors = values
+        .validate_options_with(&MaxValue(10), &ValidateOptions::fail_fast())
+        .unwrap_err();
+    let errors = errors.flatten();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].0, "/first");
+
+    assert_eq!(
+        values
+            .validate_options_with(&MaxValue(10), &ValidateOptions::max_errors(5))
+            .unwrap_err()
+            .flatten()
+            .len(),
+        5
+    );
+}
@@ -0,0 +1,178 @@
+use serde_json::json;
+use serde_valid::output::{self, Output, OutputFormat};
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    #[validate(multiple_of = 5)]
+    val: i32,
+    #[validate(max_length = 2)]
+    names: Vec<String>,
+}
+
+fn invalid() -> TestStruct {
+    TestStruct {
+        val: 12,
+        names: vec!["ab".to_string(), "abc".to_string()],
+    }
+}
+
+#[test]
+fn output_flag() {
+    assert_eq!(
+        output::evaluate(&invalid(), OutputFormat::Flag),
+        Output::Flag { valid: false }
+    );
+    assert_eq!(
+        serde_json::to_value(output::evaluate(
+            &TestStruct {
+                val: 5,
+                names: vec![]
+            },
+            OutputFormat::Flag
+        ))
+        .unwrap(),
+        json!({ "valid": true })
+    );
+}
+
+#[test]
+fn output_basic() {
+    assert_eq!(
+        serde_json::to_value(output::evaluate(&invalid(), OutputFormat::Basic)).unwrap(),
+        json!({
+            "valid": false,
+            "keywordLocation": "",
+            "instanceLocation": "",
+            "errors": [
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/val/maximum",
+                    "instanceLocation": "/val",
+                    "error": "The number must be `<= 10`."
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/val/multipleOf",
+                    "instanceLocation": "/val",
+                    "error": "The value must be multiple of `5`."
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/names/items/maxLength",
+                    "instanceLocation": "/names/1",
+                    "error": "The length of the value must be `<= 2`."
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn output_detailed() {
+    assert_eq!(
+        serde_json::to_value(output::evaluate(&invalid(), OutputFormat::Detailed)).unwrap(),
+        json!({
+            "valid": false,
+            "keywordLocation": "",
+            "instanceLocation": "",
+            "errors": [
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/val",
+                    "instanceLocation": "/val",
+                    "errors": [
+                        {
+                            "valid": false,
+                            "keywordLocation": "/properties/val/maximum",
+                            "instanceLocation": "/val",
+                            "error": "The number must be `<= 10`."
+                        },
+                        {
+                            "valid": false,
+                            "keywordLocation": "/properties/val/multipleOf",
+                            "instanceLocation": "/val",
+                            "error": "The value must be multiple of `5`."
+                        }
+                    ]
+                },
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/names/items/maxLength",
+                    "instanceLocation": "/names/1",
+                    "error": "The length of the value must be `<= 2`."
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn output_verbose() {
+    let output = output::evaluate(&invalid(), OutputFormat::Verbose);
+
+    assert!(!output.valid());
+    assert_eq!(
+        serde_json::to_value(output).unwrap()["errors"][1],
+        json!({
+            "valid": false,
+            "keywordLocation": "/properties/names",
+            "instanceLocation": "/names",
+            "errors": [
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/names/items",
+                    "instanceLocation": "/names/1",
+                    "errors": [
+                        {
+                            "valid": false,
+                            "keywordLocation": "/properties/names/items/maxLength",
+                            "instanceLocation": "/names/1",
+                            "error": "The length of the value must be `<= 2`."
+                        }
+                    ]
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn output_of_custom_error() {
+    fn is_even(val: &i32) -> Result<(), serde_valid::validation::Error> {
+        if val % 2 == 0 {
+            Ok(())
+        } else {
+            Err(serde_valid::validation::Error::Custom("odd.".to_owned()))
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(custom = is_even)]
+        val: i32,
+    }
+
+    assert_eq!(
+        serde_json::to_value(output::evaluate(
+            &TestStruct { val: 1 },
+            OutputFormat::Basic
+        ))
+        .unwrap(),
+        json!({
+            "valid": false,
+            "keywordLocation": "",
+            "instanceLocation": "",
+            "errors": [
+                {
+                    "valid": false,
+                    "keywordLocation": "/properties/val",
+                    "instanceLocation": "/val",
+                    "error": "odd.",
+                    "code": "custom"
+                }
+            ]
+        })
+    );
+}
@@ -0,0 +1,83 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::json::FromJsonStr;
+use serde_valid::problem_details::{ProblemDetails, Violation};
+use serde_valid::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+#[validate(custom = sample_validation)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    val: i32,
+    #[validate(max_length = 2)]
+    names: Vec<String>,
+}
+
+fn sample_validation(s: &TestStruct) -> Result<(), serde_valid::validation::Error> {
+    if s.names.is_empty() {
+        Err(serde_valid::validation::Error::Custom(
+            "names must not be empty.".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn problem_details_of_validation_errors() {
+    let err = TestStruct::from_json_str(r#"{ "val": 12, "names": [] }"#).unwrap_err();
+
+    let problem = ProblemDetails::from(err);
+
+    assert_eq!(problem.status, 422);
+    assert_eq!(
+        problem.errors,
+        vec![
+            Violation {
+                pointer: "".to_string(),
+                detail: "names must not be empty.".to_string(),
+            },
+            Violation {
+                pointer: "/val".to_string(),
+                detail: "The number must be `<= 10`.".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn problem_details_of_nested_errors() {
+    let errors = TestStruct {
+        val: 1,
+        names: vec!["ab".to_string(), "abc".to_string()],
+    }
+    .validate()
+    .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(ProblemDetails::from(&errors).with_instance("/users/1")).unwrap(),
+        json!({
+            "type": "about:blank",
+            "title": "Unprocessable Content",
+            "status": 422,
+            "instance": "/users/1",
+            "errors": [
+                { "pointer": "/names/1", "detail": "The length of the value must be `<= 2`." }
+            ]
+        })
+    );
+}
+
+#[test]
+fn problem_details_of_deserialize_error() {
+    let err = TestStruct::from_json_str(r#"{ "val": "12" }"#).unwrap_err();
+    let message = err.to_string();
+
+    let problem = ProblemDetails::from(&err);
+
+    assert_eq!(
+        problem,
+        ProblemDetails::new(400, "Bad Request").with_detail(message)
+    );
+    assert!(problem.errors.is_empty());
+}
@@ -0,0 +1,165 @@
+#![allow(dead_code)]
+
+#[cfg(feature = "proptest")]
+mod tests {
+    use proptest::prelude::*;
+    use serde::Deserialize;
+    use serde_valid::output::{evaluate, OutputFormat, OutputUnit};
+    use serde_valid::proptest::{FieldStrategy, ValidStrategy};
+    use serde_valid::rules::Rule;
+    use serde_valid::Validate;
+    use unicode_segmentation::UnicodeSegmentation;
+
+    fn is_even(value: &i32) -> Result<(), serde_valid::validation::Error> {
+        match value % 2 {
+            0 => Ok(()),
+            _ => Err(serde_valid::validation::Error::Custom("odd".to_string())),
+        }
+    }
+
+    #[derive(Debug, Clone, Deserialize, Validate, ValidStrategy)]
+    struct TestStruct {
+        #[validate(minimum = -10)]
+        #[validate(exclusive_maximum = 100)]
+        #[validate(multiple_of = 3)]
+        int_value: i32,
+        #[validate(exclusive_minimum = 0.0)]
+        #[validate(maximum = 1.0)]
+        float_value: f64,
+        #[validate(min_length = 2)]
+        #[validate(max_length = 4)]
+        name: String,
+        #[serde(rename = "tag_list")]
+        #[validate(min_items = 1)]
+        #[validate(max_items = 3)]
+        #[validate(unique_items)]
+        #[validate(pattern = r"^[a-z]{1,8}$")]
+        tags: Vec<String>,
+        #[validate(enumerate = ["a", "b"])]
+        kind: Option<String>,
+        #[validate(custom = is_even)]
+        even: i32,
+        #[validate]
+        child: Child,
+        count: u8,
+    }
+
+    #[derive(Debug, Clone, Deserialize, Validate, ValidStrategy)]
+    struct Child {
+        #[validate(max_length = 3)]
+        values: Vec<String>,
+    }
+
+    #[derive(Debug, Validate, ValidStrategy)]
+    enum TestEnum {
+        Named {
+            #[validate(maximum = 10)]
+            value: u8,
+        },
+        Unnamed(#[validate(enumerate = [1, 2])] i64, bool),
+        NewType(#[validate(min_length = 1)] String),
+        Unit,
+    }
+
+    fn error_locations<T: Validate>(value: &T) -> Vec<String> {
+        fn collect(unit: &OutputUnit, locations: &mut Vec<String>) {
+            if unit.error.is_some() {
+                locations.push(unit.instance_location.clone());
+            }
+            for unit in &unit.errors {
+                collect(unit, locations);
+            }
+        }
+
+        let mut locations = vec![];
+        if let serde_valid::output::Output::Unit(unit) = evaluate(value, OutputFormat::Basic) {
+            collect(&unit, &mut locations);
+        }
+        locations
+    }
+
+    proptest! {
+        #[test]
+        fn valid_strategy_of_struct(value in TestStruct::valid_strategy()) {
+            prop_assert!(value.validate().is_ok());
+            prop_assert_eq!(value.int_value % 3, 0);
+            prop_assert!((2..=4).contains(&value.name.graphemes(true).count()));
+        }
+
+        #[test]
+        fn invalid_strategy_of_struct(invalid in TestStruct::invalid_strategy().unwrap()) {
+            prop_assert!(invalid.value.validate().is_err());
+            prop_assert!(error_locations(&invalid.value).contains(&invalid.path));
+            prop_assert!(!matches!(invalid.rule, Rule::Custom(_)));
+        }
+
+        #[test]
+        fn valid_strategy_of_enum(value in TestEnum::valid_strategy()) {
+            prop_assert!(value.validate().is_ok());
+        }
+
+        #[test]
+        fn invalid_strategy_of_enum(invalid in TestEnum::invalid_strategy().unwrap()) {
+            prop_assert!(invalid.value.validate().is_err());
+            prop_assert!(!matches!(invalid.value, TestEnum::Unit));
+            prop_assert!(error_locations(&invalid.value).contains(&invalid.path));
+        }
+
+        #[test]
+        fn invalid_field_strategy_of_items(
+            invalid in Vec::<i32>::invalid_field_strategy(&[Rule::MaxItems(2), Rule::Maximum(5.into())]).unwrap()
+        ) {
+            match invalid.rule {
+                Rule::MaxItems(_) => {
+                    prop_assert!(invalid.value.len() > 2);
+                    prop_assert_eq!(invalid.path, "");
+                }
+                _ => {
+                    let index = invalid.value.iter().position(|value| *value > 5).unwrap();
+                    prop_assert_eq!(invalid.path, format!("/{index}"));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_strategy_paths() {
+        let mut runner = proptest::test_runner::TestRunner::deterministic();
+        let strategy = TestStruct::invalid_strategy().unwrap();
+        let mut paths = std::collections::BTreeSet::new();
+        for _ in 0..500 {
+            let invalid = strategy.new_tree(&mut runner).unwrap().current();
+            paths.insert(invalid.path.split('/').nth(1).unwrap().to_string());
+        }
+
+        assert_eq!(
+            paths.into_iter().collect::<Vec<_>>(),
+            vec![
+                "child",
+                "float_value",
+                "int_value",
+                "kind",
+                "name",
+                "tag_list"
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_strategy_of_no_rules() {
+        #[derive(Debug, Validate, ValidStrategy)]
+        struct TestStruct {
+            #[validate]
+            children: Vec<Child>,
+            value: i32,
+        }
+
+        #[derive(Debug, Clone, Validate, ValidStrategy)]
+        struct Child {
+            value: i32,
+        }
+
+        assert!(Child::invalid_strategy().is_none());
+        assert!(TestStruct::invalid_strategy().is_none());
+    }
+}
@@ -0,0 +1,113 @@
+use serde_json::json;
+use serde_valid::validation::{Errors, RemoteError};
+use serde_valid::Validate;
+
+#[test]
+fn deserialize_errors_from_default_format() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(maximum = 10)]
+        val: i32,
+        #[validate(min_items = 2)]
+        #[validate(max_length = 2)]
+        items: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: 11,
+        items: vec!["abc".to_string()],
+    };
+    let errors = s.validate().unwrap_err();
+
+    let messages: Errors<String> = serde_json::from_str(&errors.to_string()).unwrap();
+    assert_eq!(messages.to_string(), errors.to_string());
+
+    let remote: Errors<RemoteError> = serde_json::from_str(&errors.to_string()).unwrap();
+    let Errors::Object(object) = remote else {
+        panic!("expected object errors");
+    };
+    let Errors::NewType(val_errors) = &object.properties["val"] else {
+        panic!("expected new type errors");
+    };
+    assert_eq!(
+        val_errors,
+        &vec![RemoteError::new("The number must be `<= 10`.")]
+    );
+}
+
+#[test]
+fn deserialize_errors_from_structured_format() {
+    #[derive(Validate)]
+    struct Child {
+        #[validate(pattern = r"^\d+$")]
+        code: String,
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(enumerate = ["a", "b"])]
+        kind: &'static str,
+        #[validate]
+        children: Vec<Child>,
+    }
+
+    let s = TestStruct {
+        kind: "c",
+        children: vec![
+            Child {
+                code: "1".to_string(),
+            },
+            Child {
+                code: "x".to_string(),
+            },
+        ],
+    };
+    let errors = s.validate().unwrap_err();
+
+    let body = serde_json::to_value(errors.to_remote()).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "errors": [],
+            "properties": {
+                "kind": {
+                    "errors": [{
+                        "code": "enumerate",
+                        "params": { "enumerate": ["a", "b"] },
+                        "message": "The value must be in [a, b]."
+                    }]
+                },
+                "children": {
+                    "errors": [],
+                    "items": {
+                        "1": {
+                            "errors": [],
+                            "properties": {
+                                "code": {
+                                    "errors": [{
+                                        "code": "pattern",
+                                        "params": { "pattern": "^\\d+$" },
+                                        "message": "The value must match the pattern of \"^\\d+$\"."
+                                    }]
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let remote: Errors<RemoteError> = serde_json::from_value(body.clone()).unwrap();
+    assert_eq!(serde_json::to_value(remote).unwrap(), body);
+}
+
+#[test]
+fn deserialize_errors_conflict_is_err() {
+    assert!(serde_json::from_value::<Errors<RemoteError>>(json!({
+        "errors": [],
+        "properties": {},
+        "items": {}
+    }))
+    .is_err());
+}
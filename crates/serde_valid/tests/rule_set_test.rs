@@ -0,0 +1,324 @@
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::rules::{Rule, ValidateRules};
+use serde_valid::{RuleSet, Validate};
+
+mod rule_sets {
+    use serde_valid::RuleSet;
+
+    #[derive(RuleSet)]
+    #[validate(min_length = 3)]
+    #[validate(max_length = 8)]
+    #[validate(
+        pattern = r"^[a-z0-9_]+$",
+        message = "only lowercase letters, digits and `_`."
+    )]
+    pub struct Username;
+
+    #[derive(RuleSet)]
+    #[validate(rules = Username)]
+    #[validate(custom = crate::rule_sets::not_reserved)]
+    pub struct NewUsername;
+
+    pub fn not_reserved(val: &str) -> Result<(), serde_valid::validation::Error> {
+        if val == "admin" {
+            Err(serde_valid::validation::Error::Custom(
+                "the username is reserved.".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The same public rule set as `rule_sets::Username`, in another module.
+mod other_rule_sets {
+    use serde_valid::RuleSet;
+
+    #[derive(RuleSet)]
+    #[validate(min_length = 3)]
+    #[validate(max_length = 8)]
+    #[validate(
+        pattern = r"^[a-z0-9_]+$",
+        message = "only lowercase letters, digits and `_`."
+    )]
+    pub struct Username;
+}
+
+use rule_sets::{NewUsername, Username};
+
+#[test]
+fn rule_set_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rules = Username)]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "user_1".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn rule_set_is_same_as_attributes() {
+    #[derive(Validate)]
+    struct WithRuleSet {
+        #[validate(rules = Username)]
+        val: String,
+    }
+
+    #[derive(Validate)]
+    struct WithAttributes {
+        #[validate(min_length = 3)]
+        #[validate(max_length = 8)]
+        #[validate(
+            pattern = r"^[a-z0-9_]+$",
+            message = "only lowercase letters, digits and `_`."
+        )]
+        val: String,
+    }
+
+    for val in ["a", "Too Long Name", "ok_name"] {
+        assert_eq!(
+            WithRuleSet {
+                val: val.to_owned()
+            }
+            .validate()
+            .map_err(|errors| errors.to_string()),
+            WithAttributes {
+                val: val.to_owned()
+            }
+            .validate()
+            .map_err(|errors| errors.to_string()),
+        );
+    }
+}
+
+#[test]
+fn rule_set_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rules = Username)]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "A".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The length of the value must be `>= 3`.",
+                        "only lowercase letters, digits and `_`."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_set_with_custom_message() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rules = Username, message = "invalid username.")]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "A".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": { "errors": ["invalid username.", "invalid username."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_set_with_serde_rename() {
+    #[derive(Deserialize, Validate)]
+    struct TestStruct {
+        #[serde(rename = "userName")]
+        #[validate(rules = Username)]
+        user_name: String,
+    }
+
+    let s = TestStruct {
+        user_name: "a".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "userName": { "errors": ["The length of the value must be `>= 3`."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_set_composited() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rules = Username)]
+        vals: Vec<String>,
+        #[validate(rules = Username)]
+        maybe: Option<String>,
+    }
+
+    let s = TestStruct {
+        vals: vec!["user_1".to_owned(), "u".to_owned()],
+        maybe: None,
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": [],
+                    "items": {
+                        "1": { "errors": ["The length of the value must be `>= 3`."] }
+                    }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn nested_rule_set() {
+    #[derive(Validate)]
+    struct TestStruct(#[validate(rules = NewUsername)] &'static str);
+
+    assert!(TestStruct("user_1").validate().is_ok());
+    assert_eq!(
+        TestStruct("admin").validate().unwrap_err().to_string(),
+        json!({ "errors": ["the username is reserved."] }).to_string()
+    );
+}
+
+#[test]
+fn rule_set_of_enum_with_groups() {
+    #[derive(PartialEq)]
+    enum Group {
+        Create,
+    }
+
+    impl serde_valid::ValidationGroup for Group {}
+
+    #[derive(Validate)]
+    enum TestEnum {
+        User {
+            #[validate(rules = Username, groups = [Group::Create])]
+            name: String,
+        },
+    }
+
+    let s = TestEnum::User {
+        name: "A".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+    assert!(s.validate_groups(&[Group::Create]).is_err());
+}
+
+#[test]
+fn rule_set_rules() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rules = NewUsername)]
+        #[validate(max_length = 6)]
+        val: String,
+    }
+
+    let username_rules = vec![
+        Rule::MinLength(3),
+        Rule::MaxLength(8),
+        Rule::Pattern(serde_valid::validation::Pattern::from(r"^[a-z0-9_]+$")),
+    ];
+    assert_eq!(Username::rules(), username_rules);
+    assert_eq!(
+        TestStruct::validate_rules().fields[0].rules,
+        [
+            username_rules,
+            vec![
+                Rule::Custom(Some("crate::rule_sets::not_reserved")),
+                Rule::MaxLength(6),
+            ],
+        ]
+        .concat()
+    );
+}
+
+#[test]
+fn rule_set_with_context() {
+    use serde_valid::ValidateWithContext;
+
+    struct Reserved(Vec<&'static str>);
+
+    fn not_reserved(val: &str, reserved: &Reserved) -> Result<(), serde_valid::validation::Error> {
+        if reserved.0.contains(&val) {
+            Err(serde_valid::validation::Error::Custom(
+                "the username is reserved.".to_owned(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(RuleSet)]
+    #[validate(rules = Username)]
+    #[validate(custom = not_reserved)]
+    struct ContextUsername;
+
+    #[derive(Validate)]
+    #[validate(context = Reserved)]
+    struct TestStruct {
+        #[validate(rules = ContextUsername)]
+        val: String,
+    }
+
+    let reserved = Reserved(vec!["root"]);
+    assert!(TestStruct {
+        val: "user_1".to_owned()
+    }
+    .validate_with(&reserved)
+    .is_ok());
+    assert!(TestStruct {
+        val: "root".to_owned()
+    }
+    .validate_with(&reserved)
+    .is_err());
+}
+
+#[test]
+fn same_rule_sets_in_other_modules() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rules = Username)]
+        val: String,
+        #[validate(rules = other_rule_sets::Username)]
+        other_val: String,
+    }
+
+    let s = TestStruct {
+        val: "ab".to_owned(),
+        other_val: "ab".to_owned(),
+    };
+    assert_eq!(s.validate().unwrap_err().flatten().len(), 2);
+}
@@ -0,0 +1,207 @@
+use serde_json::json;
+use serde_valid::rules::ValidateRules;
+use serde_valid::validation::error::FormatDefault;
+use serde_valid::validation::{ErrorKind, ValidationRule};
+use serde_valid::Validate;
+
+struct Slug;
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct SlugError;
+
+impl FormatDefault for SlugError {
+    fn format_default(&self) -> String {
+        "The value must be a slug.".to_owned()
+    }
+}
+
+impl ValidationRule for Slug {
+    type Value = str;
+    type Param = ();
+    type Error = SlugError;
+
+    const NAME: &'static str = "slug";
+
+    fn validate(value: &str, _: &()) -> Result<(), SlugError> {
+        if !value.is_empty()
+            && value
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            Ok(())
+        } else {
+            Err(SlugError)
+        }
+    }
+}
+
+struct MaxBytes;
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct MaxBytesError {
+    max_bytes: usize,
+}
+
+impl FormatDefault for MaxBytesError {
+    fn format_default(&self) -> String {
+        format!("The value must be at most {} bytes.", self.max_bytes)
+    }
+}
+
+impl ValidationRule for MaxBytes {
+    type Value = str;
+    type Param = usize;
+    type Error = MaxBytesError;
+
+    const NAME: &'static str = "max_bytes";
+
+    fn validate(value: &str, max_bytes: &usize) -> Result<(), MaxBytesError> {
+        if value.len() <= *max_bytes {
+            Ok(())
+        } else {
+            Err(MaxBytesError {
+                max_bytes: *max_bytes,
+            })
+        }
+    }
+}
+
+#[test]
+fn rule_is_ok() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rule(Slug))]
+        #[validate(rule(MaxBytes = 8))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "my-slug".to_owned(),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn rule_is_err() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rule(Slug))]
+        #[validate(rule(MaxBytes = 4))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "My Slug".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": {
+                    "errors": [
+                        "The value must be a slug.",
+                        "The value must be at most 4 bytes."
+                    ]
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_error_code_and_params() {
+    #[derive(Validate)]
+    struct TestStruct(#[validate(rule(MaxBytes = 2))] &'static str);
+
+    let errors = TestStruct("name").validate().unwrap_err();
+    let Some((_, error)) = errors.flatten().into_iter().next() else {
+        panic!("no errors");
+    };
+
+    assert_eq!(error.kind(), ErrorKind::Rule);
+    assert_eq!(error.code(), "max_bytes");
+    assert_eq!(
+        serde_json::Value::Object(error.params()),
+        json!({ "max_bytes": 2 })
+    );
+}
+
+#[test]
+fn rule_custom_message() {
+    fn max_bytes_message(error: &MaxBytesError) -> String {
+        format!("up to {} bytes.", error.max_bytes)
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rule(Slug), message = "not a slug.")]
+        #[validate(rule(MaxBytes = 2), message_fn = max_bytes_message)]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "A B".to_owned(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": { "errors": ["not a slug.", "up to 2 bytes."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn rule_composited() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rule(Slug))]
+        vals: Vec<String>,
+        #[validate(rule(Slug))]
+        maybe: Option<String>,
+        #[validate(rule(MaxBytes = 1))]
+        nested: Vec<Option<&'static str>>,
+    }
+
+    let s = TestStruct {
+        vals: vec!["ok".to_owned(), "Not Ok".to_owned()],
+        maybe: None,
+        nested: vec![Some("a"), None, Some("ab")],
+    };
+    assert_eq!(
+        serde_json::to_value(s.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "vals": {
+                    "errors": [],
+                    "items": { "1": { "errors": ["The value must be a slug."] } }
+                },
+                "nested": {
+                    "errors": [],
+                    "items": { "2": { "errors": ["The value must be at most 1 bytes."] } }
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn rule_validate_rules() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(rule(Slug))]
+        val: String,
+    }
+
+    let rules = TestStruct::validate_rules();
+    assert_eq!(
+        rules.fields[0].rules,
+        vec![serde_valid::rules::Rule::UserDefined("slug")]
+    );
+}
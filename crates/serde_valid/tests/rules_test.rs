@@ -0,0 +1,285 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+use serde_json::json;
+use serde_valid::rules::{FieldRules, Rule, TypeRules, ValidateRules, VariantRules};
+use serde_valid::validation::{Literal, Number, Pattern};
+use serde_valid::Validate;
+
+fn validate_name(_: &str) -> Result<(), serde_valid::validation::Error> {
+    Ok(())
+}
+
+fn validate_struct(_: &TestStruct) -> Result<(), serde_valid::validation::Error> {
+    Ok(())
+}
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(custom = validate_struct)]
+struct TestStruct {
+    #[validate(minimum = 0.0)]
+    #[validate(exclusive_maximum = 10.5, message = "too large")]
+    #[validate(multiple_of = 0.5)]
+    max_value: f64,
+    #[serde(rename = "tag_list")]
+    #[validate(min_items = 1)]
+    #[validate(unique_items)]
+    #[validate(pattern = "^[a-z]+$")]
+    tags: Vec<String>,
+    #[validate(enumerate = ["a", "b"])]
+    #[validate(custom = validate_name)]
+    kind: String,
+    count: u32,
+    #[validate]
+    child: Option<Child>,
+}
+
+#[derive(Deserialize, Validate)]
+struct Child {
+    #[validate(max_length = 4)]
+    name: String,
+    #[validate]
+    children: Vec<Child>,
+}
+
+#[test]
+fn validate_rules_of_named_struct() {
+    assert_eq!(
+        TestStruct::validate_rules(),
+        TypeRules {
+            name: "TestStruct",
+            rules: vec![Rule::Custom(Some("validate_struct"))],
+            fields: vec![
+                FieldRules {
+                    key: "maxValue",
+                    rules: vec![
+                        Rule::Minimum(Number::F64(0.0)),
+                        Rule::ExclusiveMaximum(Number::F64(10.5)),
+                        Rule::MultipleOf(Number::F64(0.5)),
+                    ],
+                    nested: None,
+                },
+                FieldRules {
+                    key: "tag_list",
+                    rules: vec![
+                        Rule::MinItems(1),
+                        Rule::UniqueItems,
+                        Rule::Pattern(Pattern::from("^[a-z]+$")),
+                    ],
+                    nested: None,
+                },
+                FieldRules {
+                    key: "kind",
+                    rules: vec![
+                        Rule::Enumerate(vec![Literal::String("a"), Literal::String("b")]),
+                        Rule::Custom(Some("validate_name")),
+                    ],
+                    nested: None,
+                },
+                FieldRules {
+                    key: "child",
+                    rules: vec![],
+                    nested: Some(|| Some(Child::validate_rules())),
+                },
+            ],
+            variants: vec![],
+        }
+    );
+}
+
+#[test]
+fn validate_rules_of_nested_types() {
+    let child = TestStruct::validate_rules().fields[3].nested().unwrap();
+
+    assert_eq!(child.name, "Child");
+    assert_eq!(child.fields[0].rules, vec![Rule::MaxLength(4)]);
+
+    let recursive = child.fields[1].nested().unwrap();
+
+    assert_eq!(recursive.name, "Child");
+    assert!(TestStruct::validate_rules().fields[0].nested().is_none());
+}
+
+#[test]
+fn validate_rules_of_nested_type_implemented_by_hand() {
+    struct Manual;
+
+    impl Validate for Manual {
+        fn validate(&self) -> Result<(), serde_valid::validation::Errors> {
+            Ok(())
+        }
+    }
+
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate]
+        manual: Vec<Manual>,
+    }
+
+    let rules = TestStruct::validate_rules();
+
+    assert_eq!(rules.fields[0].key, "manual");
+    assert!(rules.fields[0].nested.is_some());
+    assert!(rules.fields[0].nested().is_none());
+}
+
+#[test]
+fn validate_rules_leave_out_conditional_and_grouped_rules() {
+    #[derive(PartialEq)]
+    enum Group {
+        Admin,
+    }
+
+    impl serde_valid::ValidationGroup for Group {}
+
+    #[derive(Validate)]
+    struct TestStruct {
+        strict: bool,
+        #[validate(minimum = 0)]
+        #[validate(maximum = 10, when = self.strict)]
+        #[validate(maximum = 100, groups = [Group::Admin])]
+        val: i32,
+    }
+
+    assert_eq!(
+        TestStruct::validate_rules().fields,
+        vec![FieldRules {
+            key: "val",
+            rules: vec![Rule::Minimum(Number::I32(0))],
+            nested: None,
+        }]
+    );
+}
+
+#[test]
+fn validate_rules_of_unnamed_struct() {
+    #[derive(Validate)]
+    struct TestStruct(
+        #[validate(maximum = 10)] i32,
+        String,
+        #[validate] Vec<Child>,
+    );
+
+    let rules = TestStruct::validate_rules();
+
+    assert_eq!(rules.fields.len(), 2);
+    assert_eq!(rules.fields[0].key, "0");
+    assert_eq!(rules.fields[0].rules, vec![Rule::Maximum(Number::I32(10))]);
+    assert_eq!(rules.fields[1].key, "2");
+    assert_eq!(rules.fields[1].nested().unwrap().name, "Child");
+}
+
+#[test]
+fn validate_rules_of_enum() {
+    #[derive(Deserialize, Validate)]
+    #[serde(rename_all = "snake_case")]
+    enum TestEnum {
+        Named {
+            #[validate(max_items = 2)]
+            values: Vec<i32>,
+        },
+        Unnamed(#[validate(min_length = 1)] String),
+        Unit,
+    }
+
+    assert_eq!(
+        TestEnum::validate_rules().variants,
+        vec![
+            VariantRules {
+                name: "named",
+                fields: vec![FieldRules {
+                    key: "values",
+                    rules: vec![Rule::MaxItems(2)],
+                    nested: None,
+                }],
+            },
+            VariantRules {
+                name: "unnamed",
+                fields: vec![FieldRules {
+                    key: "0",
+                    rules: vec![Rule::MinLength(1)],
+                    nested: None,
+                }],
+            },
+            VariantRules {
+                name: "unit",
+                fields: vec![],
+            },
+        ]
+    );
+}
+
+#[test]
+fn validate_rules_of_custom_validators() {
+    fn max_len(max: usize) -> impl FnOnce(&str) -> Result<(), serde_valid::validation::Error> {
+        move |val| {
+            if val.len() <= max {
+                Ok(())
+            } else {
+                Err(serde_valid::validation::Error::Custom(
+                    "too long.".to_owned(),
+                ))
+            }
+        }
+    }
+
+    #[derive(Validate)]
+    #[validate(custom = |_| Ok::<_, serde_valid::validation::Error>(()))]
+    struct TestStruct {
+        #[validate(custom = max_len(4))]
+        #[validate(custom = self::validate_name)]
+        name: String,
+    }
+
+    let rules = TestStruct::validate_rules();
+
+    assert_eq!(rules.rules, vec![Rule::Custom(None)]);
+    assert_eq!(
+        rules.fields[0].rules,
+        vec![
+            Rule::Custom(Some("max_len")),
+            Rule::Custom(Some("self::validate_name")),
+        ]
+    );
+}
+
+#[test]
+fn validate_rules_serialization() {
+    assert_eq!(
+        serde_json::to_value(TestStruct::validate_rules().fields).unwrap(),
+        json!([
+            {
+                "key": "maxValue",
+                "rules": [
+                    { "kind": "minimum", "value": 0.0 },
+                    { "kind": "exclusive_maximum", "value": 10.5 },
+                    { "kind": "multiple_of", "value": 0.5 }
+                ],
+                "nested": null
+            },
+            {
+                "key": "tag_list",
+                "rules": [
+                    { "kind": "min_items", "value": 1 },
+                    { "kind": "unique_items" },
+                    { "kind": "pattern", "value": "\"^[a-z]+$\"" }
+                ],
+                "nested": null
+            },
+            {
+                "key": "kind",
+                "rules": [
+                    { "kind": "enumerate", "value": ["a", "b"] },
+                    { "kind": "custom", "value": "validate_name" }
+                ],
+                "nested": null
+            },
+            {
+                "key": "child",
+                "rules": [],
+                "nested": "Child"
+            }
+        ])
+    );
+}
@@ -0,0 +1,216 @@
+use serde_json::json;
+use serde_valid::schema::{SchemaError, Validator};
+
+#[test]
+fn schema_object_is_ok() {
+    let validator = Validator::new(&json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string", "minLength": 1, "pattern": "^[a-z]+$" },
+            "score": { "type": "number", "minimum": 0, "exclusiveMaximum": 100 },
+            "tags": { "type": "array", "items": { "enum": ["a", "b"] }, "uniqueItems": true }
+        },
+        "required": ["name"]
+    }))
+    .unwrap();
+
+    assert!(validator
+        .validate(&json!({ "name": "abc", "score": 99.5, "tags": ["a", "b"] }))
+        .is_ok());
+    assert!(validator.is_valid(&json!({ "name": "abc" })));
+}
+
+#[test]
+fn schema_object_is_err() {
+    let validator = Validator::new(&json!({
+        "type": "object",
+        "properties": {
+            "name": { "type": "string", "minLength": 1, "pattern": "^[a-z]+$" },
+            "score": { "type": "number", "minimum": 0, "exclusiveMaximum": 100 },
+            "tags": { "type": "array", "items": { "enum": ["a", "b"] }, "uniqueItems": true }
+        },
+        "required": ["name", "score"],
+        "additionalProperties": false,
+        "maxProperties": 3
+    }))
+    .unwrap();
+
+    let errors = validator
+        .validate(&json!({ "name": "A1", "tags": ["a", "c", "a"], "other": 1, "more": 2 }))
+        .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!({
+            "errors": ["The size of the properties must be `<= 3`."],
+            "properties": {
+                "score": { "errors": ["The property is required."] },
+                "name": { "errors": [r#"The value must match the pattern of "^[a-z]+$"."#] },
+                "tags": {
+                    "errors": ["The items must be unique."],
+                    "items": {
+                        "1": { "errors": ["The value must be in [a, b]."] }
+                    }
+                },
+                "other": { "errors": ["The value is not allowed."] },
+                "more": { "errors": ["The value is not allowed."] }
+            }
+        })
+    );
+}
+
+#[test]
+fn schema_type_is_err() {
+    let validator = Validator::new(&json!({
+        "type": "array",
+        "items": { "type": ["integer", "null"], "maximum": 10, "multipleOf": 2 }
+    }))
+    .unwrap();
+
+    assert!(validator.is_valid(&json!([2, null, 4.0])));
+    assert_eq!(
+        validator
+            .validate(&json!([12, "a", 3]))
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "items": {
+                "0": { "errors": ["The number must be `<= 10`."] },
+                "1": { "errors": ["The type must be in [integer, null]."] },
+                "2": { "errors": ["The value must be multiple of `2`."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn schema_reference_is_err() {
+    let validator = Validator::new(&json!({
+        "$ref": "#/$defs/Node",
+        "$defs": {
+            "Node": {
+                "type": "object",
+                "properties": {
+                    "value": { "const": 1 },
+                    "children": { "type": "array", "items": { "$ref": "#/$defs/Node" } }
+                }
+            }
+        }
+    }))
+    .unwrap();
+
+    assert!(validator.is_valid(&json!({ "value": 1, "children": [{ "value": 1 }] })));
+    assert_eq!(
+        validator
+            .validate(&json!({ "children": [{ "value": 2, "children": [] }] }))
+            .unwrap_err()
+            .to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "children": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "value": { "errors": ["The value must be in [1]."] }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn schema_is_invalid() {
+    assert_eq!(
+        Validator::new(&json!({ "properties": { "a": { "minLength": -1 } } })).unwrap_err(),
+        SchemaError::InvalidKeyword {
+            pointer: "/properties/a".to_string(),
+            keyword: "minLength".to_string(),
+            expected: "a non-negative integer",
+        }
+    );
+    assert_eq!(
+        Validator::new(&json!({ "items": { "anyOf": [] } })).unwrap_err(),
+        SchemaError::UnsupportedKeyword {
+            pointer: "/items".to_string(),
+            keyword: "anyOf".to_string(),
+        }
+    );
+    assert_eq!(
+        Validator::new(&json!({ "$ref": "#/$defs/Missing" }))
+            .unwrap_err()
+            .to_string(),
+        ": `$ref` of `#/$defs/Missing` can not be resolved."
+    );
+}
+
+#[test]
+fn schema_cyclic_reference_is_invalid() {
+    assert_eq!(
+        Validator::new(&json!({ "$defs": { "A": { "$ref": "#/$defs/A" } }, "$ref": "#/$defs/A" }))
+            .unwrap_err(),
+        SchemaError::CyclicReference {
+            pointer: "/$defs/A".to_string(),
+        }
+    );
+    assert!(matches!(
+        Validator::new(&json!({
+            "$defs": {
+                "A": { "$ref": "#/$defs/B" },
+                "B": { "type": "object", "$ref": "#/$defs/A" }
+            },
+            "properties": { "a": { "$ref": "#/$defs/A" } }
+        })),
+        Err(SchemaError::CyclicReference { .. })
+    ));
+}
+
+#[test]
+fn schema_enum_of_arrays_and_objects() {
+    let validator = Validator::new(&json!({
+        "enum": [[1, 2], { "a": 1.0 }, "x"]
+    }))
+    .unwrap();
+
+    assert!(validator.is_valid(&json!([1, 2])));
+    assert!(validator.is_valid(&json!({ "a": 1 })));
+    assert!(validator.is_valid(&json!("x")));
+    assert_eq!(
+        validator.validate(&json!([2, 1])).unwrap_err().to_string(),
+        json!({
+            "errors": [r#"The value must be in [[1,2], {"a":1.0}, x]."#],
+            "items": {}
+        })
+        .to_string()
+    );
+
+    let validator = Validator::new(&json!({ "const": { "a": [null] } })).unwrap();
+    assert!(validator.is_valid(&json!({ "a": [null] })));
+    assert!(!validator.is_valid(&json!({ "a": [] })));
+}
+
+#[test]
+fn schema_length_counts_code_points() {
+    let validator = Validator::new(&json!({ "minLength": 4, "maxLength": 4 })).unwrap();
+
+    // 4 code points in 3 graphemes.
+    assert!(validator.is_valid(&json!("a̐éö")));
+    assert!(!validator.is_valid(&json!("abc")));
+}
+
+#[test]
+fn schema_unique_items_compares_numbers_by_value() {
+    let validator = Validator::new(&json!({ "uniqueItems": true })).unwrap();
+
+    assert!(!validator.is_valid(&json!([1, 1.0])));
+    assert!(!validator.is_valid(&json!([{ "a": [1] }, { "a": [1.0] }])));
+    assert!(validator.is_valid(&json!([1, 1.5])));
+}
@@ -0,0 +1,110 @@
+#[cfg(feature = "schemars")]
+mod tests {
+    use std::collections::HashMap;
+
+    use schemars::{schema_for, JsonSchema};
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::Validate;
+
+    #[test]
+    fn schemars_named_struct() {
+        #[derive(Deserialize, Validate, JsonSchema)]
+        #[serde(rename_all = "camelCase")]
+        #[schemars(transform = serde_valid::schemars::transform::<Self>)]
+        struct TestStruct {
+            #[validate(minimum = 0)]
+            #[validate(exclusive_maximum = 100)]
+            max_value: i32,
+            #[validate(min_length = 1)]
+            #[validate(max_length = 8)]
+            #[validate(pattern = "^[a-z]+$")]
+            name: String,
+            #[serde(rename = "tag_list")]
+            #[validate(max_items = 4)]
+            #[validate(unique_items)]
+            #[validate(max_length = 8)]
+            tags: Vec<String>,
+            #[validate(enumerate = ["a", "b"])]
+            kind: Option<String>,
+            #[validate(min_properties = 1)]
+            labels: HashMap<String, String>,
+        }
+
+        let schema = schema_for!(TestStruct);
+        let properties = schema.get("properties").unwrap();
+
+        assert_eq!(
+            properties["maxValue"],
+            json!({
+                "type": "integer",
+                "format": "int32",
+                "minimum": 0,
+                "exclusiveMaximum": 100
+            })
+        );
+        assert_eq!(
+            properties["name"],
+            json!({
+                "type": "string",
+                "minLength": 1,
+                "maxLength": 8,
+                "pattern": "^[a-z]+$"
+            })
+        );
+        assert_eq!(
+            properties["tag_list"],
+            json!({
+                "type": "array",
+                "items": { "type": "string", "maxLength": 8 },
+                "maxItems": 4,
+                "uniqueItems": true
+            })
+        );
+        assert_eq!(
+            properties["kind"],
+            json!({ "type": ["string", "null"], "enum": ["a", "b", null] })
+        );
+        assert_eq!(properties["labels"]["minProperties"], json!(1));
+    }
+
+    #[test]
+    fn schemars_unnamed_struct() {
+        #[derive(Validate, JsonSchema)]
+        #[schemars(transform = serde_valid::schemars::transform::<Self>)]
+        struct NewType(#[validate(maximum = 10)] u8);
+
+        #[derive(Validate, JsonSchema)]
+        #[schemars(transform = serde_valid::schemars::transform::<Self>)]
+        struct Tuple(
+            #[validate(min_length = 1)] String,
+            #[validate(multiple_of = 2)] i32,
+        );
+
+        assert_eq!(schema_for!(NewType).get("maximum"), Some(&json!(10)));
+
+        let schema = schema_for!(Tuple);
+        let items = schema.get("prefixItems").unwrap();
+        assert_eq!(items[0]["minLength"], json!(1));
+        assert_eq!(items[1]["multipleOf"], json!(2));
+    }
+
+    #[test]
+    fn schemars_enum_variants_are_left_as_they_are() {
+        #[allow(dead_code)]
+        #[derive(Validate, JsonSchema)]
+        #[schemars(transform = serde_valid::schemars::transform::<Self>)]
+        enum TestEnum {
+            Named {
+                #[validate(maximum = 10)]
+                val: i32,
+            },
+        }
+
+        let schema = schema_for!(TestEnum);
+        let variant = &schema.get("oneOf").unwrap()[0]["properties"]["Named"];
+
+        assert_eq!(variant["properties"]["val"]["type"], json!("integer"));
+        assert!(variant["properties"]["val"].get("maximum").is_none());
+    }
+}
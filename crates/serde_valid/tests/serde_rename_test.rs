@@ -122,3 +122,53 @@ fn serde_rename_enume_is_err() {
         })
     );
 }
+
+#[test]
+fn serde_rename_all_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct TestStruct {
+        #[validate(maximum = 100)]
+        max_value: i32,
+        #[validate(minimum = 0)]
+        #[serde(rename = "min")]
+        min_value: i32,
+    }
+
+    let err = TestStruct::from_json_value(json!({ "maxValue": 123, "min": -1 })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "maxValue": { "errors": ["The number must be `<= 100`."] },
+                "min": { "errors": ["The number must be `>= 0`."] }
+            }
+        })
+    );
+}
+
+#[test]
+fn serde_rename_all_enum_variant_is_err() {
+    #[derive(Debug, Validate, Deserialize)]
+    enum TestEnum {
+        #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+        Named {
+            #[validate(maximum = 100)]
+            max_value: i32,
+        },
+    }
+
+    let err = TestEnum::from_json_value(json!({ "Named": { "MAX_VALUE": 123 } })).unwrap_err();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&err.to_string()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "MAX_VALUE": { "errors": ["The number must be `<= 100`."] }
+            }
+        })
+    );
+}
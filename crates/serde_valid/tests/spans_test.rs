@@ -0,0 +1,122 @@
+use serde::Deserialize;
+use serde_valid::json::FromJsonStr;
+use serde_valid::Validate;
+
+#[derive(Debug, Validate, Deserialize)]
+struct Config {
+    #[validate(maximum = 10)]
+    val: i32,
+    #[validate(max_length = 2)]
+    names: Vec<String>,
+    #[validate]
+    server: Server,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+struct Server {
+    #[validate(minimum = 1024)]
+    port: u16,
+}
+
+#[test]
+fn json_spans() {
+    let source = r#"{
+  "val": 12,
+  "names": ["ab", "abc"],
+  "server": { "port": 80 }
+}"#;
+
+    let err = Config::from_json_str_with_spans(source).unwrap_err();
+
+    assert_eq!(
+        err.labels()
+            .iter()
+            .map(|label| {
+                let span = label.span.unwrap();
+                (
+                    label.pointer.as_str(),
+                    span.line,
+                    span.column,
+                    &source[span.offset..span.offset + span.len],
+                )
+            })
+            .collect::<Vec<_>>(),
+        vec![
+            ("/val", 2, 10, "12"),
+            ("/names/1", 3, 19, "\"abc\""),
+            ("/server/port", 4, 23, "80"),
+        ]
+    );
+    assert!(err.error().is_validation_errors());
+}
+
+#[test]
+fn json_spans_render() {
+    let source = "{\n  \"val\": 12, \"names\": [], \"server\": { \"port\": 8080 }\n}";
+
+    let err = Config::from_json_str_with_spans(source).unwrap_err();
+
+    assert_eq!(err.source_text(), source);
+    assert_eq!(
+        err.render(),
+        [
+            "  × The number must be `<= 10`.",
+            "   ╭─[/val 2:10]",
+            " 2 │   \"val\": 12, \"names\": [], \"server\": { \"port\": 8080 }",
+            "   ·          ──",
+            "   ╰────",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn json_spans_of_deserialize_error() {
+    let source = "{\n  \"val\": \"twelve\"\n}";
+
+    let err = Config::from_json_str_with_spans(source).unwrap_err();
+
+    assert!(err.error().is_serde_error());
+    let span = err.labels()[0].span.unwrap();
+    assert_eq!((span.line, span.column), (2, 17));
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_spans() {
+    use serde_valid::yaml::FromYamlStr;
+
+    let source = "val: 12\nnames:\n  - ab\n  - abc\nserver:\n  port: 80\n";
+
+    let err = Config::from_yaml_str_with_spans(source).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        [
+            "/val at 1:6: The number must be `<= 10`.",
+            "/names/1 at 4:5: The length of the value must be `<= 2`.",
+            "/server/port at 6:9: The number must be `>= 1024`.",
+        ]
+        .join("\n")
+    );
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_spans() {
+    use serde_valid::toml::FromTomlStr;
+
+    let source = "val = 12\nnames = [\"ab\", \"abc\"]\n\n[server]\nport = 80\n";
+
+    let err = Config::from_toml_str_with_spans(source).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        [
+            "/val at 1:7: The number must be `<= 10`.",
+            "/names/1 at 2:16: The length of the value must be `<= 2`.",
+            "/server/port at 5:8: The number must be `>= 1024`.",
+        ]
+        .join("\n")
+    );
+}
@@ -0,0 +1,175 @@
+use serde_valid::testing::{
+    assert_errors_eq, assert_invalid, assert_valid, error_kinds, ErrorKind,
+};
+use serde_valid::Validate;
+
+#[derive(Validate)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    #[validate(multiple_of = 2)]
+    val: i32,
+    #[validate(max_items = 2)]
+    #[validate(pattern = "^[a-z]+$")]
+    names: Vec<String>,
+    #[validate]
+    child: Option<Child>,
+}
+
+#[derive(Validate)]
+struct Child {
+    #[validate(min_length = 1)]
+    name: String,
+}
+
+fn invalid_struct() -> TestStruct {
+    TestStruct {
+        val: 11,
+        names: vec!["a".to_string(), "B".to_string(), "c".to_string()],
+        child: Some(Child {
+            name: String::new(),
+        }),
+    }
+}
+
+#[test]
+fn assert_valid_is_ok() {
+    assert_valid!(TestStruct {
+        val: 10,
+        names: vec!["a".to_string()],
+        child: None,
+    });
+}
+
+#[test]
+#[should_panic(expected = "expected the value to be valid")]
+fn assert_valid_panics() {
+    assert_valid!(invalid_struct());
+}
+
+#[test]
+fn assert_invalid_is_ok() {
+    assert_invalid!(invalid_struct());
+    assert_invalid!(invalid_struct(), "/val" => ErrorKind::Maximum);
+    assert_invalid!(
+        invalid_struct(),
+        "/names/1" => ErrorKind::Pattern,
+        "/child/name" => ErrorKind::MinLength,
+    );
+}
+
+#[test]
+#[should_panic(expected = "missing:\n    /val: minimum\n")]
+fn assert_invalid_panics_on_missing_error() {
+    assert_invalid!(invalid_struct(), "/val" => ErrorKind::Minimum);
+}
+
+#[test]
+#[should_panic(expected = "expected the value to be invalid")]
+fn assert_invalid_panics_on_valid_value() {
+    assert_invalid!(Child {
+        name: "a".to_string()
+    });
+}
+
+#[test]
+fn assert_errors_eq_is_ok() {
+    assert_errors_eq!(
+        invalid_struct(),
+        [
+            ("/child/name", ErrorKind::MinLength),
+            ("/names", ErrorKind::MaxItems),
+            ("/names/1", ErrorKind::Pattern),
+            ("/val", ErrorKind::MultipleOf),
+            ("/val", ErrorKind::Maximum),
+        ]
+    );
+    assert_errors_eq!(
+        Child {
+            name: "a".to_string()
+        },
+        []
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "missing:\n    /names/0: pattern\n  unexpected:\n    /names/1: pattern\n"
+)]
+fn assert_errors_eq_panics_with_diff() {
+    assert_errors_eq!(
+        invalid_struct(),
+        [
+            ("/child/name", ErrorKind::MinLength),
+            ("/names", ErrorKind::MaxItems),
+            ("/names/0", ErrorKind::Pattern),
+            ("/val", ErrorKind::Maximum),
+            ("/val", ErrorKind::MultipleOf),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "unexpected:\n    /val: maximum\n")]
+fn assert_errors_eq_panics_on_unexpected_error() {
+    assert_errors_eq!(
+        invalid_struct(),
+        [
+            ("/child/name", ErrorKind::MinLength),
+            ("/names", ErrorKind::MaxItems),
+            ("/names/1", ErrorKind::Pattern),
+            ("/val", ErrorKind::MultipleOf),
+        ]
+    );
+}
+
+#[test]
+fn assertions_of_nested_collections() {
+    #[derive(Validate)]
+    struct TestStruct {
+        #[validate(max_length = 2)]
+        matrix: Vec<Vec<String>>,
+        #[validate]
+        children: Vec<Child>,
+    }
+
+    let value = TestStruct {
+        matrix: vec![
+            vec!["a".to_string()],
+            vec!["abc".to_string(), "b".to_string()],
+        ],
+        children: vec![
+            Child {
+                name: "a".to_string(),
+            },
+            Child {
+                name: String::new(),
+            },
+        ],
+    };
+
+    assert_invalid!(value, "/matrix/1/0" => ErrorKind::MaxLength);
+    assert_errors_eq!(
+        value,
+        [
+            ("/children/1/name", ErrorKind::MinLength),
+            ("/matrix/1/0", ErrorKind::MaxLength),
+        ]
+    );
+}
+
+#[test]
+fn error_kinds_of_errors() {
+    let errors = invalid_struct().validate().unwrap_err();
+
+    assert_eq!(
+        error_kinds(&errors),
+        vec![
+            ("/val".to_string(), ErrorKind::Maximum),
+            ("/val".to_string(), ErrorKind::MultipleOf),
+            ("/names".to_string(), ErrorKind::MaxItems),
+            ("/names/1".to_string(), ErrorKind::Pattern),
+            ("/child/name".to_string(), ErrorKind::MinLength),
+        ]
+    );
+    assert_eq!(ErrorKind::MinLength.to_string(), "min_length");
+}
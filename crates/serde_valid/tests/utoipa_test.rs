@@ -0,0 +1,109 @@
+#[cfg(feature = "utoipa")]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+    use serde_json::json;
+    use serde_valid::Validate;
+    use utoipa::{OpenApi, PartialSchema, ToSchema};
+
+    #[test]
+    fn utoipa_named_struct() {
+        #[derive(Deserialize, Validate, ToSchema)]
+        #[serde(rename_all = "camelCase")]
+        struct TestStruct {
+            #[validate(minimum = 0)]
+            #[validate(exclusive_maximum = 100)]
+            max_value: i32,
+            #[validate(min_length = 1)]
+            #[validate(max_length = 8)]
+            #[validate(pattern = "^[a-z]+$")]
+            name: String,
+            #[serde(rename = "tag_list")]
+            #[validate(max_items = 4)]
+            #[validate(unique_items)]
+            #[validate(max_length = 8)]
+            tags: Vec<String>,
+            #[validate(enumerate = ["a", "b"])]
+            kind: Option<String>,
+            #[validate(multiple_of = 0.5)]
+            score: f64,
+            #[validate(min_properties = 1)]
+            labels: HashMap<String, String>,
+        }
+
+        #[derive(OpenApi)]
+        #[openapi(components(schemas(TestStruct)))]
+        struct ApiDoc;
+
+        let mut openapi = ApiDoc::openapi();
+        serde_valid::utoipa::apply::<TestStruct>(&mut openapi).unwrap();
+
+        let schema =
+            serde_json::to_value(&openapi.components.unwrap().schemas["TestStruct"]).unwrap();
+        let properties = &schema["properties"];
+
+        assert_eq!(
+            properties["maxValue"],
+            json!({
+                "type": "integer",
+                "format": "int32",
+                "minimum": 0,
+                "exclusiveMaximum": 100
+            })
+        );
+        assert_eq!(
+            properties["name"],
+            json!({
+                "type": "string",
+                "minLength": 1,
+                "maxLength": 8,
+                "pattern": "^[a-z]+$"
+            })
+        );
+        assert_eq!(
+            properties["tag_list"],
+            json!({
+                "type": "array",
+                "items": { "type": "string", "maxLength": 8 },
+                "maxItems": 4,
+                "uniqueItems": true
+            })
+        );
+        assert_eq!(
+            properties["kind"],
+            json!({ "type": ["string", "null"], "enum": ["a", "b", null] })
+        );
+        assert_eq!(properties["score"]["multipleOf"], json!(0.5));
+        assert_eq!(properties["labels"]["minProperties"], json!(1));
+    }
+
+    #[test]
+    fn utoipa_transform_new_type() {
+        #[derive(Validate, ToSchema)]
+        struct NewType(#[validate(maximum = 10)] u8);
+
+        let mut schema = NewType::schema();
+        serde_valid::utoipa::transform::<NewType>(&mut schema).unwrap();
+
+        assert_eq!(serde_json::to_value(&schema).unwrap()["maximum"], json!(10));
+    }
+
+    #[test]
+    fn utoipa_enum_variants_are_left_as_they_are() {
+        #[allow(dead_code)]
+        #[derive(Validate, ToSchema)]
+        enum TestEnum {
+            Named {
+                #[validate(maximum = 10)]
+                val: u8,
+            },
+        }
+
+        let mut schema = TestEnum::schema();
+        let expected = serde_json::to_value(&schema).unwrap();
+        serde_valid::utoipa::transform::<TestEnum>(&mut schema).unwrap();
+
+        assert_eq!(serde_json::to_value(&schema).unwrap(), expected);
+    }
+}
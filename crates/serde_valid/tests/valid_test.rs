@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_valid::json::{FromJsonStr, FromJsonValue, ToJsonString};
+use serde_valid::{Valid, Validate};
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Validate)]
+struct TestStruct {
+    #[validate(maximum = 10)]
+    val: i32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Request {
+    items: Vec<Valid<TestStruct>>,
+}
+
+#[test]
+fn valid_new_is_ok() {
+    let valid = Valid::new(TestStruct { val: 10 }).unwrap();
+
+    assert_eq!(valid.val, 10);
+    assert_eq!(valid.as_ref(), &TestStruct { val: 10 });
+    assert_eq!(valid.into_inner(), TestStruct { val: 10 });
+}
+
+#[test]
+fn valid_new_is_err() {
+    let errors = Valid::new(TestStruct { val: 11 }).unwrap_err();
+
+    assert_eq!(
+        errors.to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": { "errors": ["The number must be `<= 10`."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn valid_serialize_is_transparent() {
+    let valid = Valid::new(TestStruct { val: 10 }).unwrap();
+
+    assert_eq!(valid.to_json_string().unwrap(), r#"{"val":10}"#);
+}
+
+#[test]
+fn valid_deserialize_is_ok() {
+    let valid = Valid::<TestStruct>::from_json_str(r#"{ "val": 10 }"#).unwrap();
+
+    assert_eq!(valid.val, 10);
+}
+
+#[test]
+fn valid_deserialize_is_err() {
+    let err = Valid::<TestStruct>::from_json_value(json!({ "val": 11 })).unwrap_err();
+
+    assert!(err.is_serde_error());
+    assert_eq!(
+        err.as_serde_error().unwrap().to_string(),
+        json!({
+            "errors": [],
+            "properties": {
+                "val": { "errors": ["The number must be `<= 10`."] }
+            }
+        })
+        .to_string()
+    );
+}
+
+#[test]
+fn valid_nested_in_serde_type() {
+    let request: Request =
+        serde_json::from_value(json!({ "items": [{ "val": 1 }, { "val": 2 }] })).unwrap();
+
+    assert_eq!(
+        request
+            .items
+            .iter()
+            .map(|item| item.val)
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert!(serde_json::from_value::<Request>(json!({ "items": [{ "val": 11 }] })).is_err());
+}
+
+#[test]
+fn valid_nested_in_validated_type() {
+    #[derive(Debug, Deserialize, Validate)]
+    struct Parent {
+        #[validate]
+        #[validate(max_items = 1)]
+        children: Vec<Valid<TestStruct>>,
+    }
+
+    let err =
+        Parent::from_json_value(json!({ "children": [{ "val": 1 }, { "val": 2 }] })).unwrap_err();
+
+    assert!(err.is_validation_errors());
+}
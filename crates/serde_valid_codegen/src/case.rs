@@ -0,0 +1,97 @@
+/// Reserved words which can not be used as identifiers without `r#`.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "gen", "macro", "override", "priv", "try",
+    "typeof", "unsized", "virtual", "yield",
+];
+
+/// Split a name into words, at non-alphanumeric characters and lower-to-upper case changes.
+fn words(name: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let mut previous_lowercase = false;
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            previous_lowercase = false;
+            continue;
+        }
+        if c.is_uppercase() && previous_lowercase && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        previous_lowercase = c.is_lowercase() || c.is_numeric();
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+/// `PascalCase` name of a type or a variant.
+pub fn to_pascal_case(name: &str) -> String {
+    let name = words(name)
+        .into_iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first
+                    .to_uppercase()
+                    .chain(chars.flat_map(char::to_lowercase))
+                    .collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<String>();
+    escape_leading_digit(name, "Value")
+}
+
+/// `snake_case` name of a field, escaped with `r#` if it is a keyword.
+pub fn to_snake_case(name: &str) -> String {
+    let name = words(name)
+        .into_iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_");
+    let name = escape_leading_digit(name, "value");
+    match name.as_str() {
+        "self" | "Self" | "super" | "crate" => format!("{name}_"),
+        _ if KEYWORDS.contains(&name.as_str()) => format!("r#{name}"),
+        _ => name,
+    }
+}
+
+fn escape_leading_digit(name: String, empty: &str) -> String {
+    match name.chars().next() {
+        None => empty.to_string(),
+        Some(first) if first.is_numeric() => format!("_{name}"),
+        Some(_) => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_pascal_case() {
+        assert_eq!(to_pascal_case("user_name"), "UserName");
+        assert_eq!(to_pascal_case("userName"), "UserName");
+        assert_eq!(to_pascal_case("in-progress"), "InProgress");
+        assert_eq!(to_pascal_case("HTTP"), "Http");
+        assert_eq!(to_pascal_case("2fa"), "_2fa");
+    }
+
+    #[test]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case("userName"), "user_name");
+        assert_eq!(to_snake_case("Content-Type"), "content_type");
+        assert_eq!(to_snake_case("type"), "r#type");
+        assert_eq!(to_snake_case("self"), "self_");
+        assert_eq!(to_snake_case(""), "value");
+    }
+}
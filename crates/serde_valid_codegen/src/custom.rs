@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+use serde_valid::rules::{Rule, TypeRules};
+use serde_valid::validation::error::escape_pointer_token;
+
+use crate::schema::CUSTOM_KEYWORD;
+
+/// Annotate the schema of a derived type with the names of its custom validator functions.
+///
+/// The rules of `#[validate]` fields are followed to the schemas in `$defs`.
+/// The custom validators of enum variants are not annotated.
+pub fn annotate_custom_validators(schema: &mut Value, rules: &TypeRules) {
+    annotate(schema, String::new(), rules, &mut HashSet::new());
+}
+
+fn annotate(root: &mut Value, pointer: String, rules: &TypeRules, visited: &mut HashSet<String>) {
+    if !visited.insert(pointer.clone()) {
+        return;
+    }
+    add_custom_names(root.pointer_mut(&pointer), &rules.rules);
+
+    for field in &rules.fields {
+        let field_pointer = root
+            .pointer(&pointer)
+            .and_then(|schema| field_pointer(schema, field.key))
+            .map(|field_pointer| format!("{pointer}{field_pointer}"));
+        if let Some(field_pointer) = field_pointer {
+            add_custom_names(root.pointer_mut(&field_pointer), &field.rules);
+        }
+
+        if let Some(nested) = field.nested() {
+            let nested_pointer = format!("/$defs/{}", escape_pointer_token(nested.name));
+            if root.pointer(&nested_pointer).is_some() {
+                annotate(root, nested_pointer, &nested, visited);
+            }
+        }
+    }
+}
+
+/// The JSON pointer of the schema of a field, relative to the schema of its type.
+fn field_pointer(schema: &Value, key: &str) -> Option<String> {
+    if schema.get("properties")?.get(key).is_some() {
+        return Some(format!("/properties/{}", escape_pointer_token(key)));
+    }
+    if let Some(schemas) = schema.get("allOf").and_then(Value::as_array) {
+        return schemas.iter().enumerate().find_map(|(index, schema)| {
+            field_pointer(schema, key).map(|pointer| format!("/allOf/{index}{pointer}"))
+        });
+    }
+    None
+}
+
+fn add_custom_names(schema: Option<&mut Value>, rules: &[Rule]) {
+    let names = rules
+        .iter()
+        .filter_map(|rule| match rule {
+            Rule::Custom(Some(name)) => Some(Value::from(*name)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if let (Some(Value::Object(object)), false) = (schema, names.is_empty()) {
+        object.insert(CUSTOM_KEYWORD.to_string(), Value::Array(names));
+    }
+}
@@ -0,0 +1,137 @@
+//! # Serde Valid Codegen
+//!
+//! Generate Rust types with [serde_valid](https://docs.rs/serde_valid) validations from JSON Schemas,
+//! and TypeScript types with [Zod](https://zod.dev) schemas for frontend validations.
+//!
+//! ## Rust
+//!
+//! - Objects become structs with `#[serde(rename)]`, and the properties which are not `required` become `Option`.
+//! - The validation keywords become `#[validate(...)]` attributes (e.g. `"maxLength": 4` → `#[validate(max_length = 4)]`).
+//! - String `enum`s become enums, and `oneOf`/`anyOf` become untagged enums.
+//! - `$defs` (or `definitions`) become types named after their keys, referenced by `$ref`.
+//! - Keywords without a serde_valid equivalent (e.g. `format`) are kept as `// WARNING:` comments.
+//!
+//! ```rust
+//! use serde_json::json;
+//!
+//! let source = serde_valid_codegen::generate_rust(
+//!     &json!({
+//!         "type": "object",
+//!         "properties": {
+//!             "userName": { "type": "string", "maxLength": 8 }
+//!         }
+//!     }),
+//!     "User",
+//! );
+//!
+//! assert!(source.contains(
+//!     r#"    #[validate(max_length = 8)]
+//!     #[serde(rename = "userName")]
+//!     pub user_name: Option<String>,
+//! "#
+//! ));
+//! ```
+//!
+//! ### Build Script
+//!
+//! In `build.rs`,
+//!
+//! ```rust,no_run
+//! let out_dir = std::env::var("OUT_DIR").unwrap();
+//! serde_valid_codegen::generate_rust_file(
+//!     "schemas/user.json",
+//!     "User",
+//!     std::path::Path::new(&out_dir).join("user.rs"),
+//! )
+//! .unwrap();
+//! println!("cargo::rerun-if-changed=schemas/user.json");
+//! ```
+//!
+//! and `include!(concat!(env!("OUT_DIR"), "/user.rs"));` in the crate.
+//!
+//! ## TypeScript
+//!
+//! [`generate_zod`] generates a TypeScript type and a Zod schema (e.g. `User` and `UserSchema`) of each schema.
+//!
+//! - `minLength`/`maxLength` become `.min()`/`.max()` of strings, and `pattern` becomes `.regex()`.
+//! - `minimum`/`maximum` become `.min()`/`.max()`, and the exclusive ones become `.gt()`/`.lt()`.
+//! - String `enum`s become `z.enum()`, and the other `enum`s become `z.union()` of `z.literal()`s.
+//! - `$ref` becomes a lazy reference to the schema of the definition.
+//!
+//! [`generate_zod_for`] generates them from the derived [`JsonSchema`](serde_valid::json_schema::JsonSchema)
+//! of a type, and annotates its custom validator functions, given by the derived
+//! [`ValidateRules`](serde_valid::rules::ValidateRules), as TODO refinements to port by hand.
+//!
+//! ```rust
+//! use serde_valid::json_schema::JsonSchema;
+//! use serde_valid::Validate;
+//!
+//! fn validate_name(name: &str) -> Result<(), serde_valid::validation::Error> {
+//!     Ok(())
+//! }
+//!
+//! #[derive(Validate, JsonSchema)]
+//! struct User {
+//!     #[validate(max_length = 8)]
+//!     #[validate(custom = validate_name)]
+//!     name: String,
+//! }
+//!
+//! let source = serde_valid_codegen::generate_zod_for::<User>();
+//!
+//! assert!(source.contains(
+//!     "name: z.string().max(8).refine(() => true /* TODO: port the custom validator `validate_name`. */),"
+//! ));
+//! ```
+//!
+//! Types are not available in the build script of their own crate,
+//! so write the file from a test or a binary of the crate (e.g. an xtask).
+//!
+//! ## Command Line
+//!
+//! ```sh
+//! serde-valid-codegen rust schemas/user.json --name User --output src/user.rs
+//! serde-valid-codegen zod schemas/user.json --name User --output web/src/user.ts
+//! ```
+
+mod case;
+mod custom;
+mod error;
+mod rust;
+mod schema;
+mod zod;
+
+use std::path::Path;
+
+pub use custom::annotate_custom_validators;
+pub use error::Error;
+pub use rust::generate_rust;
+pub use zod::{generate_zod, generate_zod_for};
+
+/// Read a JSON Schema file, and write the generated Rust types to `output`.
+pub fn generate_rust_file(
+    schema: impl AsRef<Path>,
+    root_name: &str,
+    output: impl AsRef<Path>,
+) -> Result<(), Error> {
+    let schema = read_schema(schema.as_ref())?;
+    std::fs::write(output, generate_rust(&schema, root_name))?;
+    Ok(())
+}
+
+/// Read a JSON Schema file, and write the generated TypeScript types and Zod schemas to `output`.
+pub fn generate_zod_file(
+    schema: impl AsRef<Path>,
+    root_name: &str,
+    output: impl AsRef<Path>,
+) -> Result<(), Error> {
+    let schema = read_schema(schema.as_ref())?;
+    std::fs::write(output, generate_zod(&schema, root_name))?;
+    Ok(())
+}
+
+/// Read a JSON Schema file.
+pub fn read_schema(path: &Path) -> Result<serde_json::Value, Error> {
+    let schema = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&schema)?)
+}
@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+#[derive(Debug, clap::Parser)]
+#[command(
+    version,
+    about = "Generate serde_valid types and Zod schemas from JSON Schemas."
+)]
+enum Args {
+    /// Generate Rust structs and enums with `#[validate(...)]` attributes.
+    Rust(Options),
+
+    /// Generate TypeScript types and Zod schemas.
+    Zod(Options),
+}
+
+#[derive(Debug, clap::Args)]
+struct Options {
+    /// JSON Schema file.
+    schema: PathBuf,
+
+    /// Name of the root type.
+    #[arg(long, default_value = "Root")]
+    name: String,
+
+    /// Output file. Defaults to stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+fn main() -> Result<(), serde_valid_codegen::Error> {
+    let (args, generate): (_, fn(&serde_json::Value, &str) -> String) = match Args::parse() {
+        Args::Rust(args) => (args, serde_valid_codegen::generate_rust),
+        Args::Zod(args) => (args, serde_valid_codegen::generate_zod),
+    };
+    let schema = serde_valid_codegen::read_schema(&args.schema)?;
+    let source = generate(&schema, &args.name);
+    match args.output {
+        Some(output) => std::fs::write(output, source)?,
+        None => print!("{source}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,558 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+
+use crate::case::{to_pascal_case, to_snake_case};
+use crate::schema::{
+    definition_pointers, is_nullable_pair, is_string_enum, kind, literal, type_names,
+    unsupported_keywords, Kind,
+};
+
+const DERIVE: &str =
+    "#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, serde_valid::Validate)]";
+const DERIVE_ENUM: &str =
+    "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]";
+
+/// Generate Rust types with serde_valid validations from a JSON Schema.
+///
+/// The root schema is named `root_name`, and the schemas in `$defs` (or `definitions`)
+/// are named after their keys.
+pub fn generate_rust(schema: &Value, root_name: &str) -> String {
+    let mut generator = RustGenerator {
+        root: schema,
+        root_name: String::new(),
+        definitions: IndexMap::new(),
+        names: HashSet::new(),
+        stack: vec![],
+        items: vec![],
+    };
+
+    generator.root_name = generator.unique_name(&to_pascal_case(root_name));
+    for pointer in definition_pointers(schema) {
+        let name = pointer.rsplit('/').next().unwrap_or_default();
+        let name = generator.unique_name(&to_pascal_case(name));
+        generator.definitions.insert(pointer, name);
+    }
+
+    generator.generate_named(&generator.root_name.clone(), schema);
+    for (pointer, name) in generator.definitions.clone() {
+        if let Some(definition) = schema.pointer(&pointer) {
+            generator.generate_named(&name, definition);
+        }
+    }
+
+    let mut source = String::from("// This file is generated by serde_valid_codegen.\n");
+    for item in generator.items {
+        source.push('\n');
+        source.push_str(&item);
+    }
+    source
+}
+
+struct RustGenerator<'a> {
+    root: &'a Value,
+    root_name: String,
+    /// Type names of the definitions, keyed by JSON pointer.
+    definitions: IndexMap<String, String>,
+    names: HashSet<String>,
+    /// Types being generated, to box recursive fields.
+    stack: Vec<String>,
+    items: Vec<String>,
+}
+
+/// The type of a field with its validations.
+#[derive(Debug, Default)]
+struct FieldType {
+    ty: String,
+    validations: Vec<String>,
+    nested: bool,
+    warnings: Vec<String>,
+}
+
+impl FieldType {
+    fn json_value(warnings: Vec<String>) -> Self {
+        Self {
+            ty: "serde_json::Value".to_string(),
+            warnings,
+            ..Default::default()
+        }
+    }
+
+    fn optional(mut self) -> Self {
+        if !self.ty.starts_with("Option<") {
+            self.ty = format!("Option<{}>", self.ty);
+        }
+        self
+    }
+
+    /// Attributes of the field, indented by `indent`.
+    fn attributes(&self, indent: &str) -> String {
+        let mut attributes = String::new();
+        for warning in &self.warnings {
+            attributes.push_str(&format!("{indent}// WARNING: {warning}\n"));
+        }
+        for validation in &self.validations {
+            attributes.push_str(&format!("{indent}#[validate({validation})]\n"));
+        }
+        if self.nested {
+            attributes.push_str(&format!("{indent}#[validate]\n"));
+        }
+        attributes
+    }
+}
+
+impl RustGenerator<'_> {
+    fn unique_name(&mut self, name: &str) -> String {
+        let mut unique = name.to_string();
+        let mut index = 2;
+        while !self.names.insert(unique.clone()) {
+            unique = format!("{name}{index}");
+            index += 1;
+        }
+        unique
+    }
+
+    /// Generate a type named `name`. The item is reserved first to keep the parents first.
+    fn generate_named(&mut self, name: &str, schema: &Value) {
+        let slot = self.items.len();
+        self.items.push(String::new());
+        self.stack.push(name.to_string());
+
+        let item = match (kind(schema), schema.as_object()) {
+            (Kind::Struct, Some(object)) => self.generate_struct(name, object),
+            (Kind::StringEnum, Some(object)) => self.generate_string_enum(name, object),
+            (Kind::Union, Some(object)) => self.generate_union(name, object),
+            _ => self.generate_newtype(name, schema),
+        };
+
+        self.stack.pop();
+        self.items[slot] = item;
+    }
+
+    fn generate_struct(&mut self, name: &str, object: &Map<String, Value>) -> String {
+        let mut warnings = unsupported_keywords(object);
+        let required = object
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| {
+                required
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let mut attributes = vec![];
+        match object.get("additionalProperties") {
+            Some(Value::Bool(false)) => {
+                attributes.push("#[serde(deny_unknown_fields)]".to_string())
+            }
+            Some(Value::Object(_)) => {
+                warnings.push("`additionalProperties` of structs is not supported.".to_string())
+            }
+            _ => {}
+        }
+        for keyword in ["minProperties", "maxProperties"] {
+            if object.contains_key(keyword) {
+                warnings.push(format!("`{keyword}` of structs is not supported."));
+            }
+        }
+
+        let mut fields = String::new();
+        let mut field_names = HashSet::new();
+        let properties = object.get("properties").and_then(Value::as_object);
+        for (property, schema) in properties.into_iter().flatten() {
+            // Properties such as `userName` and `user_name` have the same snake case name.
+            let snake_case_name = to_snake_case(property);
+            let mut field_name = snake_case_name.clone();
+            let mut index = 2;
+            while !field_names.insert(field_name.clone()) {
+                field_name = format!("{snake_case_name}{index}");
+                index += 1;
+            }
+            let hint = format!("{name}{}", to_pascal_case(property));
+            let mut field = self.resolve(schema, &hint, false);
+            if !required.contains(&property.as_str()) {
+                field = field.optional();
+            }
+
+            fields.push_str(&doc_comment(schema, "    "));
+            fields.push_str(&field.attributes("    "));
+            if field_name.trim_start_matches("r#") != property {
+                fields.push_str(&format!("    #[serde(rename = {property:?})]\n"));
+            }
+            fields.push_str(&format!("    pub {field_name}: {},\n", field.ty));
+        }
+
+        format!(
+            "{doc}{warnings}{DERIVE}\n{attributes}pub struct {name} {{\n{fields}}}\n",
+            doc = doc_comment(&Value::Object(object.clone()), ""),
+            warnings = warning_comments(&warnings, ""),
+            attributes = attributes
+                .iter()
+                .map(|attribute| format!("{attribute}\n"))
+                .collect::<String>(),
+        )
+    }
+
+    fn generate_string_enum(&mut self, name: &str, object: &Map<String, Value>) -> String {
+        let mut names = HashSet::new();
+        let mut variants = String::new();
+        for value in object["enum"].as_array().into_iter().flatten() {
+            let Some(value) = value.as_str() else {
+                continue;
+            };
+            let mut variant = to_pascal_case(value);
+            while !names.insert(variant.clone()) {
+                variant.push('_');
+            }
+            if variant != value {
+                variants.push_str(&format!("    #[serde(rename = {value:?})]\n"));
+            }
+            variants.push_str(&format!("    {variant},\n"));
+        }
+
+        format!(
+            "{doc}{warnings}{DERIVE_ENUM}\npub enum {name} {{\n{variants}}}\n",
+            doc = doc_comment(&Value::Object(object.clone()), ""),
+            warnings = warning_comments(&unsupported_keywords(object), ""),
+        )
+    }
+
+    fn generate_union(&mut self, name: &str, object: &Map<String, Value>) -> String {
+        let branches = object
+            .get("oneOf")
+            .or_else(|| object.get("anyOf"))
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut names = HashSet::new();
+        let mut variants = String::new();
+        for branch in &branches {
+            let base = match branch.get("$ref").and_then(Value::as_str) {
+                Some(reference) => reference.rsplit('/').next().map(to_pascal_case),
+                None => type_names(branch)
+                    .0
+                    .first()
+                    .map(|name| to_pascal_case(name)),
+            }
+            .unwrap_or_else(|| "Value".to_string());
+            let mut variant = base.clone();
+            let mut index = 2;
+            while !names.insert(variant.clone()) {
+                variant = format!("{base}{index}");
+                index += 1;
+            }
+
+            if branch.get("type").and_then(Value::as_str) == Some("null") {
+                variants.push_str(&format!("    {variant},\n"));
+                continue;
+            }
+            let field = self.resolve(branch, &format!("{name}{variant}"), false);
+            let attributes = field.attributes("        ");
+            if attributes.is_empty() {
+                variants.push_str(&format!("    {variant}({}),\n", field.ty));
+            } else {
+                variants.push_str(&format!(
+                    "    {variant}(\n{attributes}        {},\n    ),\n",
+                    field.ty
+                ));
+            }
+        }
+
+        format!(
+            "{doc}{warnings}{DERIVE}\n#[serde(untagged)]\npub enum {name} {{\n{variants}}}\n",
+            doc = doc_comment(&Value::Object(object.clone()), ""),
+            warnings = warning_comments(&unsupported_keywords(object), ""),
+        )
+    }
+
+    fn generate_newtype(&mut self, name: &str, schema: &Value) -> String {
+        let field = self.resolve(schema, &format!("{name}Value"), false);
+        let attributes = field.attributes("    ");
+        let field = if attributes.is_empty() {
+            format!("pub {}", field.ty)
+        } else {
+            format!("\n{attributes}    pub {},\n", field.ty)
+        };
+
+        format!(
+            "{doc}{DERIVE}\npub struct {name}({field});\n",
+            doc = doc_comment(schema, ""),
+        )
+    }
+
+    /// The type of a field of `schema`. Inline structs and enums are named after `hint`.
+    fn resolve(&mut self, schema: &Value, hint: &str, indirect: bool) -> FieldType {
+        let object = match schema {
+            Value::Object(object) => object,
+            Value::Bool(false) => {
+                return FieldType::json_value(vec![
+                    "The schema `false` is not supported.".to_string()
+                ])
+            }
+            _ => return FieldType::json_value(vec![]),
+        };
+
+        if let Some(reference) = object.get("$ref") {
+            return self.resolve_reference(reference, indirect);
+        }
+        match kind(schema) {
+            Kind::Struct | Kind::StringEnum | Kind::Union => {
+                let name = self.unique_name(hint);
+                self.generate_named(&name, schema);
+                return FieldType {
+                    nested: !is_string_enum(schema),
+                    ty: name,
+                    ..Default::default()
+                };
+            }
+            Kind::Other => {}
+        }
+
+        let mut warnings = unsupported_keywords(object);
+        if let Some(branches) = is_nullable_pair(object) {
+            let branch = branches
+                .iter()
+                .find(|branch| !crate::schema::is_null(branch));
+            let mut field = self.resolve(branch.unwrap_or(&Value::Bool(true)), hint, indirect);
+            field.warnings.extend(warnings);
+            return field.optional();
+        }
+
+        let (types, nullable) = type_names(schema);
+        let base = match types.as_slice() {
+            [] if object.contains_key("items") => "array",
+            [] if object.contains_key("additionalProperties") => "object",
+            [] => "",
+            [base] => base.as_str(),
+            _ => {
+                warnings.push(format!(
+                    "The union of types [{}] is not supported.",
+                    types.join(", ")
+                ));
+                ""
+            }
+        };
+
+        let mut field = match base {
+            "string" => {
+                let mut field = FieldType {
+                    ty: "String".to_string(),
+                    ..Default::default()
+                };
+                for (keyword, validation) in
+                    [("minLength", "min_length"), ("maxLength", "max_length")]
+                {
+                    if let Some(value) = object.get(keyword).and_then(Value::as_u64) {
+                        field.validations.push(format!("{validation} = {value}"));
+                    }
+                }
+                if let Some(pattern) = object.get("pattern").and_then(Value::as_str) {
+                    field.validations.push(format!("pattern = {pattern:?}"));
+                }
+                field
+            }
+            "integer" | "number" => {
+                let mut field = FieldType {
+                    ty: if base == "integer" { "i64" } else { "f64" }.to_string(),
+                    ..Default::default()
+                };
+                for (keyword, validation) in [
+                    ("minimum", "minimum"),
+                    ("maximum", "maximum"),
+                    ("exclusiveMinimum", "exclusive_minimum"),
+                    ("exclusiveMaximum", "exclusive_maximum"),
+                    ("multipleOf", "multiple_of"),
+                ] {
+                    if let Some(value) = object.get(keyword) {
+                        match number_literal(value, base == "integer") {
+                            Some(value) => {
+                                field.validations.push(format!("{validation} = {value}"))
+                            }
+                            None => warnings.push(format!("`{keyword}` must be a {base}.")),
+                        }
+                    }
+                }
+                field
+            }
+            "boolean" => FieldType {
+                ty: "bool".to_string(),
+                ..Default::default()
+            },
+            "null" => FieldType {
+                ty: "()".to_string(),
+                ..Default::default()
+            },
+            "array" => self.resolve_array(object, hint, &mut warnings),
+            "object" => self.resolve_map(object, hint, &mut warnings),
+            _ => FieldType::json_value(vec![]),
+        };
+
+        let enumerate = match (object.get("enum"), object.get("const")) {
+            (Some(Value::Array(values)), _) => Some(values.clone()),
+            (None, Some(value)) => Some(vec![value.clone()]),
+            _ => None,
+        };
+        if let Some(values) = enumerate {
+            let literals = values
+                .iter()
+                .filter(|value| !value.is_null())
+                .map(|value| literal(value, base == "number"))
+                .collect::<Option<Vec<_>>>();
+            match literals {
+                Some(literals) if matches!(base, "string" | "integer" | "number") => field
+                    .validations
+                    .push(format!("enumerate = [{}]", literals.join(", "))),
+                _ => warnings.push("`enum` of this type is not supported.".to_string()),
+            }
+        }
+
+        field.warnings.extend(warnings);
+        if nullable {
+            field.optional()
+        } else {
+            field
+        }
+    }
+
+    fn resolve_reference(&mut self, reference: &Value, indirect: bool) -> FieldType {
+        let target = reference
+            .as_str()
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| {
+                let name = match pointer {
+                    "" => self.root_name.clone(),
+                    pointer => self.definitions.get(pointer)?.clone(),
+                };
+                Some((name, self.root.pointer(pointer)?))
+            });
+        let Some((name, definition)) = target else {
+            return FieldType::json_value(vec![format!("`$ref` of {reference} is not supported.")]);
+        };
+
+        let ty = if !indirect && self.stack.contains(&name) {
+            format!("Box<{name}>")
+        } else {
+            name
+        };
+        FieldType {
+            ty,
+            nested: !is_string_enum(definition),
+            ..Default::default()
+        }
+    }
+
+    fn resolve_array(
+        &mut self,
+        object: &Map<String, Value>,
+        hint: &str,
+        warnings: &mut Vec<String>,
+    ) -> FieldType {
+        let items = match object.get("items") {
+            Some(items) => self.resolve(items, &format!("{hint}Item"), true),
+            None => FieldType::json_value(vec![]),
+        };
+        let mut field = FieldType {
+            ty: format!("Vec<{}>", items.ty),
+            nested: items.nested,
+            ..Default::default()
+        };
+        warnings.extend(items.warnings);
+
+        // The validations of the items are applied to each item by serde_valid,
+        // except the validations of arrays.
+        for validation in items.validations {
+            if ["min_items", "max_items", "unique_items"]
+                .iter()
+                .any(|name| validation.starts_with(name))
+            {
+                warnings.push(format!("`{validation}` of nested arrays is not supported."));
+            } else {
+                field.validations.push(validation);
+            }
+        }
+        for (keyword, validation) in [("minItems", "min_items"), ("maxItems", "max_items")] {
+            if let Some(value) = object.get(keyword).and_then(Value::as_u64) {
+                field.validations.push(format!("{validation} = {value}"));
+            }
+        }
+        if object.get("uniqueItems") == Some(&Value::Bool(true)) {
+            if matches!(items.ty.as_str(), "String" | "i64" | "bool") {
+                field.validations.push("unique_items".to_string());
+            } else {
+                warnings.push(format!("`uniqueItems` of `{}` is not supported.", items.ty));
+            }
+        }
+        field
+    }
+
+    fn resolve_map(
+        &mut self,
+        object: &Map<String, Value>,
+        hint: &str,
+        warnings: &mut Vec<String>,
+    ) -> FieldType {
+        let values = match object.get("additionalProperties") {
+            Some(schema @ Value::Object(_)) => self.resolve(schema, &format!("{hint}Value"), true),
+            _ => FieldType::json_value(vec![]),
+        };
+        let mut field = FieldType {
+            ty: format!("std::collections::HashMap<String, {}>", values.ty),
+            nested: values.nested,
+            ..Default::default()
+        };
+        warnings.extend(values.warnings);
+        if !values.validations.is_empty() {
+            warnings
+                .push("The validations of `additionalProperties` are not supported.".to_string());
+        }
+        for (keyword, validation) in [
+            ("minProperties", "min_properties"),
+            ("maxProperties", "max_properties"),
+        ] {
+            if let Some(value) = object.get(keyword).and_then(Value::as_u64) {
+                field.validations.push(format!("{validation} = {value}"));
+            }
+        }
+        field
+    }
+}
+
+/// A number literal of `i64` or `f64`.
+fn number_literal(value: &Value, integer: bool) -> Option<String> {
+    let number = value.as_number()?;
+    if integer {
+        match (number.as_i64(), number.as_f64()) {
+            (Some(value), _) => Some(value.to_string()),
+            (None, Some(value)) if value.fract() == 0.0 && value.abs() < 9e15 => {
+                Some((value as i64).to_string())
+            }
+            _ => None,
+        }
+    } else {
+        let value = number.as_f64()?;
+        Some(format!("{value:?}"))
+    }
+}
+
+fn doc_comment(schema: &Value, indent: &str) -> String {
+    let Some(description) = schema.get("description").and_then(Value::as_str) else {
+        return String::new();
+    };
+    description
+        .lines()
+        .map(|line| match line.trim_end() {
+            "" => format!("{indent}///\n"),
+            line => format!("{indent}/// {line}\n"),
+        })
+        .collect()
+}
+
+fn warning_comments(warnings: &[String], indent: &str) -> String {
+    warnings
+        .iter()
+        .map(|warning| format!("{indent}// WARNING: {warning}\n"))
+        .collect()
+}
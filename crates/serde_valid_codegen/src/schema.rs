@@ -0,0 +1,174 @@
+use serde_json::{Map, Value};
+use serde_valid::validation::error::escape_pointer_token;
+
+/// The annotation of the names of custom validators, added by [`crate::generate_zod_for`].
+pub const CUSTOM_KEYWORD: &str = "x-serde-valid-custom";
+
+/// Keywords which are translated into types or validations.
+const SUPPORTED_KEYWORDS: &[&str] = &[
+    "$ref",
+    "type",
+    "enum",
+    "const",
+    "anyOf",
+    "oneOf",
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "multipleOf",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "items",
+    "minItems",
+    "maxItems",
+    "uniqueItems",
+    "properties",
+    "required",
+    "additionalProperties",
+    "minProperties",
+    "maxProperties",
+];
+
+/// Keywords which do not affect validation.
+const ANNOTATIONS: &[&str] = &[
+    "$schema",
+    "$id",
+    "$comment",
+    "$defs",
+    "definitions",
+    "title",
+    "description",
+    "default",
+    "examples",
+    "readOnly",
+    "writeOnly",
+    "deprecated",
+];
+
+/// How a schema is generated as a named type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// A struct of `properties`.
+    Struct,
+    /// A unit-only enum of the string values of `enum`.
+    StringEnum,
+    /// An untagged enum of the branches of `oneOf` or `anyOf`.
+    Union,
+    /// Any other schema, as a field type.
+    Other,
+}
+
+pub fn kind(schema: &Value) -> Kind {
+    let Some(object) = schema.as_object() else {
+        return Kind::Other;
+    };
+    if object.contains_key("$ref") || is_nullable_pair(object).is_some() {
+        Kind::Other
+    } else if object
+        .get("oneOf")
+        .or_else(|| object.get("anyOf"))
+        .is_some_and(Value::is_array)
+    {
+        Kind::Union
+    } else if is_string_enum(schema) {
+        Kind::StringEnum
+    } else if object.get("properties").is_some_and(Value::is_object) {
+        Kind::Struct
+    } else {
+        Kind::Other
+    }
+}
+
+pub fn is_string_enum(schema: &Value) -> bool {
+    match schema.get("enum") {
+        Some(Value::Array(values)) => {
+            !values.is_empty()
+                && values.iter().all(Value::is_string)
+                && schema.get("$ref").is_none()
+        }
+        _ => false,
+    }
+}
+
+pub fn is_null(schema: &Value) -> bool {
+    schema.get("type").and_then(Value::as_str) == Some("null")
+}
+
+/// The branches of `oneOf` or `anyOf` of exactly one schema and `null`.
+pub fn is_nullable_pair(object: &Map<String, Value>) -> Option<&Vec<Value>> {
+    let branches = object
+        .get("oneOf")
+        .or_else(|| object.get("anyOf"))?
+        .as_array()?;
+    (branches.len() == 2 && branches.iter().filter(|branch| is_null(branch)).count() == 1)
+        .then_some(branches)
+}
+
+/// The names of `type` except `null`, and whether `null` is one of them.
+pub fn type_names(schema: &Value) -> (Vec<String>, bool) {
+    let names = match schema.get("type") {
+        Some(Value::String(name)) => vec![name.clone()],
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(|name| name.as_str().map(ToString::to_string))
+            .collect(),
+        _ => vec![],
+    };
+    let nullable = names.len() > 1 && names.iter().any(|name| name == "null");
+    let names = if nullable {
+        names.into_iter().filter(|name| name != "null").collect()
+    } else {
+        names
+    };
+    (names, nullable)
+}
+
+/// JSON pointers of the schemas in `$defs` and `definitions`.
+pub fn definition_pointers(schema: &Value) -> Vec<String> {
+    ["$defs", "definitions"]
+        .into_iter()
+        .flat_map(|keyword| {
+            schema
+                .get(keyword)
+                .and_then(Value::as_object)
+                .into_iter()
+                .flat_map(move |definitions| {
+                    definitions
+                        .keys()
+                        .map(move |name| format!("/{keyword}/{}", escape_pointer_token(name)))
+                })
+        })
+        .collect()
+}
+
+/// Warnings of the keywords without a serde_valid equivalent.
+pub fn unsupported_keywords(object: &Map<String, Value>) -> Vec<String> {
+    unknown_keywords(object, &[])
+        .map(|keyword| format!("`{keyword}` is not supported by serde_valid."))
+        .collect()
+}
+
+/// The keywords which are neither supported, in `supported` nor annotations.
+pub fn unknown_keywords<'a>(
+    object: &'a Map<String, Value>,
+    supported: &'a [&str],
+) -> impl Iterator<Item = &'a String> {
+    object.keys().filter(move |keyword| {
+        !SUPPORTED_KEYWORDS.contains(&keyword.as_str())
+            && !supported.contains(&keyword.as_str())
+            && !ANNOTATIONS.contains(&keyword.as_str())
+    })
+}
+
+/// A Rust literal of a scalar value.
+pub fn literal(value: &Value, float: bool) -> Option<String> {
+    match value {
+        Value::String(string) => Some(format!("{string:?}")),
+        Value::Number(number) if float => number.as_f64().map(|number| format!("{number:?}")),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(boolean) => Some(boolean.to_string()),
+        _ => None,
+    }
+}
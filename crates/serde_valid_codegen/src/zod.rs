@@ -0,0 +1,545 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+
+use serde_valid::json_schema::JsonSchema;
+use serde_valid::rules::ValidateRules;
+
+use crate::case::to_pascal_case;
+use crate::custom::annotate_custom_validators;
+use crate::schema::{
+    definition_pointers, is_nullable_pair, type_names, unknown_keywords, CUSTOM_KEYWORD,
+};
+
+/// Keywords which are translated by the TypeScript generator, in addition to the Rust generator.
+const ZOD_KEYWORDS: &[&str] = &["allOf", "prefixItems", CUSTOM_KEYWORD];
+
+/// Generate TypeScript types and [Zod](https://zod.dev) schemas from a JSON Schema.
+///
+/// The root schema is named `root_name`, and the schemas in `$defs` (or `definitions`)
+/// are named after their keys. Each type `T` is exported with its schema `TSchema`.
+pub fn generate_zod(schema: &Value, root_name: &str) -> String {
+    let mut generator = ZodGenerator {
+        root_name: String::new(),
+        definitions: IndexMap::new(),
+        names: HashSet::new(),
+        warnings: vec![],
+    };
+
+    generator.root_name = generator.unique_name(&to_pascal_case(root_name));
+    for pointer in definition_pointers(schema) {
+        let name = pointer.rsplit('/').next().unwrap_or_default();
+        let name = generator.unique_name(&to_pascal_case(name));
+        generator.definitions.insert(pointer, name);
+    }
+
+    let mut items = vec![generator.generate_named(&generator.root_name.clone(), schema)];
+    for (pointer, name) in generator.definitions.clone() {
+        if let Some(definition) = schema.pointer(&pointer) {
+            items.push(generator.generate_named(&name, definition));
+        }
+    }
+
+    let mut source = String::from(
+        "// This file is generated by serde_valid_codegen.\n\nimport { z } from \"zod\";\n",
+    );
+    for item in items {
+        source.push('\n');
+        source.push_str(&item);
+    }
+    source
+}
+
+/// Generate TypeScript types and Zod schemas of a type with `#[derive(Validate, JsonSchema)]`.
+///
+/// The custom validators of the type are annotated as TODO refinements.
+pub fn generate_zod_for<T>() -> String
+where
+    T: JsonSchema + ValidateRules,
+{
+    let mut schema = T::json_schema();
+    annotate_custom_validators(&mut schema, &T::validate_rules());
+    generate_zod(&schema, &T::schema_name())
+}
+
+struct ZodGenerator {
+    root_name: String,
+    /// Type names of the definitions, keyed by JSON pointer.
+    definitions: IndexMap<String, String>,
+    names: HashSet<String>,
+    /// Warnings of the type being generated.
+    warnings: Vec<String>,
+}
+
+/// A TypeScript type and its Zod schema.
+#[derive(Debug)]
+struct Resolved {
+    ty: String,
+    zod: String,
+}
+
+impl Resolved {
+    fn new(ty: impl Into<String>, zod: impl Into<String>) -> Self {
+        Self {
+            ty: ty.into(),
+            zod: zod.into(),
+        }
+    }
+
+    fn unknown() -> Self {
+        Self::new("unknown", "z.unknown()")
+    }
+
+    fn nullable(self) -> Self {
+        Self::new(
+            format!("{} | null", self.ty),
+            format!("{}.nullable()", self.zod),
+        )
+    }
+
+    fn union(branches: Vec<Resolved>) -> Self {
+        match <[Resolved; 1]>::try_from(branches) {
+            Ok([branch]) => branch,
+            Err(branches) => Self::new(
+                join(branches.iter().map(|branch| branch.ty.as_str()), " | "),
+                format!(
+                    "z.union([{}])",
+                    join(branches.iter().map(|branch| branch.zod.as_str()), ", ")
+                ),
+            ),
+        }
+    }
+}
+
+impl ZodGenerator {
+    fn unique_name(&mut self, name: &str) -> String {
+        let mut unique = name.to_string();
+        let mut index = 2;
+        while !self.names.insert(unique.clone()) {
+            unique = format!("{name}{index}");
+            index += 1;
+        }
+        unique
+    }
+
+    fn warn(&mut self, path: &str, warning: String) {
+        self.warnings.push(match path {
+            "" => warning,
+            path => format!("{path}: {warning}"),
+        });
+    }
+
+    fn generate_named(&mut self, name: &str, schema: &Value) -> String {
+        let resolved = self.resolve(schema, "", 0);
+        let warnings = std::mem::take(&mut self.warnings)
+            .into_iter()
+            .map(|warning| format!("// WARNING: {warning}\n"))
+            .collect::<String>();
+
+        format!(
+            "{doc}{warnings}export type {name} = {ty};\n\n\
+             export const {name}Schema: z.ZodType<{name}> = {zod};\n",
+            doc = doc_comment(schema, ""),
+            ty = resolved.ty,
+            zod = resolved.zod,
+        )
+    }
+
+    /// The type of `schema` at `path`, which is indented by `depth`.
+    fn resolve(&mut self, schema: &Value, path: &str, depth: usize) -> Resolved {
+        let mut resolved = match schema {
+            Value::Object(object) => self.resolve_object(object, path, depth),
+            Value::Bool(false) => Resolved::new("never", "z.never()"),
+            _ => Resolved::unknown(),
+        };
+        let customs = schema.get(CUSTOM_KEYWORD).and_then(Value::as_array);
+        for custom in customs.into_iter().flatten().filter_map(Value::as_str) {
+            resolved.zod.push_str(&format!(
+                ".refine(() => true /* TODO: port the custom validator `{custom}`. */)"
+            ));
+        }
+        resolved
+    }
+
+    fn resolve_object(
+        &mut self,
+        object: &Map<String, Value>,
+        path: &str,
+        depth: usize,
+    ) -> Resolved {
+        for keyword in unknown_keywords(object, ZOD_KEYWORDS) {
+            self.warn(path, format!("`{keyword}` is not supported."));
+        }
+
+        if let Some(reference) = object.get("$ref") {
+            return self.resolve_reference(reference, path);
+        }
+        if let Some(branches) = is_nullable_pair(object) {
+            let branch = branches
+                .iter()
+                .find(|branch| !crate::schema::is_null(branch));
+            return self
+                .resolve(branch.unwrap_or(&Value::Bool(true)), path, depth)
+                .nullable();
+        }
+        if let Some(Value::Array(branches)) = object.get("oneOf").or_else(|| object.get("anyOf")) {
+            let branches = branches
+                .iter()
+                .map(|branch| self.resolve(branch, path, depth))
+                .collect();
+            return Resolved::union(branches);
+        }
+        if let Some(Value::Array(schemas)) = object.get("allOf") {
+            let mut schemas = schemas
+                .iter()
+                .map(|schema| self.resolve(schema, path, depth));
+            let first = schemas.next().unwrap_or_else(Resolved::unknown);
+            return schemas.fold(first, |intersection, schema| {
+                Resolved::new(
+                    format!("{} & {}", group(&intersection.ty), group(&schema.ty)),
+                    format!("z.intersection({}, {})", intersection.zod, schema.zod),
+                )
+            });
+        }
+        match (object.get("enum"), object.get("const")) {
+            (Some(Value::Array(values)), _) => return self.resolve_literals(values, path),
+            (None, Some(value)) => return self.resolve_literals(std::slice::from_ref(value), path),
+            _ => {}
+        }
+
+        let (types, nullable) = type_names(&Value::Object(object.clone()));
+        let types = match types.as_slice() {
+            [] if object.contains_key("items") || object.contains_key("prefixItems") => {
+                vec!["array".to_string()]
+            }
+            [] if object.contains_key("properties")
+                || object.contains_key("additionalProperties") =>
+            {
+                vec!["object".to_string()]
+            }
+            _ => types,
+        };
+        if types.is_empty() {
+            return Resolved::unknown();
+        }
+
+        let branches = types
+            .iter()
+            .map(|base| self.resolve_type(base, object, path, depth))
+            .collect();
+        let resolved = Resolved::union(branches);
+        if nullable {
+            resolved.nullable()
+        } else {
+            resolved
+        }
+    }
+
+    fn resolve_type(
+        &mut self,
+        base: &str,
+        object: &Map<String, Value>,
+        path: &str,
+        depth: usize,
+    ) -> Resolved {
+        match base {
+            "string" => {
+                let mut zod = "z.string()".to_string();
+                for (keyword, method) in [("minLength", "min"), ("maxLength", "max")] {
+                    if let Some(value) = object.get(keyword).and_then(Value::as_u64) {
+                        zod.push_str(&format!(".{method}({value})"));
+                    }
+                }
+                if let Some(pattern) = object.get("pattern").and_then(Value::as_str) {
+                    zod.push_str(&format!(".regex(new RegExp({}))", string_literal(pattern)));
+                }
+                Resolved::new("string", zod)
+            }
+            "integer" | "number" => {
+                let mut zod = match base {
+                    "integer" => "z.number().int()",
+                    _ => "z.number()",
+                }
+                .to_string();
+                for (keyword, method) in [
+                    ("minimum", "min"),
+                    ("maximum", "max"),
+                    ("exclusiveMinimum", "gt"),
+                    ("exclusiveMaximum", "lt"),
+                    ("multipleOf", "multipleOf"),
+                ] {
+                    if let Some(value) = object.get(keyword).and_then(Value::as_number) {
+                        zod.push_str(&format!(".{method}({value})"));
+                    }
+                }
+                Resolved::new("number", zod)
+            }
+            "boolean" => Resolved::new("boolean", "z.boolean()"),
+            "null" => Resolved::new("null", "z.null()"),
+            "array" => self.resolve_array(object, path, depth),
+            "object" => self.resolve_properties(object, path, depth),
+            _ => {
+                self.warn(path, format!("The type `{base}` is not supported."));
+                Resolved::unknown()
+            }
+        }
+    }
+
+    fn resolve_reference(&mut self, reference: &Value, path: &str) -> Resolved {
+        let name = reference
+            .as_str()
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| match pointer {
+                "" => Some(self.root_name.clone()),
+                pointer => self.definitions.get(pointer).cloned(),
+            });
+        match name {
+            // Lazy, so that the schemas can refer to each other in any order.
+            Some(name) => Resolved::new(name.clone(), format!("z.lazy(() => {name}Schema)")),
+            None => {
+                self.warn(path, format!("`$ref` of {reference} is not supported."));
+                Resolved::unknown()
+            }
+        }
+    }
+
+    fn resolve_literals(&mut self, values: &[Value], path: &str) -> Resolved {
+        let nullable = values.iter().any(Value::is_null);
+        let values = values
+            .iter()
+            .filter(|value| !value.is_null())
+            .collect::<Vec<_>>();
+
+        let resolved = if values.is_empty() {
+            Resolved::new("null", "z.null()")
+        } else if values.iter().all(|value| value.is_string()) {
+            let literals = values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>();
+            Resolved::new(
+                literals.join(" | "),
+                format!("z.enum([{}])", literals.join(", ")),
+            )
+        } else if values
+            .iter()
+            .all(|value| !value.is_array() && !value.is_object())
+        {
+            Resolved::union(
+                values
+                    .iter()
+                    .map(|value| Resolved::new(value.to_string(), format!("z.literal({value})")))
+                    .collect(),
+            )
+        } else {
+            self.warn(
+                path,
+                "`enum` of arrays and objects is not supported.".to_string(),
+            );
+            return Resolved::unknown();
+        };
+
+        if nullable && !values.is_empty() {
+            resolved.nullable()
+        } else {
+            resolved
+        }
+    }
+
+    fn resolve_array(&mut self, object: &Map<String, Value>, path: &str, depth: usize) -> Resolved {
+        if let Some(Value::Array(prefix_items)) = object.get("prefixItems") {
+            let items = prefix_items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| self.resolve(item, &format!("{path}[{index}]"), depth))
+                .collect::<Vec<_>>();
+            return Resolved::new(
+                format!(
+                    "[{}]",
+                    join(items.iter().map(|item| item.ty.as_str()), ", ")
+                ),
+                format!(
+                    "z.tuple([{}])",
+                    join(items.iter().map(|item| item.zod.as_str()), ", ")
+                ),
+            );
+        }
+
+        let items = match object.get("items") {
+            Some(items) => self.resolve(items, &format!("{path}[]"), depth),
+            None => Resolved::unknown(),
+        };
+        let mut zod = format!("z.array({})", items.zod);
+        for (keyword, method) in [("minItems", "min"), ("maxItems", "max")] {
+            if let Some(value) = object.get(keyword).and_then(Value::as_u64) {
+                zod.push_str(&format!(".{method}({value})"));
+            }
+        }
+        if object.get("uniqueItems") == Some(&Value::Bool(true)) {
+            zod.push_str(
+                ".refine((items) => new Set(items.map((item) => JSON.stringify(item))).size === items.length, \
+                 { message: \"The items must be unique.\" })",
+            );
+        }
+        Resolved::new(format!("Array<{}>", items.ty), zod)
+    }
+
+    fn resolve_properties(
+        &mut self,
+        object: &Map<String, Value>,
+        path: &str,
+        depth: usize,
+    ) -> Resolved {
+        let additional_properties = object.get("additionalProperties");
+        let mut resolved = match object.get("properties").and_then(Value::as_object) {
+            Some(properties) => {
+                let required = object
+                    .get("required")
+                    .and_then(Value::as_array)
+                    .map(|required| {
+                        required
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                let indent = "  ".repeat(depth + 1);
+                let close = "  ".repeat(depth);
+
+                let mut ty = String::new();
+                let mut zod = String::new();
+                for (property, schema) in properties {
+                    let property_path = match path {
+                        "" => property.clone(),
+                        path => format!("{path}.{property}"),
+                    };
+                    let resolved = self.resolve(schema, &property_path, depth + 1);
+                    let key = property_key(property);
+                    let optional = !required.contains(&property.as_str());
+
+                    ty.push_str(&doc_comment(schema, &indent));
+                    ty.push_str(&format!(
+                        "{indent}{key}{}: {};\n",
+                        // Zod infers the properties of `unknown` as optional.
+                        if optional || resolved.ty == "unknown" {
+                            "?"
+                        } else {
+                            ""
+                        },
+                        resolved.ty
+                    ));
+                    zod.push_str(&format!(
+                        "{indent}{key}: {}{},\n",
+                        resolved.zod,
+                        if optional { ".optional()" } else { "" }
+                    ));
+                }
+
+                let mut resolved = if properties.is_empty() {
+                    Resolved::new("{}", "z.object({})")
+                } else {
+                    Resolved::new(
+                        format!("{{\n{ty}{close}}}"),
+                        format!("z.object({{\n{zod}{close}}})"),
+                    )
+                };
+                match additional_properties {
+                    Some(Value::Bool(false)) => resolved.zod.push_str(".strict()"),
+                    Some(Value::Object(_)) => self.warn(
+                        path,
+                        "`additionalProperties` with `properties` is not supported.".to_string(),
+                    ),
+                    _ => {}
+                }
+                resolved
+            }
+            None => {
+                let values = match additional_properties {
+                    Some(schema @ Value::Object(_)) => {
+                        self.resolve(schema, &format!("{path}{{}}"), depth)
+                    }
+                    _ => Resolved::unknown(),
+                };
+                Resolved::new(
+                    format!("Record<string, {}>", values.ty),
+                    format!("z.record(z.string(), {})", values.zod),
+                )
+            }
+        };
+
+        for (keyword, operator, message) in [
+            (
+                "minProperties",
+                ">=",
+                "The size of the properties must be `>= {}`.",
+            ),
+            (
+                "maxProperties",
+                "<=",
+                "The size of the properties must be `<= {}`.",
+            ),
+        ] {
+            if let Some(value) = object.get(keyword).and_then(Value::as_u64) {
+                resolved.zod.push_str(&format!(
+                    ".refine((value) => Object.keys(value).length {operator} {value}, {{ message: {} }})",
+                    string_literal(&message.replace("{}", &value.to_string()))
+                ));
+            }
+        }
+        resolved
+    }
+}
+
+/// Parenthesize a union type, to be a part of an intersection type.
+fn group(ty: &str) -> String {
+    if ty.contains(" | ") {
+        format!("({ty})")
+    } else {
+        ty.to_string()
+    }
+}
+
+fn join<'a>(items: impl Iterator<Item = &'a str>, separator: &str) -> String {
+    items.collect::<Vec<_>>().join(separator)
+}
+
+/// A TypeScript string literal, which is also a JSON string.
+fn string_literal(value: &str) -> String {
+    Value::from(value).to_string()
+}
+
+/// A property key, quoted if it is not an identifier.
+fn property_key(property: &str) -> String {
+    let mut chars = property.chars();
+    let identifier = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if identifier {
+        property.to_string()
+    } else {
+        string_literal(property)
+    }
+}
+
+fn doc_comment(schema: &Value, indent: &str) -> String {
+    let Some(description) = schema.get("description").and_then(Value::as_str) else {
+        return String::new();
+    };
+    let lines = description.lines().map(str::trim_end).collect::<Vec<_>>();
+    match lines.as_slice() {
+        [line] => format!("{indent}/** {line} */\n"),
+        lines => {
+            let mut comment = format!("{indent}/**\n");
+            for line in lines {
+                match *line {
+                    "" => comment.push_str(&format!("{indent} *\n")),
+                    line => comment.push_str(&format!("{indent} * {line}\n")),
+                }
+            }
+            comment.push_str(&format!("{indent} */\n"));
+            comment
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// This file is generated by serde_valid_codegen.
+
+/// A person.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, serde_valid::Validate)]
+pub struct Person {
+    #[validate(min_length = 1)]
+    #[validate(max_length = 32)]
+    #[serde(rename = "firstName")]
+    pub first_name: String,
+    #[validate(minimum = 0)]
+    #[validate(exclusive_maximum = 150)]
+    pub age: i64,
+    #[validate(minimum = -1.0)]
+    #[validate(multiple_of = 0.5)]
+    pub score: Option<f64>,
+    // WARNING: `format` is not supported by serde_valid.
+    #[validate(pattern = "^[^@]+@[^@]+$")]
+    pub email: Option<String>,
+    #[validate(max_length = 8)]
+    #[validate(max_items = 4)]
+    #[validate(unique_items)]
+    pub tags: Option<Vec<String>>,
+    pub status: Status,
+    #[validate(enumerate = [1, 2, 3])]
+    pub size: Option<i64>,
+    /// Postal address.
+    #[validate]
+    pub address: Option<PersonAddress>,
+    #[validate]
+    pub children: Option<Vec<Person>>,
+    #[validate]
+    pub contact: Option<PersonContact>,
+    #[validate(max_properties = 2)]
+    pub labels: Option<std::collections::HashMap<String, String>>,
+}
+
+/// Postal address.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, serde_valid::Validate)]
+#[serde(deny_unknown_fields)]
+pub struct PersonAddress {
+    #[validate(pattern = "^\\d{3}-\\d{4}$")]
+    #[serde(rename = "zipCode")]
+    pub zip_code: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, serde_valid::Validate)]
+#[serde(untagged)]
+pub enum PersonContact {
+    String(
+        #[validate(max_length = 16)]
+        String,
+    ),
+    Phone(
+        #[validate]
+        Phone,
+    ),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+pub enum Status {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "in-progress")]
+    InProgress,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, serde_valid::Validate)]
+pub struct Phone {
+    #[validate(min_length = 4)]
+    pub number: String,
+    #[validate(enumerate = ["mobile"])]
+    pub r#type: Option<String>,
+}
@@ -0,0 +1,52 @@
+// This file is generated by serde_valid_codegen.
+
+import { z } from "zod";
+
+/** A person. */
+// WARNING: email: `format` is not supported.
+export type Person = {
+  firstName: string;
+  age: number;
+  score?: number | null;
+  email?: string;
+  tags?: Array<string>;
+  status: Status;
+  size?: 1 | 2 | 3;
+  /** Postal address. */
+  address?: {
+    zipCode: string;
+  };
+  children?: Array<Person>;
+  contact?: string | Phone;
+  labels?: Record<string, string>;
+};
+
+export const PersonSchema: z.ZodType<Person> = z.object({
+  firstName: z.string().min(1).max(32),
+  age: z.number().int().min(0).lt(150),
+  score: z.number().min(-1).multipleOf(0.5).nullable().optional(),
+  email: z.string().regex(new RegExp("^[^@]+@[^@]+$")).optional(),
+  tags: z.array(z.string().max(8)).max(4).refine((items) => new Set(items.map((item) => JSON.stringify(item))).size === items.length, { message: "The items must be unique." }).optional(),
+  status: z.lazy(() => StatusSchema),
+  size: z.union([z.literal(1), z.literal(2), z.literal(3)]).optional(),
+  address: z.object({
+    zipCode: z.string().regex(new RegExp("^\\d{3}-\\d{4}$")),
+  }).strict().optional(),
+  children: z.array(z.lazy(() => PersonSchema)).optional(),
+  contact: z.union([z.string().max(16), z.lazy(() => PhoneSchema)]).optional(),
+  labels: z.record(z.string(), z.string()).refine((value) => Object.keys(value).length <= 2, { message: "The size of the properties must be `<= 2`." }).optional(),
+});
+
+export type Status = "active" | "in-progress";
+
+export const StatusSchema: z.ZodType<Status> = z.enum(["active", "in-progress"]);
+
+export type Phone = {
+  number: string;
+  type?: "mobile";
+};
+
+export const PhoneSchema: z.ZodType<Phone> = z.object({
+  number: z.string().min(4),
+  type: z.enum(["mobile"]).optional(),
+});
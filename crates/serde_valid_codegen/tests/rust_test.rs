@@ -0,0 +1,104 @@
+use serde_json::json;
+use serde_valid::Validate;
+
+mod person {
+    include!("fixtures/person.rs");
+}
+
+#[test]
+fn generate_rust_matches_fixture() {
+    let schema: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/person.json")).unwrap();
+
+    assert_eq!(
+        serde_valid_codegen::generate_rust(&schema, "Person"),
+        include_str!("fixtures/person.rs")
+    );
+}
+
+#[test]
+fn generated_rust_is_ok() {
+    let person: person::Person = serde_json::from_value(json!({
+        "firstName": "Alex",
+        "age": 20,
+        "score": 1.5,
+        "tags": ["a", "b"],
+        "status": "in-progress",
+        "address": { "zipCode": "123-4567" },
+        "children": [{ "firstName": "Sam", "age": 1, "status": "active" }],
+        "contact": { "number": "0123", "type": "mobile" },
+        "labels": { "a": "b" }
+    }))
+    .unwrap();
+
+    assert!(person.validate().is_ok());
+}
+
+#[test]
+fn generated_rust_is_err() {
+    let person: person::Person = serde_json::from_value(json!({
+        "firstName": "",
+        "age": 150,
+        "score": 0.2,
+        "tags": ["a", "a"],
+        "status": "active",
+        "size": 4,
+        "address": { "zipCode": "1234567" },
+        "children": [{ "firstName": "Sam", "age": -1, "status": "active" }],
+        "contact": "a phone number too long",
+        "labels": { "a": "b", "c": "d", "e": "f" }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(person.validate().unwrap_err()).unwrap(),
+        json!({
+            "errors": [],
+            "properties": {
+                "firstName": { "errors": ["The length of the value must be `>= 1`."] },
+                "age": { "errors": ["The number must be `< 150`."] },
+                "score": { "errors": ["The value must be multiple of `0.5`."] },
+                "tags": { "errors": ["The items must be unique."] },
+                "size": { "errors": ["The value must be in [1, 2, 3]."] },
+                "address": {
+                    "errors": [],
+                    "properties": {
+                        "zipCode": {
+                            "errors": [r#"The value must match the pattern of "^\d{3}-\d{4}$"."#]
+                        }
+                    }
+                },
+                "children": {
+                    "errors": [],
+                    "items": {
+                        "0": {
+                            "errors": [],
+                            "properties": {
+                                "age": { "errors": ["The number must be `>= 0`."] }
+                            }
+                        }
+                    }
+                },
+                "contact": { "errors": ["The length of the value must be `<= 16`."] },
+                "labels": { "errors": ["The size of the properties must be `<= 2`."] }
+            }
+        })
+    );
+}
+
+#[test]
+fn generate_rust_of_properties_with_same_field_name() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "userName": { "type": "string" },
+            "user_name": { "type": "string" }
+        },
+        "required": ["userName", "user_name"]
+    });
+
+    let rust = serde_valid_codegen::generate_rust(&schema, "User");
+
+    assert!(rust.contains("    #[serde(rename = \"userName\")]\n    pub user_name: String,\n"));
+    assert!(rust.contains("    #[serde(rename = \"user_name\")]\n    pub user_name2: String,\n"));
+}
@@ -0,0 +1,81 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+use serde_valid::json_schema::JsonSchema;
+use serde_valid::Validate;
+
+#[test]
+fn generate_zod_matches_fixture() {
+    let schema: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/person.json")).unwrap();
+
+    assert_eq!(
+        serde_valid_codegen::generate_zod(&schema, "Person"),
+        include_str!("fixtures/person.ts")
+    );
+}
+
+fn validate_name(_: &str) -> Result<(), serde_valid::validation::Error> {
+    Ok(())
+}
+
+fn validate_order(_: &Order) -> Result<(), serde_valid::validation::Error> {
+    Ok(())
+}
+
+#[derive(Deserialize, Validate, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[validate(custom = validate_order)]
+struct Order {
+    #[validate(min_length = 1)]
+    #[validate(custom = validate_name)]
+    customer_name: String,
+    #[validate(enumerate = ["card", "cash"])]
+    payment: Option<String>,
+    #[validate(min_items = 1)]
+    #[validate]
+    items: Vec<Item>,
+}
+
+#[derive(Deserialize, Validate, JsonSchema)]
+struct Item {
+    #[validate(exclusive_minimum = 0)]
+    #[validate(maximum = 100)]
+    quantity: u32,
+    #[validate(custom = validate_name)]
+    #[validate(pattern = r"^[A-Z]{3}$")]
+    code: String,
+}
+
+#[test]
+fn generate_zod_for_derived_type() {
+    assert_eq!(
+        serde_valid_codegen::generate_zod_for::<Order>(),
+        r#"// This file is generated by serde_valid_codegen.
+
+import { z } from "zod";
+
+export type Order = {
+  customerName: string;
+  payment?: "card" | "cash" | null;
+  items: Array<Item>;
+};
+
+export const OrderSchema: z.ZodType<Order> = z.object({
+  customerName: z.string().min(1).refine(() => true /* TODO: port the custom validator `validate_name`. */),
+  payment: z.enum(["card", "cash"]).nullable().optional(),
+  items: z.array(z.lazy(() => ItemSchema)).min(1),
+}).refine(() => true /* TODO: port the custom validator `validate_order`. */);
+
+export type Item = {
+  quantity: number;
+  code: string;
+};
+
+export const ItemSchema: z.ZodType<Item> = z.object({
+  quantity: z.number().int().min(0).max(100).gt(0),
+  code: z.string().regex(new RegExp("^[A-Z]{3}$")).refine(() => true /* TODO: port the custom validator `validate_name`. */),
+});
+"#
+    );
+}
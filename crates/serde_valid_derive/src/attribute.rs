@@ -76,6 +76,7 @@ macro_rules! enum_str {
 
 enum_str! {
     pub enum MetaPathStructValidation {
+        Asynchronous = "asynchronous",
     }
 }
 
@@ -87,6 +88,7 @@ enum_str! {
 enum_str! {
     pub enum MetaNameValueStructValidation {
         Custom = "custom",
+        Context = "context",
     }
 }
 
@@ -99,6 +101,7 @@ enum_str! {
 enum_str! {
     pub enum MetaListFieldValidation {
         Custom = "custom",
+        Rule = "rule",
     }
 }
 
@@ -118,6 +121,9 @@ enum_str! {
         Pattern = "pattern",
         Enumerate = "enumerate",
         Custom = "custom",
+        CustomAsync = "custom_async",
+        SkipIf = "skip_if",
+        Rules = "rules",
     }
 }
 
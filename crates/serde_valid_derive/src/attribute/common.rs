@@ -1,2 +1,5 @@
+pub mod condition;
+pub mod groups;
+pub mod limit;
 pub mod lit;
 pub mod message_format;
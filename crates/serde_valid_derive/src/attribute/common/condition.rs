@@ -0,0 +1,92 @@
+use crate::attribute::Validator;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Split `when = ...` from the items of a field attribute.
+///
+/// Returns the other items and the condition, an expression which can refer to `self`.
+pub fn split_when(
+    metas: Vec<syn::Meta>,
+) -> Result<(Vec<syn::Meta>, Option<syn::Expr>), crate::Errors> {
+    let mut errors = vec![];
+    let mut others = vec![];
+    let mut condition = None;
+
+    for meta in metas {
+        match meta {
+            syn::Meta::NameValue(name_value) if name_value.path.is_ident("when") => {
+                if condition.is_some() {
+                    errors.push(crate::Error::validate_when_duplicated(&name_value));
+                } else {
+                    condition = Some(name_value.value);
+                }
+            }
+            meta => others.push(meta),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok((others, condition))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Run the validator only when the condition of `when = ...` holds.
+pub fn conditional_validator(validator: Validator, condition: Option<&syn::Expr>) -> Validator {
+    match condition {
+        Some(condition) if !validator.is_empty() => quote!(
+            if #condition {
+                #validator
+            }
+        ),
+        _ => validator,
+    }
+}
+
+/// The function or closure of `#[validate(skip_if = ...)]` on a field, which takes the struct or the enum.
+pub fn extract_skip_if(meta_name_value: &syn::MetaNameValue) -> Result<TokenStream, crate::Errors> {
+    match &meta_name_value.value {
+        syn::Expr::Path(path) => Ok(quote!(#path)),
+        syn::Expr::Closure(closure) => Ok(quote!((#closure))),
+        _ => Err(vec![
+            crate::Error::validate_skip_if_need_function_or_closure(meta_name_value),
+        ]),
+    }
+}
+
+/// The functions of `#[validate(skip_if = ...)]` on a field.
+///
+/// Errors in the attributes are left to the field validators.
+pub fn collect_skip_ifs(attributes: &[syn::Attribute]) -> Vec<TokenStream> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+        .filter_map(|attribute| match &attribute.meta {
+            syn::Meta::List(list) => list
+                .parse_args_with(crate::types::CommaSeparatedMetas::parse_terminated)
+                .ok()?
+                .into_iter()
+                .find_map(|meta| match meta {
+                    syn::Meta::NameValue(name_value) if name_value.path.is_ident("skip_if") => {
+                        extract_skip_if(&name_value).ok()
+                    }
+                    _ => None,
+                }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Skip the validators of a field when one of the functions of `skip_if` returns `true`.
+pub fn skipped_validator(validator: Validator, skip_ifs: &[TokenStream]) -> Validator {
+    if validator.is_empty() || skip_ifs.is_empty() {
+        validator
+    } else {
+        quote!(
+            if !(#(#skip_ifs(self))||*) {
+                #validator
+            }
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use crate::attribute::Validator;
+use quote::quote;
+
+/// Split `groups = [...]` from the items of a field attribute.
+///
+/// Returns the other items and the groups, which are values of `ValidationGroup` types.
+pub fn split_groups(
+    nested: crate::types::CommaSeparatedMetas,
+) -> Result<(Vec<syn::Meta>, Vec<syn::Expr>), crate::Errors> {
+    let mut errors = vec![];
+    let mut metas = vec![];
+    let mut groups = vec![];
+
+    for meta in nested {
+        match meta {
+            syn::Meta::NameValue(name_value) if name_value.path.is_ident("groups") => {
+                match &name_value.value {
+                    syn::Expr::Array(array) => groups.extend(array.elems.iter().cloned()),
+                    value => errors.push(crate::Error::validate_groups_need_array(value)),
+                }
+            }
+            meta => metas.push(meta),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok((metas, groups))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Run the validator only when one of the groups is selected, or always without groups.
+pub fn grouped_validator(validator: Validator, groups: &[syn::Expr]) -> Validator {
+    if groups.is_empty() {
+        validator
+    } else {
+        quote!(
+            if #(__groups.contains(&#groups))||* {
+                #validator
+            }
+        )
+    }
+}
@@ -0,0 +1,15 @@
+use crate::attribute::Validator;
+use quote::quote;
+
+/// Skip the validator once the limit of the errors of `ValidateOptions` is reached.
+pub fn limited_validator(validator: Validator) -> Validator {
+    if validator.is_empty() {
+        validator
+    } else {
+        quote!(
+            if !__limit.is_reached() {
+                #validator
+            }
+        )
+    }
+}
@@ -7,4 +7,5 @@ mod object;
 mod string;
 
 pub use field::FieldValidators;
+pub use generic::extract_rule;
 pub use meta::extract_field_validator;
@@ -40,6 +40,7 @@ macro_rules! extract_array_length_validator{
                         #[<$ErrorType:snake>],
                     ) {
                         use ::serde_valid::validation::error::FormatDefault;
+                        __limit.add(1);
 
                         #errors
                             .entry(#rename)
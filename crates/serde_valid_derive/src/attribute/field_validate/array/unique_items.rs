@@ -29,6 +29,7 @@ fn inner_extract_array_unique_items_validator(
             #field_ident
         ) {
             use ::serde_valid::validation::error::FormatDefault;
+            __limit.add(1);
 
             #errors
                 .entry(#rename)
@@ -1,3 +1,5 @@
+use crate::attribute::common::condition::{collect_skip_ifs, skipped_validator};
+use crate::attribute::common::limit::limited_validator;
 use crate::attribute::Validator;
 use crate::types::Field;
 use crate::warning::WithWarnings;
@@ -13,9 +15,14 @@ pub struct FieldValidators<'a, F: Field + Clone + 'a> {
 
 impl<'a, F: Field + Clone> FieldValidators<'a, F> {
     pub fn new(field: Cow<'a, F>, validators: Vec<WithWarnings<Validator>>) -> Self {
+        let skip_ifs = collect_skip_ifs(field.attrs());
         Self {
+            validators: validators
+                .iter()
+                .filter(|v| !v.data.is_empty())
+                .map(|v| skipped_validator(limited_validator(v.data.clone()), &skip_ifs))
+                .collect(),
             field,
-            validators: validators.iter().map(|v| v.data.clone()).collect(),
             warnings: validators.into_iter().flat_map(|v| v.warnings).collect(),
         }
     }
@@ -1,10 +1,16 @@
 mod custom;
+mod custom_async;
 mod enumerate;
+mod rule;
+mod rule_set;
 mod validate;
 
 pub use custom::{
     extract_generic_custom_validator_from_meta_list,
     extract_generic_custom_validator_from_meta_name_value,
 };
+pub use custom_async::extract_generic_custom_async_validator;
 pub use enumerate::extract_generic_enumerate_validator_from_name_value;
+pub use rule::{extract_generic_rule_validator, extract_rule};
+pub use rule_set::extract_generic_rule_set_validator;
 pub use validate::extract_generic_validate_validator;
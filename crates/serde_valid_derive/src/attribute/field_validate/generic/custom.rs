@@ -8,7 +8,7 @@ use quote::quote;
 pub fn extract_generic_custom_validator_from_meta_list(
     field: &impl Field,
     meta_list: &syn::MetaList,
-    _message_format: MessageFormat,
+    message_format: MessageFormat,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
     let path = &meta_list.path;
@@ -19,7 +19,6 @@ pub fn extract_generic_custom_validator_from_meta_list(
         .parse_args_with(CommaSeparatedNestedMetas::parse_terminated)
         .map_err(|error| vec![crate::Error::custom_message_parse_error(path_ident, &error)])?;
 
-    let field_ident = field.ident();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let custom_fn_name = match nested.len() {
         0 => Err(vec![
@@ -33,23 +32,23 @@ pub fn extract_generic_custom_validator_from_meta_list(
             .collect()),
     }?;
     inner_extract_generic_custom_validator(
-        field_ident,
+        &custom_fn_args(field),
         rename,
         &custom_fn_name,
         &field.errors_variable(),
+        &message_format,
     )
 }
 
 pub fn extract_generic_custom_validator_from_meta_name_value(
     field: &impl Field,
     meta_name_value: &syn::MetaNameValue,
-    _message_format: MessageFormat,
+    message_format: MessageFormat,
     rename_map: &RenameMap,
 ) -> Result<Validator, crate::Errors> {
     let field_name = field.name();
     let field_key = field.key();
 
-    let field_ident = field.ident();
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let fn_name = match &meta_name_value.value {
         syn::Expr::Path(path) => Ok(quote!(#path)),
@@ -60,17 +59,35 @@ pub fn extract_generic_custom_validator_from_meta_name_value(
         ]),
     }?;
 
-    inner_extract_generic_custom_validator(field_ident, rename, &fn_name, &field.errors_variable())
+    inner_extract_generic_custom_validator(
+        &custom_fn_args(field),
+        rename,
+        &fn_name,
+        &field.errors_variable(),
+        &message_format,
+    )
+}
+
+/// The arguments of the custom function, with the context of `#[validate(context = ...)]` if any.
+fn custom_fn_args(field: &impl Field) -> TokenStream {
+    let field_ident = field.ident();
+    match field.context() {
+        Some(_) => quote!(#field_ident, __context),
+        None => quote!(#field_ident),
+    }
 }
 
 fn inner_extract_generic_custom_validator(
-    field_ident: &syn::Ident,
+    custom_fn_args: &TokenStream,
     rename: &TokenStream,
     custom_fn_name: &TokenStream,
     errors: &TokenStream,
+    message_format: &MessageFormat,
 ) -> Result<Validator, crate::Errors> {
     Ok(quote!(
-        if let Err(__errors) = serde_valid::validation::custom::wrap_into_vec_errors(#custom_fn_name(#field_ident)) {
+        if let Err(__errors) = serde_valid::validation::custom::wrap_into_vec_errors(#custom_fn_name(#custom_fn_args)) {
+            let __errors = ::serde_valid::validation::custom::custom_errors_by(__errors, #message_format);
+            __limit.add(__errors.len());
             #errors
                 .entry(#rename)
                 .or_default()
@@ -0,0 +1,45 @@
+use crate::attribute::Validator;
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use quote::quote;
+
+/// `#[validate(custom_async = ...)]`, which is awaited only by the derived `AsyncValidate`.
+pub fn extract_generic_custom_async_validator(
+    field: &impl Field,
+    meta_name_value: &syn::MetaNameValue,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_ident = field.ident();
+    let field_name = field.name();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+
+    let fn_name = match &meta_name_value.value {
+        syn::Expr::Path(path) => Ok(quote!(#path)),
+        _ => Err(vec![crate::Error::validate_custom_async_need_function(
+            meta_name_value,
+        )]),
+    }?;
+
+    if field.context().is_some() {
+        return Err(vec![crate::Error::validate_custom_async_with_context(
+            meta_name_value,
+        )]);
+    }
+
+    if !cfg!(feature = "async") {
+        return Err(vec![
+            crate::Error::validate_custom_async_need_async_feature(meta_name_value),
+        ]);
+    }
+
+    if !field.is_async() {
+        return Ok(quote!(let _ = #field_ident;));
+    }
+
+    Ok(quote!(
+        #errors.entry(#rename).or_default();
+        __async_validations.push_custom(#rename, #fn_name(#field_ident));
+    ))
+}
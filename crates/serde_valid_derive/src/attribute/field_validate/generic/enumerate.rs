@@ -29,9 +29,10 @@ fn inner_extract_generic_enumerate_validator(
     let errors = field.errors_variable();
 
     Ok(quote!(
-        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedEnumerate::validate_composited_enumerate(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedEnumerate::validate_composited_enumerate_limited(
             #field_ident,
             &[#lits],
+            __limit,
         ) {
             use ::serde_valid::validation::IntoError;
             use ::serde_valid::validation::error::FormatDefault;
@@ -44,7 +45,9 @@ fn inner_extract_generic_enumerate_validator(
     ))
 }
 
-fn get_enumerate_from_name_value(name_value: &syn::MetaNameValue) -> Result<Lits, crate::Errors> {
+fn get_enumerate_from_name_value(
+    name_value: &syn::MetaNameValue,
+) -> Result<Lits<'_>, crate::Errors> {
     if let syn::Expr::Array(array) = &name_value.value {
         let mut enumerate = Lits::new();
         for item in &array.elems {
@@ -0,0 +1,53 @@
+use crate::attribute::common::message_format::MessageFormat;
+use crate::attribute::Validator;
+use crate::serde::rename::RenameMap;
+use crate::types::{CommaSeparatedMetas, Field};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `#[validate(rule(Name))]` or `#[validate(rule(Name = param))]` of a user-defined `Rule`.
+pub fn extract_generic_rule_validator(
+    field: &impl Field,
+    meta_list: &syn::MetaList,
+    message_format: MessageFormat,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_name = field.name();
+    let field_ident = field.ident();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let (rule, param) = extract_rule(meta_list)?;
+
+    Ok(quote!(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedRule::<#rule, _>::validate_composited_rule_limited(
+            #field_ident,
+            &(#param),
+            __limit,
+        ) {
+            #errors
+                .entry(#rename)
+                .or_default()
+                .push(::serde_valid::validation::rule_error_by::<#rule>(
+                    __composited_error_params,
+                    #message_format,
+                ));
+        }
+    ))
+}
+
+/// The path of the rule and the parameter, which is `()` for `rule(Name)`.
+pub fn extract_rule(meta_list: &syn::MetaList) -> Result<(syn::Path, TokenStream), crate::Errors> {
+    let nested = meta_list
+        .parse_args_with(CommaSeparatedMetas::parse_terminated)
+        .map_err(|_| vec![crate::Error::validate_rule_need_rule(meta_list)])?;
+
+    match (nested.len(), nested.first()) {
+        (1, Some(syn::Meta::Path(path))) => Ok((path.clone(), quote!(()))),
+        (1, Some(syn::Meta::NameValue(name_value))) => {
+            let value = &name_value.value;
+            Ok((name_value.path.clone(), quote!(#value)))
+        }
+        _ => Err(vec![crate::Error::validate_rule_need_rule(meta_list)]),
+    }
+}
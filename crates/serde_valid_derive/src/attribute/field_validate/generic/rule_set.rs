@@ -0,0 +1,38 @@
+use crate::attribute::Validator;
+use crate::serde::rename::RenameMap;
+use crate::types::Field;
+use quote::quote;
+
+/// `#[validate(rules = Name)]` of a `#[derive(RuleSet)]` type.
+///
+/// The macro of the rule set expands its attributes for the field,
+/// with the custom message of this attribute if any.
+pub fn extract_generic_rule_set_validator(
+    field: &impl Field,
+    meta_name_value: &syn::MetaNameValue,
+    custom_message: Option<&syn::Meta>,
+    rename_map: &RenameMap,
+) -> Result<Validator, crate::Errors> {
+    let field_ident = field.ident();
+    let field_name = field.name();
+    let field_key = field.key();
+    let rename = rename_map.get(field_name).unwrap_or(&field_key);
+    let errors = field.errors_variable();
+    let context = field.context();
+    let asynchronous = field.is_async();
+
+    let rule_set = match &meta_name_value.value {
+        syn::Expr::Path(syn::ExprPath { path, .. }) => path,
+        _ => {
+            return Err(vec![crate::Error::validate_rules_need_rule_set(
+                &meta_name_value.value,
+            )])
+        }
+    };
+
+    Ok(quote!(
+        #rule_set! {
+            #field_ident, #errors, (#rename), [#context], [#custom_message], #asynchronous
+        }
+    ))
+}
@@ -14,8 +14,28 @@ pub fn extract_generic_validate_validator(
     let rename = rename_map.get(field_name).unwrap_or(&field_key);
     let errors = field.errors_variable();
 
+    if field.is_async() {
+        return Ok(WithWarnings::new(quote!(
+            #errors.entry(#rename).or_default();
+            __async_validations.push_nested(
+                #rename,
+                ::serde_valid::AsyncValidate::__validate_async_limited(
+                    #field_ident,
+                    __concurrency,
+                    __groups,
+                    __limit,
+                ),
+            );
+        )));
+    }
+
+    let validate = match field.context() {
+        Some(_) => quote!(#field_ident.__validate_limited_with(__context, __groups, __limit)),
+        None => quote!(#field_ident.__validate_limited(__groups, __limit)),
+    };
+
     Ok(WithWarnings::new(quote!(
-        if let Err(__inner_errors) = #field_ident.validate() {
+        if let Err(__inner_errors) = #validate {
             match __inner_errors {
                 ::serde_valid::validation::Errors::Object(__object_errors) => {
                     #errors.entry(#rename).or_default().push(
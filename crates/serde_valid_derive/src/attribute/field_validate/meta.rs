@@ -2,6 +2,8 @@ mod meta_list;
 mod meta_name_value;
 mod meta_path;
 
+use crate::attribute::common::condition::{conditional_validator, split_when};
+use crate::attribute::common::groups::{grouped_validator, split_groups};
 use crate::attribute::common::message_format::{
     default_message_format, extract_custom_message_format,
 };
@@ -17,7 +19,7 @@ use meta_name_value::extract_field_validator_from_meta_name_value;
 use meta_path::extract_field_validator_from_meta_path;
 use std::str::FromStr;
 
-use super::generic::extract_generic_validate_validator;
+use super::generic::{extract_generic_rule_set_validator, extract_generic_validate_validator};
 
 pub fn extract_field_validator(
     field: &impl Field,
@@ -49,6 +51,8 @@ fn inner_extract_field_validator(
                 attribute, &error,
             )]
         })?;
+    let (nested, groups) = split_groups(nested)?;
+    let (nested, condition) = split_when(nested)?;
 
     let WithWarnings {
         data: message_format,
@@ -60,9 +64,11 @@ fn inner_extract_field_validator(
         1 => None,
         2 => match extract_custom_message_format(&nested[1]) {
             Ok(custom_message) => {
-                if nested[0].path().is_ident("custom") {
+                if nested[0].path().is_ident("custom_async") {
                     errors.push(
-                        crate::Error::validate_custom_does_not_support_custom_message(&nested[1]),
+                        crate::Error::validate_custom_async_does_not_support_custom_message(
+                            &nested[1],
+                        ),
                     );
                     None
                 } else {
@@ -119,6 +125,11 @@ fn inner_extract_field_validator(
             )
         }
 
+        (_, _, Ok(MetaNameValueFieldValidation::Rules), syn::Meta::NameValue(validation)) => {
+            extract_generic_rule_set_validator(field, validation, nested.get(1), rename_map)
+                .map(WithWarnings::new)
+        }
+
         (_, _, Ok(validation_type), syn::Meta::NameValue(validation)) => {
             extract_field_validator_from_meta_name_value(
                 field,
@@ -159,7 +170,10 @@ fn inner_extract_field_validator(
         Ok(validator) => {
             if errors.is_empty() {
                 Ok(WithWarnings {
-                    data: validator,
+                    data: grouped_validator(
+                        conditional_validator(validator, condition.as_ref()),
+                        &groups,
+                    ),
                     warnings,
                 })
             } else {
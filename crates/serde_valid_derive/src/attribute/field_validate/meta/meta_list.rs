@@ -1,5 +1,7 @@
 use crate::attribute::common::message_format::MessageFormat;
-use crate::attribute::field_validate::generic::extract_generic_custom_validator_from_meta_list;
+use crate::attribute::field_validate::generic::{
+    extract_generic_custom_validator_from_meta_list, extract_generic_rule_validator,
+};
 use crate::attribute::{MetaListFieldValidation, Validator};
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
@@ -20,5 +22,9 @@ pub fn extract_field_validator_from_meta_list(
             rename_map,
         )
         .map(WithWarnings::new),
+        MetaListFieldValidation::Rule => {
+            extract_generic_rule_validator(field, validation, message_format, rename_map)
+                .map(WithWarnings::new)
+        }
     }
 }
@@ -1,10 +1,11 @@
+use crate::attribute::common::condition::extract_skip_if;
 use crate::attribute::common::lit::get_lit;
 use crate::attribute::common::message_format::MessageFormat;
 use crate::attribute::field_validate::array::{
     extract_array_max_items_validator, extract_array_min_items_validator,
 };
 use crate::attribute::field_validate::generic::{
-    extract_generic_custom_validator_from_meta_name_value,
+    extract_generic_custom_async_validator, extract_generic_custom_validator_from_meta_name_value,
     extract_generic_enumerate_validator_from_name_value,
 };
 use crate::attribute::field_validate::numeric::{
@@ -22,6 +23,7 @@ use crate::attribute::field_validate::string::{
 use crate::attribute::{MetaNameValueFieldValidation, Validator};
 use crate::serde::rename::RenameMap;
 use crate::types::Field;
+use quote::quote;
 
 pub fn extract_field_validator_from_meta_name_value(
     field: &impl Field,
@@ -121,5 +123,14 @@ pub fn extract_field_validator_from_meta_name_value(
                 rename_map,
             )
         }
+        MetaNameValueFieldValidation::CustomAsync => {
+            extract_generic_custom_async_validator(field, validation, rename_map)
+        }
+        // The validators of the field are skipped by `extract_field_validator`.
+        MetaNameValueFieldValidation::SkipIf => extract_skip_if(validation).map(|_| quote!()),
+        // The rule sets take the custom message as it is, in `extract_field_validator`.
+        MetaNameValueFieldValidation::Rules => {
+            unreachable!("This should not be called")
+        }
     }
 }
@@ -29,9 +29,10 @@ fn inner_extract_numeric_multiple_of_validator(
     let multiple_of = get_numeric(validation_value)?;
 
     Ok(quote!(
-        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedMultipleOf::validate_composited_multiple_of(
+        if let Err(__composited_error_params) = ::serde_valid::validation::ValidateCompositedMultipleOf::validate_composited_multiple_of_limited(
             #field_ident,
             #multiple_of,
+            __limit,
         ) {
             use ::serde_valid::validation::IntoError;
             use ::serde_valid::validation::error::FormatDefault;
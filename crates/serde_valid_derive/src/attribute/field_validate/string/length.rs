@@ -35,9 +35,10 @@ macro_rules! extract_string_length_validator{
                 let [<$ErrorType:snake>] = get_numeric(validation_value)?;
 
                 Ok(quote!(
-                    if let Err(__composited_error_params) = ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake>](
+                    if let Err(__composited_error_params) = ::serde_valid::validation::[<ValidateComposited $ErrorType>]::[<validate_composited_ $ErrorType:snake _limited>](
                         #field_ident,
                         #[<$ErrorType:snake>],
+                        __limit,
                     ) {
                         use ::serde_valid::validation::IntoError;
                         use ::serde_valid::validation::error::FormatDefault;
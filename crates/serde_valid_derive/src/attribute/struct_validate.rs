@@ -1,12 +1,16 @@
 pub mod generic;
 mod meta;
 
-use crate::{attribute::Validator, warning::WithWarnings};
+use crate::{
+    attribute::{common::limit::limited_validator, Validator},
+    warning::WithWarnings,
+};
 
 use self::meta::extract_struct_validator;
 
 pub fn collect_struct_custom_from_named_struct(
     attributes: &[syn::Attribute],
+    context: Option<&syn::Type>,
 ) -> Result<WithWarnings<Vec<Validator>>, crate::Errors> {
     let mut errors = vec![];
 
@@ -14,8 +18,11 @@ pub fn collect_struct_custom_from_named_struct(
         .iter()
         .filter_map(|attribute| {
             if attribute.path().is_ident("validate") {
-                match extract_struct_validator(attribute) {
-                    Ok(validator) => Some(validator),
+                match extract_struct_validator(attribute, context) {
+                    Ok(validator) => Some(WithWarnings {
+                        data: limited_validator(validator.data),
+                        warnings: validator.warnings,
+                    }),
                     Err(validator_error) => {
                         errors.extend(validator_error);
                         None
@@ -33,3 +40,69 @@ pub fn collect_struct_custom_from_named_struct(
         Err(errors)
     }
 }
+
+/// The type of `#[validate(context = ...)]` on the struct or the enum.
+///
+/// Errors in the other attributes are left to the validators.
+pub fn extract_context_type(
+    attributes: &[syn::Attribute],
+) -> Result<Option<syn::Type>, crate::Errors> {
+    let mut errors = vec![];
+    let mut context = None;
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("validate") {
+            continue;
+        }
+        let syn::Meta::List(list) = &attribute.meta else {
+            continue;
+        };
+        let Ok(nested) = list.parse_args_with(crate::types::CommaSeparatedMetas::parse_terminated)
+        else {
+            continue;
+        };
+        let Some(syn::Meta::NameValue(name_value)) = nested.first() else {
+            continue;
+        };
+        if !name_value.path.is_ident("context") {
+            continue;
+        }
+
+        let value = &name_value.value;
+        match syn::parse2::<syn::Type>(quote::quote!(#value)) {
+            Ok(_) if context.is_some() => {
+                errors.push(crate::Error::validate_context_duplicated(name_value))
+            }
+            Ok(ty) => context = Some(ty),
+            Err(_) => errors.push(crate::Error::validate_context_need_type(value)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(context)
+    } else {
+        Err(errors)
+    }
+}
+
+/// The path of `#[validate(asynchronous)]` on the struct or the enum, which derives `AsyncValidate`.
+///
+/// Errors in the other attributes are left to the validators.
+pub fn extract_asynchronous(attributes: &[syn::Attribute]) -> Option<syn::Path> {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+        .find_map(|attribute| {
+            let syn::Meta::List(list) = &attribute.meta else {
+                return None;
+            };
+            match list
+                .parse_args_with(crate::types::CommaSeparatedMetas::parse_terminated)
+                .ok()?
+                .first()?
+            {
+                syn::Meta::Path(path) if path.is_ident("asynchronous") => Some(path.clone()),
+                _ => None,
+            }
+        })
+}
@@ -4,31 +4,57 @@ use quote::quote;
 
 pub fn extract_generic_struct_custom_validator_from_meta_name_value(
     meta_name_value: &syn::MetaNameValue,
-    _message_format: MessageFormat,
+    message_format: MessageFormat,
+    context: Option<&syn::Type>,
 ) -> Result<Validator, crate::Errors> {
     match &meta_name_value.value {
-        syn::Expr::Path(syn::ExprPath { path, .. }) => extract_struct_custom_from_meta_path(path),
-        syn::Expr::Call(call) => extract_struct_custom_from_call(call),
-        syn::Expr::Closure(closure) => extract_struct_custom_from_closure(closure),
+        syn::Expr::Path(syn::ExprPath { path, .. }) => {
+            extract_struct_custom_from_meta_path(path, context, &message_format)
+        }
+        syn::Expr::Call(call) => extract_struct_custom_from_call(call, context, &message_format),
+        syn::Expr::Closure(closure) => {
+            extract_struct_custom_from_closure(closure, context, &message_format)
+        }
         _ => Err(vec![
             crate::Error::validate_custom_meta_name_value_need_function_or_closure(meta_name_value),
         ]),
     }
 }
 
-fn extract_struct_custom_from_meta_path(meta_path: &syn::Path) -> Result<Validator, crate::Errors> {
+fn extract_struct_custom_from_meta_path(
+    meta_path: &syn::Path,
+    context: Option<&syn::Type>,
+    message_format: &MessageFormat,
+) -> Result<Validator, crate::Errors> {
     let rule_fn_name = &meta_path;
+    let rule_fn_args = match context {
+        Some(_) => quote!(self, __context),
+        None => quote!(self),
+    };
 
     Ok(quote!(
-        if let Err(__errors) = serde_valid::validation::custom::wrap_into_vec_errors(#rule_fn_name(self)) {
+        if let Err(__errors) = serde_valid::validation::custom::wrap_into_vec_errors(#rule_fn_name(#rule_fn_args)) {
+            let __errors = ::serde_valid::validation::custom::custom_errors_by(__errors, #message_format);
+            __limit.add(__errors.len());
             __rule_vec_errors.extend(__errors);
         };
     ))
 }
 
-fn extract_struct_custom_from_call(call: &syn::ExprCall) -> Result<Validator, crate::Errors> {
+fn extract_struct_custom_from_call(
+    call: &syn::ExprCall,
+    context: Option<&syn::Type>,
+    message_format: &MessageFormat,
+) -> Result<Validator, crate::Errors> {
+    let wrap_call_validation = match context {
+        Some(_) => quote!(wrap_call_validation_with_context(self, __context, #call)),
+        None => quote!(wrap_call_validation(self, #call)),
+    };
+
     Ok(quote!(
-        if let Err(__errors) = serde_valid::validation::custom::wrap_call_validation(self, #call) {
+        if let Err(__errors) = serde_valid::validation::custom::#wrap_call_validation {
+            let __errors = ::serde_valid::validation::custom::custom_errors_by(__errors, #message_format);
+            __limit.add(__errors.len());
             __rule_vec_errors.extend(__errors);
         };
     ))
@@ -36,9 +62,18 @@ fn extract_struct_custom_from_call(call: &syn::ExprCall) -> Result<Validator, cr
 
 fn extract_struct_custom_from_closure(
     closure: &syn::ExprClosure,
+    context: Option<&syn::Type>,
+    message_format: &MessageFormat,
 ) -> Result<Validator, crate::Errors> {
+    let wrap_closure_validation = match context {
+        Some(_) => quote!(wrap_closure_validation_with_context(self, __context, #closure)),
+        None => quote!(wrap_closure_validation(self, #closure)),
+    };
+
     Ok(quote!(
-        if let Err(__errors) = serde_valid::validation::custom::wrap_closure_validation(self, #closure) {
+        if let Err(__errors) = serde_valid::validation::custom::#wrap_closure_validation {
+            let __errors = ::serde_valid::validation::custom::custom_errors_by(__errors, #message_format);
+            __limit.add(__errors.len());
             __rule_vec_errors.extend(__errors);
         };
     ))
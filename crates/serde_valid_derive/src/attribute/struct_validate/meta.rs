@@ -22,10 +22,11 @@ use self::{
 
 pub fn extract_struct_validator(
     attribute: &syn::Attribute,
+    context: Option<&syn::Type>,
 ) -> Result<WithWarnings<Validator>, crate::Errors> {
     match &attribute.meta {
         syn::Meta::Path(_) => Ok(WithWarnings::new(quote!())),
-        syn::Meta::List(list) => inner_extract_struct_validator(attribute, list),
+        syn::Meta::List(list) => inner_extract_struct_validator(attribute, list, context),
         syn::Meta::NameValue(name_value) => {
             Err(vec![crate::Error::validate_meta_name_value_not_supported(
                 name_value,
@@ -37,6 +38,7 @@ pub fn extract_struct_validator(
 fn inner_extract_struct_validator(
     attribute: &syn::Attribute,
     meta_list: &syn::MetaList,
+    context: Option<&syn::Type>,
 ) -> Result<WithWarnings<Validator>, crate::Errors> {
     let mut errors = vec![];
     let nested = meta_list
@@ -56,16 +58,7 @@ fn inner_extract_struct_validator(
         )])?,
         1 => None,
         2 => match extract_custom_message_format(&nested[1]) {
-            Ok(custom_message) => {
-                if nested[0].path().is_ident("custom") {
-                    errors.push(
-                        crate::Error::validate_custom_does_not_support_custom_message(&nested[1]),
-                    );
-                    None
-                } else {
-                    Some(custom_message)
-                }
-            }
+            Ok(custom_message) => Some(custom_message),
             Err(message_fn_errors) => {
                 errors.extend(message_fn_errors);
                 None
@@ -107,6 +100,7 @@ fn inner_extract_struct_validator(
                 validation_type,
                 validation,
                 message_format,
+                context,
             )
         }
 
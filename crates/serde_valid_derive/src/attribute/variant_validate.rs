@@ -1,11 +1,15 @@
 mod meta;
 
-use crate::{attribute::Validator, warning::WithWarnings};
+use crate::{
+    attribute::{common::limit::limited_validator, Validator},
+    warning::WithWarnings,
+};
 
 use self::meta::extract_variant_validator;
 
 pub fn collect_variant_custom_from_variant(
     attributes: &[syn::Attribute],
+    context: Option<&syn::Type>,
 ) -> Result<WithWarnings<Validator>, crate::Errors> {
     let mut errors = vec![];
     let mut warnings = vec![];
@@ -14,10 +18,10 @@ pub fn collect_variant_custom_from_variant(
         .iter()
         .filter_map(|attribute| {
             if attribute.path().is_ident("validate") {
-                match extract_variant_validator(attribute) {
+                match extract_variant_validator(attribute, context) {
                     Ok(validator) => {
                         warnings.extend(validator.warnings);
-                        Some(validator.data)
+                        Some(limited_validator(validator.data))
                     }
                     Err(validator_error) => {
                         errors.extend(validator_error);
@@ -6,17 +6,25 @@ use crate::{
     },
     warning::WithWarnings,
 };
+use quote::quote;
 
 #[inline]
 pub fn extract_variant_validator_from_meta_name_value(
     validation_type: MetaNameValueStructValidation,
     validation: &syn::MetaNameValue,
     message_format: MessageFormat,
+    context: Option<&syn::Type>,
 ) -> Result<WithWarnings<Validator>, crate::Errors> {
     match validation_type {
         MetaNameValueStructValidation::Custom => {
-            extract_generic_struct_custom_validator_from_meta_name_value(validation, message_format)
+            extract_generic_struct_custom_validator_from_meta_name_value(
+                validation,
+                message_format,
+                context,
+            )
         }
+        // The type of the context is read by `extract_context_type`.
+        MetaNameValueStructValidation::Context => Ok(quote!()),
     }
     .map(WithWarnings::new)
 }
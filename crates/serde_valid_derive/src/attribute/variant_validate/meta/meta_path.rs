@@ -2,6 +2,7 @@ use crate::{
     attribute::{common::message_format::MessageFormat, MetaPathStructValidation, Validator},
     warning::WithWarnings,
 };
+use quote::quote;
 
 #[inline]
 pub fn extract_variant_validator_from_meta_path(
@@ -9,5 +10,8 @@ pub fn extract_variant_validator_from_meta_path(
     _validation: &syn::Path,
     _message_format: MessageFormat,
 ) -> Result<WithWarnings<Validator>, crate::Errors> {
-    match validation_type {}
+    match validation_type {
+        // `AsyncValidate` is derived by `extract_asynchronous`.
+        MetaPathStructValidation::Asynchronous => Ok(WithWarnings::new(quote!())),
+    }
 }
@@ -2,21 +2,269 @@ mod enum_derive;
 mod named_struct_derive;
 mod unnamed_struct_derive;
 
+use crate::attribute::struct_validate::{extract_asynchronous, extract_context_type};
+use crate::rules::{expand_validate_rules, validate_rules_method};
 use enum_derive::expand_enum_validate_derive;
 use named_struct_derive::expand_named_struct_derive;
 use proc_macro2::TokenStream;
+use quote::quote;
 use unnamed_struct_derive::expand_unnamed_struct_derive;
 
 pub fn expand_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    let context = extract_context_type(&input.attrs)?;
+    let context = context.as_ref();
+    let asynchronous = extract_asynchronous(&input.attrs);
+    let validate = expand_validate(input, context, false)?;
+    let validate_async = validate_async_tokens(input, context, asynchronous.as_ref())?;
+    let validate_rules = expand_validate_rules(input, context);
+    let validate_schema = validate_schema_tokens(input);
+
+    Ok(quote!(
+        #validate
+        #validate_async
+        #validate_rules
+        #validate_schema
+    ))
+}
+
+/// The implementation of the validation, which is `AsyncValidate` if `asynchronous`.
+fn expand_validate(
+    input: &syn::DeriveInput,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+) -> Result<TokenStream, crate::Errors> {
     match &input.data {
         syn::Data::Struct(syn::DataStruct { ref fields, .. }) => match fields {
-            syn::Fields::Named(fields) => expand_named_struct_derive(input, fields),
-            syn::Fields::Unnamed(fields) => expand_unnamed_struct_derive(input, fields),
+            syn::Fields::Named(fields) => {
+                expand_named_struct_derive(input, fields, context, asynchronous)
+            }
+            syn::Fields::Unnamed(fields) => {
+                expand_unnamed_struct_derive(input, fields, context, asynchronous)
+            }
             syn::Fields::Unit => Err(vec![crate::Error::unit_struct_not_supported(input)]),
         },
         syn::Data::Enum(syn::DataEnum { variants, .. }) => {
-            expand_enum_validate_derive(input, variants)
+            expand_enum_validate_derive(input, variants, context, asynchronous)
         }
         syn::Data::Union(_) => Err(vec![crate::Error::union_not_supported(input)]),
     }
 }
+
+/// `ValidateWithContext<C>` implementation for the type with `#[validate(context = C)]`,
+/// otherwise `Validate` implementation and `ValidateWithContext` implementation for every context.
+///
+/// `validations` is the body of the validation, which can refer to `__context` if any,
+/// to `__groups`, the selected groups, and to `__limit`, the limit of the errors.
+///
+/// If `asynchronous`, `AsyncValidate` implementation instead,
+/// whose `validations` can also refer to `__concurrency`.
+fn validate_impl_tokens(
+    input: &syn::DeriveInput,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
+    validations: TokenStream,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    if asynchronous {
+        // The futures of `AsyncValidate` are `Send`, so they can borrow only `Sync` fields.
+        let mut sync_generics = input.generics.clone();
+        let type_params = input
+            .generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect::<Vec<_>>();
+        sync_generics
+            .make_where_clause()
+            .predicates
+            .extend(type_params.iter().map(|param| -> syn::WherePredicate {
+                syn::parse_quote!(#param: ::std::marker::Sync)
+            }));
+        let (_, _, where_clause) = sync_generics.split_for_impl();
+
+        return quote!(
+            impl #impl_generics ::serde_valid::AsyncValidate for #ident #type_generics #where_clause {
+                async fn __validate_async_limited(
+                    &self,
+                    __concurrency: usize,
+                    __groups: &::serde_valid::Groups<'_>,
+                    __limit: &::serde_valid::ErrorLimit,
+                ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    #validations
+                }
+            }
+        );
+    }
+
+    let validate_rules_method = validate_rules_method();
+    match context {
+        Some(context) => quote!(
+            impl #impl_generics ::serde_valid::ValidateWithContext<#context> for #ident #type_generics #where_clause {
+                fn validate_with(
+                    &self,
+                    __context: &#context,
+                ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    ::serde_valid::ValidateWithContext::__validate_limited_with(
+                        self,
+                        __context,
+                        &::serde_valid::Groups::none(),
+                        &::serde_valid::ErrorLimit::unlimited(),
+                    )
+                }
+
+                fn __validate_limited_with(
+                    &self,
+                    __context: &#context,
+                    __groups: &::serde_valid::Groups<'_>,
+                    __limit: &::serde_valid::ErrorLimit,
+                ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                    use ::serde_valid::ValidateWithContext as _;
+
+                    #validations
+                }
+
+                #validate_rules_method
+            }
+        ),
+        None => {
+            let mut context_generics = input.generics.clone();
+            context_generics
+                .params
+                .push(syn::parse_quote!(__Context: ?Sized));
+            let (context_impl_generics, _, _) = context_generics.split_for_impl();
+
+            quote!(
+                impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
+                    fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                        ::serde_valid::Validate::__validate_limited(
+                            self,
+                            &::serde_valid::Groups::none(),
+                            &::serde_valid::ErrorLimit::unlimited(),
+                        )
+                    }
+
+                    fn __validate_limited(
+                        &self,
+                        __groups: &::serde_valid::Groups<'_>,
+                        __limit: &::serde_valid::ErrorLimit,
+                    ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                        #validations
+                    }
+
+                    #validate_rules_method
+                }
+
+                impl #context_impl_generics ::serde_valid::ValidateWithContext<__Context> for #ident #type_generics #where_clause {
+                    fn validate_with(
+                        &self,
+                        _context: &__Context,
+                    ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                        ::serde_valid::Validate::validate(self)
+                    }
+
+                    fn __validate_limited_with(
+                        &self,
+                        _context: &__Context,
+                        groups: &::serde_valid::Groups<'_>,
+                        limit: &::serde_valid::ErrorLimit,
+                    ) -> std::result::Result<(), ::serde_valid::validation::Errors> {
+                        ::serde_valid::Validate::__validate_limited(self, groups, limit)
+                    }
+
+                    fn __validate_rules() -> Option<::serde_valid::rules::TypeRules> {
+                        <Self as ::serde_valid::Validate>::__validate_rules()
+                    }
+                }
+            )
+        }
+    }
+}
+
+/// The collector of the asynchronous validations of the fields, `__async_validations`.
+fn async_validations_tokens(asynchronous: bool) -> TokenStream {
+    if asynchronous {
+        quote!(
+            let mut __async_validations = ::serde_valid::async_validate::AsyncValidations::new(__limit);
+        )
+    } else {
+        quote!()
+    }
+}
+
+/// Await the asynchronous validations of the fields and add their errors to `errors_variable`,
+/// where the fields with the asynchronous validations have reserved their entries in order.
+fn await_async_validations_tokens(asynchronous: bool, errors_variable: TokenStream) -> TokenStream {
+    if asynchronous {
+        quote!(
+            for (__key, __errors) in __async_validations.run(__concurrency).await {
+                #errors_variable.entry(__key).or_default().extend(__errors);
+            }
+            #errors_variable.retain(|_, __errors| !__errors.is_empty());
+        )
+    } else {
+        quote!()
+    }
+}
+
+/// `AsyncValidate` implementation for the type with `#[validate(asynchronous)]`.
+fn validate_async_tokens(
+    input: &syn::DeriveInput,
+    context: Option<&syn::Type>,
+    asynchronous: Option<&syn::Path>,
+) -> Result<TokenStream, crate::Errors> {
+    match (asynchronous, context) {
+        (Some(asynchronous), _) if !cfg!(feature = "async") => Err(vec![
+            crate::Error::validate_asynchronous_need_async_feature(asynchronous),
+        ]),
+        (Some(asynchronous), Some(_)) => {
+            Err(vec![crate::Error::validate_asynchronous_with_context(
+                asynchronous,
+            )])
+        }
+        (Some(_), None) => expand_validate(input, None, true),
+        (None, _) => match custom_async_metas(input).first() {
+            Some(meta) => Err(vec![crate::Error::validate_custom_async_need_asynchronous(
+                meta,
+            )]),
+            None => Ok(quote!()),
+        },
+    }
+}
+
+/// The `custom_async = ...` of the `#[validate(...)]` attributes of the fields.
+fn custom_async_metas(input: &syn::DeriveInput) -> Vec<syn::Meta> {
+    let fields: Vec<&syn::Field> = match &input.data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => fields.iter().collect(),
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        syn::Data::Union(_) => vec![],
+    };
+
+    fields
+        .into_iter()
+        .flat_map(|field| field.attrs.iter())
+        .filter(|attribute| attribute.path().is_ident("validate"))
+        .filter_map(|attribute| match &attribute.meta {
+            syn::Meta::List(list) => list
+                .parse_args_with(crate::types::CommaSeparatedMetas::parse_terminated)
+                .ok()?
+                .into_iter()
+                .next()
+                .filter(|meta| meta.path().is_ident("custom_async")),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+fn validate_schema_tokens(input: &syn::DeriveInput) -> TokenStream {
+    crate::json_schema::expand_validate_schema(input)
+}
+
+#[cfg(not(any(feature = "schemars", feature = "utoipa")))]
+fn validate_schema_tokens(_input: &syn::DeriveInput) -> TokenStream {
+    quote!()
+}
@@ -1,8 +1,10 @@
 use super::named_struct_derive::collect_named_fields_validators_list;
 use super::unnamed_struct_derive::collect_unnamed_fields_validators_list;
+use super::{async_validations_tokens, await_async_validations_tokens, validate_impl_tokens};
 use crate::attribute::variant_validate::collect_variant_custom_from_variant;
 use crate::attribute::Validator;
 use crate::error::{array_errors_tokens, new_type_errors_tokens, object_errors_tokens};
+use crate::serde::attributes::SerdeContainer;
 use crate::serde::rename::collect_serde_rename_map;
 use crate::types::CommaSeparatedTokenStreams;
 use crate::warning::WithWarnings;
@@ -14,9 +16,10 @@ pub type Variants = syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>
 pub fn expand_enum_validate_derive(
     input: &syn::DeriveInput,
     variants: &Variants,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<TokenStream, crate::Errors> {
     let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
 
     let mut errors = vec![];
 
@@ -29,6 +32,8 @@ pub fn expand_enum_validate_derive(
                     input,
                     variant,
                     named_fields,
+                    context,
+                    asynchronous,
                 ) {
                     Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
                     Err(variant_errors) => {
@@ -43,6 +48,8 @@ pub fn expand_enum_validate_derive(
                     input,
                     variant,
                     unnamed_fields,
+                    context,
+                    asynchronous,
                 ) {
                     Ok(variant_varidates_and_rules) => variant_varidates_and_rules,
                     Err(variant_errors) => {
@@ -64,20 +71,22 @@ pub fn expand_enum_validate_derive(
     let warnings = validations
         .into_iter()
         .flat_map(|variant| variant.warnings)
+        .filter(|_| !asynchronous)
         .enumerate()
         .map(|(index, warning)| warning.add_index(index))
         .collect::<Vec<_>>();
 
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    #( #warnings )*
-                    #validations_and_rules
-
-                    Ok(())
-                }
-            }
+        Ok(validate_impl_tokens(
+            input,
+            context,
+            asynchronous,
+            quote!(
+                #( #warnings )*
+                #validations_and_rules
+
+                Ok(())
+            ),
         ))
     } else {
         Err(errors)
@@ -89,15 +98,18 @@ fn expand_enum_variant_named_fields_validation(
     input: &syn::DeriveInput,
     variant: &syn::Variant,
     named_fields: &syn::FieldsNamed,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<WithWarnings<Validator>, crate::Errors> {
     let mut warnings = vec![];
     let mut errors = vec![];
 
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
-    let rename_map = collect_serde_rename_map(named_fields);
+    let rename_all = SerdeContainer::from_attrs(&variant.attrs).rename_all;
+    let rename_map = collect_serde_rename_map(named_fields, rename_all);
 
-    let enum_validates = match collect_variant_custom_from_variant(&input.attrs) {
+    let enum_validates = match collect_variant_custom_from_variant(&input.attrs, context) {
         Ok(validations) => {
             warnings.extend(validations.warnings);
             TokenStream::from_iter(validations.data)
@@ -108,7 +120,12 @@ fn expand_enum_variant_named_fields_validation(
         }
     };
 
-    let validates = match collect_named_fields_validators_list(named_fields, &rename_map) {
+    let validates = match collect_named_fields_validators_list(
+        named_fields,
+        &rename_map,
+        context,
+        asynchronous,
+    ) {
         Ok(field_validators_list) => {
             TokenStream::from_iter(field_validators_list.iter().map(|validators| {
                 let field_ident = validators.ident();
@@ -117,7 +134,7 @@ fn expand_enum_variant_named_fields_validation(
                     fields_idents.push(quote!(#field_ident));
                     quote!(#token)
                 } else {
-                    fields_idents.push(quote!(#field_ident));
+                    fields_idents.push(quote!(#field_ident: _));
                     quote!()
                 }
             }))
@@ -129,6 +146,9 @@ fn expand_enum_variant_named_fields_validation(
     };
 
     let variant_errors = object_errors_tokens();
+    let async_validations = async_validations_tokens(asynchronous);
+    let await_async_validations =
+        await_async_validations_tokens(asynchronous, quote!(__property_vec_errors_map));
 
     if errors.is_empty() {
         Ok(WithWarnings {
@@ -136,9 +156,11 @@ fn expand_enum_variant_named_fields_validation(
                 if let #ident::#variant_ident{#fields_idents} = &self {
                     let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
                     let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+                    #async_validations
 
                     #validates
                     #enum_validates
+                    #await_async_validations
 
                     if !(__rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty()) {
                         Err(#variant_errors)?
@@ -157,6 +179,8 @@ fn expand_enum_variant_unnamed_fields_varidation(
     input: &syn::DeriveInput,
     variant: &syn::Variant,
     unnamed_fields: &syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<WithWarnings<Validator>, crate::Errors> {
     let mut warnings = vec![];
     let mut errors = vec![];
@@ -164,7 +188,7 @@ fn expand_enum_variant_unnamed_fields_varidation(
     let variant_ident = &variant.ident;
     let mut fields_idents = CommaSeparatedTokenStreams::new();
 
-    let enum_validates = match collect_variant_custom_from_variant(&input.attrs) {
+    let enum_validates = match collect_variant_custom_from_variant(&input.attrs, context) {
         Ok(validations) => {
             warnings.extend(validations.warnings);
             TokenStream::from_iter(validations.data)
@@ -175,31 +199,35 @@ fn expand_enum_variant_unnamed_fields_varidation(
         }
     };
 
-    let validates = match collect_unnamed_fields_validators_list(unnamed_fields) {
-        Ok(field_validators_list) => {
-            TokenStream::from_iter(field_validators_list.iter().map(|validators| {
-                let field_ident = validators.ident();
-
-                if let Some(token) = validators.get_tokens() {
-                    fields_idents.push(quote!(#field_ident));
-                    quote!(#token)
-                } else {
-                    fields_idents.push(quote!(#field_ident));
-                    quote!()
-                }
-            }))
-        }
-        Err(fields_errors) => {
-            errors.extend(fields_errors);
-            quote!()
-        }
-    };
+    let validates =
+        match collect_unnamed_fields_validators_list(unnamed_fields, context, asynchronous) {
+            Ok(field_validators_list) => {
+                TokenStream::from_iter(field_validators_list.iter().map(|validators| {
+                    let field_ident = validators.ident();
+
+                    if let Some(token) = validators.get_tokens() {
+                        fields_idents.push(quote!(#field_ident));
+                        quote!(#token)
+                    } else {
+                        fields_idents.push(quote!(_));
+                        quote!()
+                    }
+                }))
+            }
+            Err(fields_errors) => {
+                errors.extend(fields_errors);
+                quote!()
+            }
+        };
 
     let variant_errors = if unnamed_fields.unnamed.len() != 1 {
         array_errors_tokens()
     } else {
         new_type_errors_tokens()
     };
+    let async_validations = async_validations_tokens(asynchronous);
+    let await_async_validations =
+        await_async_validations_tokens(asynchronous, quote!(__item_vec_errors_map));
 
     if errors.is_empty() {
         Ok(WithWarnings {
@@ -207,9 +235,11 @@ fn expand_enum_variant_unnamed_fields_varidation(
                 if let #ident::#variant_ident(#fields_idents) = &self {
                     let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
                     let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+                    #async_validations
 
                     #enum_validates
                     #validates
+                    #await_async_validations
 
                     if !(__rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty()) {
                         Err(#variant_errors)?
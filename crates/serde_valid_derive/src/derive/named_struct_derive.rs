@@ -1,6 +1,8 @@
+use super::{async_validations_tokens, await_async_validations_tokens, validate_impl_tokens};
 use crate::attribute::field_validate::{extract_field_validator, FieldValidators};
 use crate::attribute::struct_validate::collect_struct_custom_from_named_struct;
 use crate::error::object_errors_tokens;
+use crate::serde::attributes::SerdeContainer;
 use crate::serde::rename::{collect_serde_rename_map, RenameMap};
 use crate::types::{Field, NamedField};
 use proc_macro2::TokenStream;
@@ -11,18 +13,25 @@ use std::iter::FromIterator;
 pub fn expand_named_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsNamed,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-    let rename_map = collect_serde_rename_map(fields);
+    let rename_all = SerdeContainer::from_attrs(&input.attrs).rename_all;
+    let rename_map = collect_serde_rename_map(fields, rename_all);
 
     let mut warnings = vec![];
     let mut errors = vec![];
 
-    let struct_validations = match collect_struct_custom_from_named_struct(&input.attrs) {
+    let struct_validations = match collect_struct_custom_from_named_struct(&input.attrs, context) {
+        Ok(validations) if validations.data.is_empty() => quote!(),
         Ok(validations) => {
             warnings.extend(validations.warnings);
-            TokenStream::from_iter(validations.data)
+            let validations = TokenStream::from_iter(validations.data);
+            let field_errors = field_errors_tokens(fields, &rename_map);
+            quote!(
+                #validations
+                #field_errors
+            )
         }
         Err(rule_errors) => {
             errors.extend(rule_errors);
@@ -30,47 +39,57 @@ pub fn expand_named_struct_derive(
         }
     };
 
-    let field_validates = match collect_named_fields_validators_list(fields, &rename_map) {
-        Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
-            warnings.extend(validator.warnings.clone());
-            if validator.is_empty() {
+    let field_validates =
+        match collect_named_fields_validators_list(fields, &rename_map, context, asynchronous) {
+            Ok(field_validators) => {
+                TokenStream::from_iter(field_validators.iter().map(|validator| {
+                    warnings.extend(validator.warnings.clone());
+                    if validator.is_empty() {
+                        quote!()
+                    } else {
+                        validator.generate_tokens()
+                    }
+                }))
+            }
+            Err(validation_errors) => {
+                errors.extend(validation_errors);
                 quote!()
-            } else {
-                validator.generate_tokens()
             }
-        })),
-        Err(validation_errors) => {
-            errors.extend(validation_errors);
-            quote!()
-        }
-    };
+        };
 
     let fields_errors = object_errors_tokens();
+    let async_validations = async_validations_tokens(asynchronous);
+    let await_async_validations =
+        await_async_validations_tokens(asynchronous, quote!(__property_vec_errors_map));
 
     let warnings = warnings
         .into_iter()
+        .filter(|_| !asynchronous)
         .enumerate()
         .map(|(index, warning)| warning.add_index(index))
         .collect::<Vec<_>>();
 
     if errors.is_empty() {
-        Ok(quote!(
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    #(#warnings)*
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
-
-                    #field_validates
-                    #struct_validations
-
-                    if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
+        Ok(validate_impl_tokens(
+            input,
+            context,
+            asynchronous,
+            quote!(
+                #(#warnings)*
+                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                let mut __property_vec_errors_map = ::serde_valid::validation::PropertyVecErrorsMap::new();
+                #async_validations
+
+                #field_validates
+                #struct_validations
+                #await_async_validations
+
+                if __rule_vec_errors.is_empty() && __property_vec_errors_map.is_empty() {
+                    Ok(())
+                } else {
+                    Err(#fields_errors)
                 }
-            }
+            ),
         ))
     } else {
         Err(errors)
@@ -80,21 +99,23 @@ pub fn expand_named_struct_derive(
 pub fn collect_named_fields_validators_list<'a>(
     fields: &'a syn::FieldsNamed,
     rename_map: &RenameMap,
+    context: Option<&'a syn::Type>,
+    asynchronous: bool,
 ) -> Result<Vec<FieldValidators<'a, NamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
     let validators = fields
         .named
         .iter()
-        .filter_map(
-            |field| match collect_named_field_validators(field, rename_map) {
+        .filter_map(|field| {
+            match collect_named_field_validators(field, rename_map, context, asynchronous) {
                 Ok(validators) => Some(validators),
                 Err(ref mut error) => {
                     errors.append(error);
                     None
                 }
-            },
-        )
+            }
+        })
         .collect();
 
     if errors.is_empty() {
@@ -107,10 +128,14 @@ pub fn collect_named_fields_validators_list<'a>(
 fn collect_named_field_validators<'a>(
     field: &'a syn::Field,
     rename_map: &RenameMap,
+    context: Option<&'a syn::Type>,
+    asynchronous: bool,
 ) -> Result<FieldValidators<'a, NamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
-    let named_field = NamedField::new(field);
+    let named_field = NamedField::new(field)
+        .with_context(context)
+        .with_async(asynchronous);
     let validators = named_field
         .attrs()
         .iter()
@@ -138,3 +163,25 @@ fn collect_named_field_validators<'a>(
         validators,
     ))
 }
+
+/// The errors of `FieldErrors` in the struct validations as the errors of the fields,
+/// with the keys renamed by serde.
+fn field_errors_tokens(fields: &syn::FieldsNamed, rename_map: &RenameMap) -> TokenStream {
+    let renames = fields.named.iter().filter_map(|field| {
+        let field_name = NamedField::new(field).name().to_owned();
+        rename_map
+            .get(&field_name)
+            .map(|rename| quote!(#field_name => Some(#rename),))
+    });
+
+    quote!(
+        ::serde_valid::validation::custom::extend_field_errors(
+            &mut __rule_vec_errors,
+            &mut __property_vec_errors_map,
+            |__field| match __field {
+                #(#renames)*
+                _ => None,
+            },
+        );
+    )
+}
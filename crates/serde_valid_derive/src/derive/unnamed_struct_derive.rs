@@ -1,3 +1,4 @@
+use super::{async_validations_tokens, await_async_validations_tokens, validate_impl_tokens};
 use crate::attribute::field_validate::{extract_field_validator, FieldValidators};
 use crate::attribute::struct_validate::collect_struct_custom_from_named_struct;
 use crate::attribute::Validator;
@@ -12,14 +13,13 @@ use std::iter::FromIterator;
 pub fn expand_unnamed_struct_derive(
     input: &syn::DeriveInput,
     fields: &syn::FieldsUnnamed,
+    context: Option<&syn::Type>,
+    asynchronous: bool,
 ) -> Result<TokenStream, crate::Errors> {
-    let ident = &input.ident;
-    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-
     let mut warnings = vec![];
     let mut errors = vec![];
 
-    let struct_validations = match collect_struct_custom_from_named_struct(&input.attrs) {
+    let struct_validations = match collect_struct_custom_from_named_struct(&input.attrs, context) {
         Ok(validations) => {
             warnings.extend(validations.warnings);
             Validator::from_iter(validations.data)
@@ -30,73 +30,91 @@ pub fn expand_unnamed_struct_derive(
         }
     };
 
-    let field_validates: TokenStream = match collect_unnamed_fields_validators_list(fields) {
-        Ok(field_validators) => TokenStream::from_iter(field_validators.iter().map(|validator| {
-            warnings.extend(validator.warnings.clone());
-            if validator.is_empty() {
+    let field_validates: TokenStream =
+        match collect_unnamed_fields_validators_list(fields, context, asynchronous) {
+            Ok(field_validators) => {
+                TokenStream::from_iter(field_validators.iter().map(|validator| {
+                    warnings.extend(validator.warnings.clone());
+                    if validator.is_empty() {
+                        quote!()
+                    } else {
+                        validator.generate_tokens()
+                    }
+                }))
+            }
+            Err(validation_errors) => {
+                errors.extend(validation_errors);
                 quote!()
-            } else {
-                validator.generate_tokens()
             }
-        })),
-        Err(validation_errors) => {
-            errors.extend(validation_errors);
-            quote!()
-        }
-    };
+        };
 
     let fields_errors = if fields.unnamed.len() != 1 {
         array_errors_tokens()
     } else {
         new_type_errors_tokens()
     };
+    let async_validations = async_validations_tokens(asynchronous);
+    let await_async_validations =
+        await_async_validations_tokens(asynchronous, quote!(__item_vec_errors_map));
 
     let warnings = warnings
         .into_iter()
+        .filter(|_| !asynchronous)
         .enumerate()
         .map(|(index, warning)| warning.add_index(index))
         .collect::<Vec<_>>();
 
     if errors.is_empty() {
+        let validate_impl = validate_impl_tokens(
+            input,
+            context,
+            asynchronous,
+            quote!(
+                let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
+                let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
+                #async_validations
+
+                #field_validates
+                #struct_validations
+                #await_async_validations
+
+                if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
+                    Ok(())
+                } else {
+                    Err(#fields_errors)
+                }
+            ),
+        );
+
         Ok(quote!(
             #(#warnings)*
-            impl #impl_generics ::serde_valid::Validate for #ident #type_generics #where_clause {
-                fn validate(&self) -> std::result::Result<(), ::serde_valid::validation::Errors> {
-                    let mut __rule_vec_errors = ::serde_valid::validation::VecErrors::new();
-                    let mut __item_vec_errors_map = ::serde_valid::validation::ItemVecErrorsMap::new();
-
-                    #field_validates
-                    #struct_validations
-
-                    if __rule_vec_errors.is_empty() && __item_vec_errors_map.is_empty() {
-                        Ok(())
-                    } else {
-                        Err(#fields_errors)
-                    }
-                }
-            }
+            #validate_impl
         ))
     } else {
         Err(errors)
     }
 }
 
-pub fn collect_unnamed_fields_validators_list(
-    fields: &syn::FieldsUnnamed,
-) -> Result<Vec<FieldValidators<UnnamedField>>, crate::Errors> {
+pub fn collect_unnamed_fields_validators_list<'a>(
+    fields: &'a syn::FieldsUnnamed,
+    context: Option<&'a syn::Type>,
+    asynchronous: bool,
+) -> Result<Vec<FieldValidators<'a, UnnamedField<'a>>>, crate::Errors> {
     let mut errors = vec![];
 
     let validators = fields
         .unnamed
         .iter()
         .enumerate()
-        .filter_map(|field| match collect_unnamed_field_validators(field) {
-            Ok(validators) => Some(validators),
-            Err(ref mut error) => {
-                errors.append(error);
-                None
-            }
-        })
+        .filter_map(
+            |field| match collect_unnamed_field_validators(field, context, asynchronous) {
+                Ok(validators) => Some(validators),
+                Err(ref mut error) => {
+                    errors.append(error);
+                    None
+                }
+            },
+        )
         .collect();
 
     if !errors.is_empty() {
@@ -106,12 +124,16 @@ pub fn collect_unnamed_fields_validators_list(
     Ok(validators)
 }
 
-fn collect_unnamed_field_validators(
-    (index, field): (usize, &syn::Field),
-) -> Result<FieldValidators<UnnamedField>, crate::Errors> {
+fn collect_unnamed_field_validators<'a>(
+    (index, field): (usize, &'a syn::Field),
+    context: Option<&'a syn::Type>,
+    asynchronous: bool,
+) -> Result<FieldValidators<'a, UnnamedField<'a>>, crate::Errors> {
     let mut errors = vec![];
 
-    let unnamed_field = UnnamedField::new(index, field);
+    let unnamed_field = UnnamedField::new(index, field)
+        .with_context(context)
+        .with_async(asynchronous);
 
     let validators = unnamed_field
         .attrs()
@@ -25,14 +25,31 @@ pub fn object_errors_tokens() -> TokenStream {
                                 None
                             }
                             ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
+                                __field_properties_errors =
+                                    Some(match __field_properties_errors.take() {
+                                        Some(__field_object_errors) => {
+                                            ::serde_valid::validation::ObjectErrors::merge(
+                                                __field_object_errors,
+                                                __object_errors,
+                                            )
+                                        }
+                                        None => __object_errors,
+                                    });
                                 None
                             }
                             _ => Some(error),
                         })
                         .collect();
 
-                    if let Some(__object_errors) = __field_properties_errors {
+                    if let Some(mut __object_errors) = __field_properties_errors {
+                        // The indexes of the items are the keys of the properties,
+                        // e.g. of `FieldErrors::at_path` on a map.
+                        for __array_errors in __field_items_errors {
+                            __object_errors = ::serde_valid::validation::ObjectErrors::merge(
+                                __object_errors,
+                                __array_errors.into(),
+                            );
+                        }
                         __field_errors.extend(__object_errors.errors);
 
                         (
@@ -89,14 +106,31 @@ pub fn array_errors_tokens() -> TokenStream {
                                 None
                             }
                             ::serde_valid::validation::Error::Properties(__object_errors) => {
-                                __field_properties_errors = Some(__object_errors);
+                                __field_properties_errors =
+                                    Some(match __field_properties_errors.take() {
+                                        Some(__field_object_errors) => {
+                                            ::serde_valid::validation::ObjectErrors::merge(
+                                                __field_object_errors,
+                                                __object_errors,
+                                            )
+                                        }
+                                        None => __object_errors,
+                                    });
                                 None
                             }
                             _ => Some(error),
                         })
                         .collect();
 
-                    if let Some(__object_errors) = __field_properties_errors {
+                    if let Some(mut __object_errors) = __field_properties_errors {
+                        // The indexes of the items are the keys of the properties,
+                        // e.g. of `FieldErrors::at_path` on a map.
+                        for __array_errors in __field_items_errors {
+                            __object_errors = ::serde_valid::validation::ObjectErrors::merge(
+                                __object_errors,
+                                __array_errors.into(),
+                            );
+                        }
                         __field_errors.extend(__object_errors.errors);
 
                         (
@@ -323,6 +357,27 @@ impl Error {
         )
     }
 
+    pub fn validate_rule_need_rule(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(rule(???))] needs a rule, as `rule(Name)` or `rule(Name = param)`.",
+        )
+    }
+
+    pub fn validate_rules_need_rule_set(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(rules = ???)] needs the path of a #[derive(RuleSet)] type.",
+        )
+    }
+
+    pub fn rule_set_unit_struct_only(input: &syn::DeriveInput) -> Self {
+        Self::new(
+            input.span(),
+            "#[derive(RuleSet)] supports only Unit Struct.",
+        )
+    }
+
     pub fn validate_custom_tail_error(nested: &crate::types::NestedMeta) -> Self {
         Self::new(
             nested.span(),
@@ -337,6 +392,80 @@ impl Error {
         )
     }
 
+    pub fn validate_groups_need_array(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(..., groups = ???)] needs array of groups.",
+        )
+    }
+
+    pub fn validate_when_duplicated(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(..., when = ???)] can be given only once.",
+        )
+    }
+
+    pub fn validate_skip_if_need_function_or_closure(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(skip_if = ???)] needs function or closure.",
+        )
+    }
+
+    pub fn validate_custom_async_need_function(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(custom_async = ???)] needs function path, since a closure can not return a future borrowing its argument.",
+        )
+    }
+
+    pub fn validate_custom_async_need_async_feature(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(custom_async = ...)] needs the `async` feature of serde_valid.",
+        )
+    }
+
+    pub fn validate_custom_async_need_asynchronous(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(custom_async = ...)] needs #[validate(asynchronous)] on the struct or the enum.",
+        )
+    }
+
+    pub fn validate_asynchronous_need_async_feature(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(asynchronous)] needs the `async` feature of serde_valid.",
+        )
+    }
+
+    pub fn validate_asynchronous_with_context(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(asynchronous)] is not supported with #[validate(context = ...)].",
+        )
+    }
+
+    pub fn validate_custom_async_with_context(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(custom_async = ...)] is not supported with #[validate(context = ...)].",
+        )
+    }
+
+    pub fn validate_context_need_type(span: impl Spanned) -> Self {
+        Self::new(span.span(), "#[validate(context = ???)] needs type.")
+    }
+
+    pub fn validate_context_duplicated(span: impl Spanned) -> Self {
+        Self::new(
+            span.span(),
+            "#[validate(context = ???)] can be given only once.",
+        )
+    }
+
     pub fn custom_message_parse_error(ident: &syn::Ident, error: &syn::Error) -> Self {
         Self::new(
             ident.span(),
@@ -450,10 +579,10 @@ impl Error {
         self.0.to_compile_error()
     }
 
-    pub fn validate_custom_does_not_support_custom_message(meta: &syn::Meta) -> Self {
+    pub fn validate_custom_async_does_not_support_custom_message(meta: &syn::Meta) -> Self {
         Self::new(
             meta.span(),
-            "#[validate(custon(...), ???)] does not support custom error message.",
+            "#[validate(custom_async = ..., ???)] does not support custom error message.",
         )
     }
 }
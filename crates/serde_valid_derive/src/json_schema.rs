@@ -0,0 +1,240 @@
+mod keyword;
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+mod validate_schema;
+
+use crate::serde::attributes::{property_name, RenameRule, SerdeContainer, SerdeField};
+use keyword::collect_field_keywords;
+use proc_macro2::TokenStream;
+use quote::quote;
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+pub use validate_schema::expand_validate_schema;
+
+pub fn expand_json_schema_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    let ident = &input.ident;
+    let container = SerdeContainer::from_attrs(&input.attrs);
+
+    let schema = match &input.data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => fields_schema_tokens(
+            fields,
+            container.rename_all,
+            container.deny_unknown_fields,
+            container.default,
+        ),
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => enum_schema_tokens(variants, &container),
+        syn::Data::Union(_) => return Err(vec![crate::Error::union_not_supported(input)]),
+    };
+    let description = description_tokens(&input.attrs, quote!(__schema));
+
+    let mut generics = input.generics.clone();
+    let type_params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for type_param in &type_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#type_param: ::serde_valid::json_schema::JsonSchema));
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    let schema_name = if type_params.is_empty() {
+        quote!(std::borrow::Cow::Borrowed(stringify!(#ident)))
+    } else {
+        quote!(std::borrow::Cow::Owned(
+            [
+                stringify!(#ident).to_string(),
+                #(<#type_params as ::serde_valid::json_schema::JsonSchema>::schema_name().into_owned()),*
+            ]
+            .join("_")
+        ))
+    };
+
+    Ok(quote!(
+        impl #impl_generics ::serde_valid::json_schema::JsonSchema for #ident #type_generics #where_clause {
+            fn schema_name() -> std::borrow::Cow<'static, str> {
+                #schema_name
+            }
+
+            fn json_schema_object(
+                definitions: &mut ::serde_valid::json_schema::Definitions,
+            ) -> ::serde_valid::json::Value {
+                let mut __schema = #schema;
+                #description
+                __schema
+            }
+
+            fn json_subschema(
+                definitions: &mut ::serde_valid::json_schema::Definitions,
+            ) -> ::serde_valid::json::Value {
+                ::serde_valid::json_schema::reference::<Self>(definitions)
+            }
+        }
+    ))
+}
+
+fn enum_schema_tokens(
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+    container: &SerdeContainer,
+) -> TokenStream {
+    let tagging = match (&container.tag, &container.content, container.untagged) {
+        (_, _, true) => quote!(::serde_valid::json_schema::Tagging::Untagged),
+        (Some(tag), Some(content), _) => {
+            quote!(::serde_valid::json_schema::Tagging::Adjacent { tag: #tag, content: #content })
+        }
+        (Some(tag), None, _) => quote!(::serde_valid::json_schema::Tagging::Internal { tag: #tag }),
+        _ => quote!(::serde_valid::json_schema::Tagging::External),
+    };
+
+    let variant_schemas = variants.iter().filter_map(|variant| {
+        let variant_container = SerdeContainer::from_attrs(&variant.attrs);
+        if variant_container.skip {
+            return None;
+        }
+        let name = variant_container.rename.clone().unwrap_or_else(|| {
+            let name = variant.ident.to_string();
+            match container.rename_all {
+                Some(rule) => rule.apply_to_variant(&name),
+                None => name,
+            }
+        });
+        let schema = match &variant.fields {
+            syn::Fields::Unit => quote!(None),
+            fields => {
+                let schema = fields_schema_tokens(
+                    fields,
+                    variant_container.rename_all,
+                    variant_container.deny_unknown_fields,
+                    false,
+                );
+                quote!(Some(#schema))
+            }
+        };
+        let description = description_tokens(&variant.attrs, quote!(__variant_schema));
+
+        Some(quote!({
+            let mut __variant_schema =
+                ::serde_valid::json_schema::variant_schema(#tagging, #name, #schema);
+            #description
+            __variant_schema
+        }))
+    });
+
+    quote!(::serde_valid::json_schema::enum_schema(
+        #tagging,
+        vec![#(#variant_schemas),*]
+    ))
+}
+
+fn fields_schema_tokens(
+    fields: &syn::Fields,
+    rename_all: Option<RenameRule>,
+    deny_unknown_fields: bool,
+    default: bool,
+) -> TokenStream {
+    match fields {
+        syn::Fields::Named(fields) => {
+            let properties = fields.named.iter().filter_map(|field| {
+                let serde_field = SerdeField::from_attrs(&field.attrs);
+                if serde_field.skip {
+                    return None;
+                }
+                let ty = &field.ty;
+                let schema = field_schema_tokens(field);
+
+                if serde_field.flatten {
+                    return Some(quote!(__flattened.push(#schema);));
+                }
+
+                let name = property_name(field, &serde_field, rename_all);
+                let required = if serde_field.default || default {
+                    quote!()
+                } else {
+                    quote!(
+                        if !<#ty as ::serde_valid::json_schema::JsonSchema>::OPTIONAL {
+                            __required.push(#name.to_string());
+                        }
+                    )
+                };
+
+                Some(quote!(
+                    __properties.insert(#name.to_string(), #schema);
+                    #required
+                ))
+            });
+
+            quote!({
+                let mut __properties = ::serde_valid::json::Map::new();
+                let mut __required: Vec<String> = vec![];
+                let mut __flattened: Vec<::serde_valid::json::Value> = vec![];
+                #(#properties)*
+                ::serde_valid::json_schema::flatten_schema(
+                    ::serde_valid::json_schema::object_schema(
+                        __properties,
+                        __required,
+                        #deny_unknown_fields,
+                    ),
+                    __flattened,
+                )
+            })
+        }
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            field_schema_tokens(&fields.unnamed[0])
+        }
+        syn::Fields::Unnamed(fields) => {
+            let items = fields.unnamed.iter().map(field_schema_tokens);
+            quote!(::serde_valid::json_schema::tuple_schema(vec![#(#items),*]))
+        }
+        syn::Fields::Unit => quote!(::serde_valid::json::json!({ "type": "null" })),
+    }
+}
+
+/// The schema of the field type with the keywords of its validations.
+fn field_schema_tokens(field: &syn::Field) -> TokenStream {
+    let ty = &field.ty;
+    let keywords = collect_field_keywords(&field.attrs)
+        .into_iter()
+        .map(|(keyword, value)| {
+            quote!(
+                ::serde_valid::json_schema::apply_keyword(
+                    &mut __field_schema,
+                    #keyword,
+                    ::serde_valid::json::json!(#value),
+                );
+            )
+        });
+    let description = description_tokens(&field.attrs, quote!(__field_schema));
+
+    quote!({
+        let mut __field_schema =
+            <#ty as ::serde_valid::json_schema::JsonSchema>::json_subschema(definitions);
+        #(#keywords)*
+        #description
+        __field_schema
+    })
+}
+
+fn description_tokens(attrs: &[syn::Attribute], schema: TokenStream) -> TokenStream {
+    let lines = attrs
+        .iter()
+        .filter_map(|attribute| match &attribute.meta {
+            syn::Meta::NameValue(name_value) if name_value.path.is_ident("doc") => {
+                match &name_value.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit_str),
+                        ..
+                    }) => Some(lit_str.value().trim().to_string()),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        quote!()
+    } else {
+        let description = lines.join("\n").trim().to_string();
+        quote!(::serde_valid::json_schema::describe(&mut #schema, #description);)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::attribute::{MetaNameValueFieldValidation, MetaPathFieldValidation};
+use crate::types::CommaSeparatedMetas;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::str::FromStr;
+
+/// JSON Schema keywords of the `#[validate(...)]` attributes of a field.
+///
+/// Validations without a keyword (e.g. `custom`), with a `when = ...` condition
+/// or with `groups = [...]` are skipped, since they do not always run.
+/// The validation is the first item of the attribute, whose other items are its message,
+/// and errors in the attributes are left to `#[derive(Validate)]`.
+pub fn collect_field_keywords(attrs: &[syn::Attribute]) -> Vec<(String, TokenStream)> {
+    attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+        .filter_map(|attribute| match &attribute.meta {
+            syn::Meta::List(list) => {
+                let metas = list
+                    .parse_args_with(CommaSeparatedMetas::parse_terminated)
+                    .ok()?;
+                if metas
+                    .iter()
+                    .any(|meta| meta.path().is_ident("when") || meta.path().is_ident("groups"))
+                {
+                    return None;
+                }
+                metas.first().and_then(extract_keyword)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn extract_keyword(meta: &syn::Meta) -> Option<(String, TokenStream)> {
+    match meta {
+        syn::Meta::Path(path) => {
+            match MetaPathFieldValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+                MetaPathFieldValidation::UniqueItems => {
+                    Some(("uniqueItems".to_string(), quote!(true)))
+                }
+            }
+        }
+        syn::Meta::NameValue(name_value) => {
+            let validation_type =
+                MetaNameValueFieldValidation::from_str(&name_value.path.get_ident()?.to_string())
+                    .ok()?;
+            let value = &name_value.value;
+            let keyword = match validation_type {
+                MetaNameValueFieldValidation::Minimum => "minimum",
+                MetaNameValueFieldValidation::Maximum => "maximum",
+                MetaNameValueFieldValidation::ExclusiveMinimum => "exclusiveMinimum",
+                MetaNameValueFieldValidation::ExclusiveMaximum => "exclusiveMaximum",
+                MetaNameValueFieldValidation::MinLength => "minLength",
+                MetaNameValueFieldValidation::MaxLength => "maxLength",
+                MetaNameValueFieldValidation::MinItems => "minItems",
+                MetaNameValueFieldValidation::MaxItems => "maxItems",
+                MetaNameValueFieldValidation::MinProperties => "minProperties",
+                MetaNameValueFieldValidation::MaxProperties => "maxProperties",
+                MetaNameValueFieldValidation::MultipleOf => "multipleOf",
+                MetaNameValueFieldValidation::Pattern => "pattern",
+                MetaNameValueFieldValidation::Enumerate => "enum",
+                MetaNameValueFieldValidation::Custom
+                | MetaNameValueFieldValidation::CustomAsync
+                | MetaNameValueFieldValidation::SkipIf
+                | MetaNameValueFieldValidation::Rules => return None,
+            };
+            match value {
+                syn::Expr::Lit(_) | syn::Expr::Array(_) => {
+                    Some((keyword.to_string(), quote!(#value)))
+                }
+                _ => None,
+            }
+        }
+        syn::Meta::List(_) => None,
+    }
+}
@@ -0,0 +1,80 @@
+use super::collect_field_keywords;
+use crate::serde::attributes::{property_name, SerdeContainer, SerdeField};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// `ValidateSchema` implementation, which provides the keywords of the validations to `schemars` and `utoipa`.
+pub fn expand_validate_schema(input: &syn::DeriveInput) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let container = SerdeContainer::from_attrs(&input.attrs);
+
+    let keywords = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => fields
+            .named
+            .iter()
+            .filter_map(|field| {
+                let serde_field = SerdeField::from_attrs(&field.attrs);
+                if serde_field.skip || serde_field.flatten {
+                    return None;
+                }
+                let name = property_name(field, &serde_field, container.rename_all);
+                Some(keyword_tokens(
+                    field,
+                    quote!(::serde_valid::json_schema::KeywordLocation::Property(#name)),
+                ))
+            })
+            .collect(),
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(fields),
+            ..
+        }) if fields.unnamed.len() == 1 => vec![keyword_tokens(
+            &fields.unnamed[0],
+            quote!(::serde_valid::json_schema::KeywordLocation::Root),
+        )],
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(fields),
+            ..
+        }) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                keyword_tokens(
+                    field,
+                    quote!(::serde_valid::json_schema::KeywordLocation::PrefixItem(#index)),
+                )
+            })
+            .collect(),
+        _ => vec![],
+    };
+
+    quote!(
+        impl #impl_generics ::serde_valid::json_schema::ValidateSchema for #ident #type_generics #where_clause {
+            fn validation_keywords() -> Vec<::serde_valid::json_schema::ValidationKeyword> {
+                let mut __keywords = vec![];
+                #(#keywords)*
+                __keywords
+            }
+        }
+    )
+}
+
+fn keyword_tokens(field: &syn::Field, location: TokenStream) -> TokenStream {
+    let keywords = collect_field_keywords(&field.attrs)
+        .into_iter()
+        .map(|(keyword, value)| {
+            quote!(
+                __keywords.push(::serde_valid::json_schema::ValidationKeyword {
+                    location: #location,
+                    keyword: #keyword,
+                    value: ::serde_valid::json::json!(#value),
+                });
+            )
+        });
+
+    quote!(#(#keywords)*)
+}
@@ -2,6 +2,11 @@
 mod attribute;
 mod derive;
 mod error;
+mod json_schema;
+#[cfg(feature = "proptest")]
+mod proptest;
+mod rule_set;
+mod rules;
 mod serde;
 mod types;
 mod warning;
@@ -9,8 +14,10 @@ mod warning;
 use derive::expand_derive;
 use error::to_compile_errors;
 use error::{Error, Errors};
+use json_schema::expand_json_schema_derive;
 use proc_macro::TokenStream;
 use proc_macro_error2::proc_macro_error;
+use rule_set::{expand_rule_set, expand_rule_set_derive};
 use syn::{parse_macro_input, DeriveInput};
 
 #[proc_macro_derive(Validate, attributes(rule, validate, serde_valid))]
@@ -22,3 +29,45 @@ pub fn derive_validate(tokens: TokenStream) -> TokenStream {
         .unwrap_or_else(to_compile_errors)
         .into()
 }
+
+#[proc_macro_derive(RuleSet, attributes(validate))]
+#[proc_macro_error]
+pub fn derive_rule_set(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_rule_set_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+#[proc_macro_error]
+pub fn __expand_rule_set(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as rule_set::RuleSetInput);
+
+    expand_rule_set(input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
+#[proc_macro_derive(JsonSchema, attributes(validate))]
+#[proc_macro_error]
+pub fn derive_json_schema(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    expand_json_schema_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
+
+#[cfg(feature = "proptest")]
+#[proc_macro_derive(ValidStrategy, attributes(validate))]
+#[proc_macro_error]
+pub fn derive_valid_strategy(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    proptest::expand_valid_strategy_derive(&input)
+        .unwrap_or_else(to_compile_errors)
+        .into()
+}
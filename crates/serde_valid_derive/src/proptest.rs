@@ -0,0 +1,191 @@
+use crate::rules::{collect_field_rules, FieldRuleTokens};
+use crate::serde::attributes::SerdeField;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// `ValidStrategy` implementation, which generates the fields from the `#[validate(...)]` attributes.
+pub fn expand_valid_strategy_derive(
+    input: &syn::DeriveInput,
+) -> Result<TokenStream, crate::Errors> {
+    let ident = &input.ident;
+    let constructors = match &input.data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => vec![(quote!(Self), fields)],
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => variants
+            .iter()
+            .map(|variant| {
+                let variant_ident = &variant.ident;
+                (quote!(Self::#variant_ident), &variant.fields)
+            })
+            .collect(),
+        syn::Data::Union(_) => return Err(vec![crate::Error::union_not_supported(input)]),
+    };
+
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    let mut valid_strategies = vec![];
+    let mut invalid_strategies = vec![];
+    for (constructor, fields) in constructors {
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| FieldStrategy::new(index, field, fields.len()))
+            .collect::<Vec<_>>();
+        for field in &fields {
+            where_clause.predicates.push(field.bound());
+        }
+
+        let construct = construct_tokens(&constructor, &fields);
+        let pattern = fields.iter().rev().fold(quote!(()), |pattern, field| {
+            let binding = &field.binding;
+            quote!((#binding, #pattern))
+        });
+        let strategy = |invalid: Option<usize>| {
+            fields.iter().enumerate().rev().fold(
+                quote!(__proptest::strategy::Just(())),
+                |strategy, (index, field)| {
+                    let field_strategy = match invalid {
+                        Some(invalid) if invalid == index => quote!(__invalid),
+                        _ => field.valid_strategy(),
+                    };
+                    quote!((#field_strategy, #strategy))
+                },
+            )
+        };
+
+        let valid_strategy = strategy(None);
+        valid_strategies.push(quote!(
+            __proptest::strategy::Strategy::boxed(
+                __proptest::strategy::Strategy::prop_map(
+                    #valid_strategy,
+                    |#pattern| #construct
+                )
+            )
+        ));
+
+        for (index, field) in fields.iter().enumerate() {
+            let Some(rules) = &field.rules else {
+                continue;
+            };
+            let rules = rules.to_slice_tokens();
+            let ty = &field.ty;
+            let binding = &field.binding;
+            let invalid_strategy = strategy(Some(index));
+            let within = match &field.key {
+                Some(key) => quote!(.within(#key)),
+                None => quote!(),
+            };
+            invalid_strategies.push(quote!(
+                if let Some(__invalid) =
+                    <#ty as ::serde_valid::proptest::FieldStrategy>::invalid_field_strategy(#rules)
+                {
+                    __strategies.push(__proptest::strategy::Strategy::boxed(
+                        __proptest::strategy::Strategy::prop_map(
+                            #invalid_strategy,
+                            |#pattern| #binding #within.map(|#binding| #construct)
+                        )
+                    ));
+                }
+            ));
+        }
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote!(
+        impl #impl_generics ::serde_valid::proptest::ValidStrategy for #ident #type_generics #where_clause {
+            fn valid_strategy() -> ::serde_valid::export::proptest::strategy::BoxedStrategy<Self> {
+                use ::serde_valid::export::proptest as __proptest;
+
+                ::serde_valid::proptest::__valid(
+                    ::serde_valid::proptest::__one_of(vec![#(#valid_strategies),*])
+                )
+            }
+
+            fn invalid_strategy() -> Option<::serde_valid::export::proptest::strategy::BoxedStrategy<::serde_valid::proptest::Invalid<Self>>> {
+                use ::serde_valid::export::proptest as __proptest;
+
+                let mut __strategies = vec![];
+                #(#invalid_strategies)*
+                ::serde_valid::proptest::__invalid(__strategies)
+            }
+        }
+
+        impl #impl_generics ::serde_valid::proptest::FieldStrategy for #ident #type_generics #where_clause {
+            fn valid_field_strategy(
+                _rules: &[::serde_valid::rules::Rule],
+            ) -> ::serde_valid::export::proptest::strategy::BoxedStrategy<Self> {
+                <Self as ::serde_valid::proptest::ValidStrategy>::valid_strategy()
+            }
+
+            fn invalid_field_strategy(
+                _rules: &[::serde_valid::rules::Rule],
+            ) -> Option<::serde_valid::export::proptest::strategy::BoxedStrategy<::serde_valid::proptest::Invalid<Self>>> {
+                <Self as ::serde_valid::proptest::ValidStrategy>::invalid_strategy()
+            }
+        }
+    ))
+}
+
+struct FieldStrategy<'a> {
+    ty: &'a syn::Type,
+    ident: Option<&'a syn::Ident>,
+    binding: syn::Ident,
+
+    /// The key of the field in the errors, which is absent for the field of a new type.
+    key: Option<String>,
+
+    /// The rules of a field with `#[validate]` attributes.
+    rules: Option<FieldRuleTokens>,
+}
+
+impl<'a> FieldStrategy<'a> {
+    fn new(index: usize, field: &'a syn::Field, len: usize) -> Self {
+        let key = match &field.ident {
+            Some(ident) => Some(
+                SerdeField::from_attrs(&field.attrs)
+                    .rename
+                    .unwrap_or_else(|| ident.to_string()),
+            ),
+            None => (len > 1).then(|| index.to_string()),
+        };
+        Self {
+            ty: &field.ty,
+            ident: field.ident.as_ref(),
+            binding: format_ident!("__field{}", index),
+            key,
+            rules: collect_field_rules(field).map(|(rules, _)| rules),
+        }
+    }
+
+    fn valid_strategy(&self) -> TokenStream {
+        let ty = self.ty;
+        match &self.rules {
+            Some(rules) => {
+                let rules = rules.to_slice_tokens();
+                quote!(
+                    <#ty as ::serde_valid::proptest::FieldStrategy>::valid_field_strategy(#rules)
+                )
+            }
+            None => quote!(__proptest::arbitrary::any::<#ty>()),
+        }
+    }
+
+    fn bound(&self) -> syn::WherePredicate {
+        let ty = self.ty;
+        match &self.rules {
+            Some(_) => syn::parse_quote!(#ty: ::serde_valid::proptest::FieldStrategy),
+            None => syn::parse_quote!(#ty: ::serde_valid::export::proptest::arbitrary::Arbitrary),
+        }
+    }
+}
+
+fn construct_tokens(constructor: &TokenStream, fields: &[FieldStrategy]) -> TokenStream {
+    let bindings = fields.iter().map(|field| &field.binding);
+    match fields.first() {
+        None => quote!(#constructor),
+        Some(FieldStrategy { ident: Some(_), .. }) => {
+            let idents = fields.iter().map(|field| field.ident);
+            quote!(#constructor { #(#idents: #bindings),* })
+        }
+        Some(FieldStrategy { ident: None, .. }) => quote!(#constructor(#(#bindings),*)),
+    }
+}
@@ -0,0 +1,269 @@
+use crate::attribute::field_validate::{extract_field_validator, FieldValidators};
+use crate::attribute::{MetaListCustomMessage, MetaNameValueCustomMessage};
+use crate::rules::collect_rule_set_rules;
+use crate::serde::rename::RenameMap;
+use crate::types::{CommaSeparatedMetas, RuleSetField};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use std::borrow::Cow;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use syn::parse::{Parse, ParseStream};
+
+/// `RuleSet` implementation and the macro of the rule set, which has the same name as the type.
+///
+/// `#[validate(rules = Name)]` calls the macro, which expands the attributes
+/// with `__expand_rule_set!` as if they were written on the field.
+pub fn expand_rule_set_derive(input: &syn::DeriveInput) -> Result<TokenStream, crate::Errors> {
+    if !matches!(
+        input.data,
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unit,
+            ..
+        })
+    ) {
+        return Err(vec![crate::Error::rule_set_unit_struct_only(input)]);
+    }
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let attrs = input
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    // Check the attributes where the rule set is defined.
+    let field = RuleSetField::new(
+        format_ident!("__value"),
+        quote!(""),
+        format_ident!("__errors"),
+        attrs.clone(),
+    );
+    let mut errors = vec![];
+    let mut warnings = vec![];
+    for attribute in &attrs {
+        match extract_field_validator(&field, attribute, &RenameMap::new()) {
+            Ok(validator) => warnings.extend(validator.warnings),
+            Err(validator_errors) => errors.extend(validator_errors),
+        }
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    let warnings = warnings
+        .into_iter()
+        .enumerate()
+        .map(|(index, warning)| warning.add_index(index))
+        .collect::<Vec<_>>();
+
+    let rules = collect_rule_set_rules(&attrs).to_vec_tokens();
+    let attrs = dollar_crate(quote!(#(#attrs)*));
+
+    // Public rule sets are exported to be used in other crates,
+    // where the name of the macro must be unique in the crate,
+    // even for the same rule sets in different modules.
+    static RULE_SET_COUNT: AtomicUsize = AtomicUsize::new(0);
+    let macro_ident = format_ident!(
+        "__serde_valid_rule_set_{}_{}",
+        ident,
+        RULE_SET_COUNT.fetch_add(1, Ordering::Relaxed)
+    );
+    let macro_export = match vis {
+        syn::Visibility::Public(_) => quote!(#[macro_export]),
+        _ => quote!(),
+    };
+
+    Ok(quote!(
+        impl #impl_generics ::serde_valid::RuleSet for #ident #type_generics #where_clause {
+            fn rules() -> Vec<::serde_valid::rules::Rule> {
+                #rules
+            }
+        }
+
+        const _: () = {
+            // The rule set is used through its macro, rather than as a value.
+            let _ = #ident;
+            #(#warnings)*
+        };
+
+        #[doc(hidden)]
+        #macro_export
+        macro_rules! #macro_ident {
+            ($($site:tt)*) => {
+                ::serde_valid::__expand_rule_set! { [#attrs] $($site)* }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        #vis use #macro_ident as #ident;
+    ))
+}
+
+/// The validators of a rule set for a field, from the macro of the rule set.
+pub fn expand_rule_set(input: RuleSetInput) -> Result<TokenStream, crate::Errors> {
+    let RuleSetInput {
+        attrs,
+        ident,
+        errors,
+        key,
+        context,
+        custom_message,
+        asynchronous,
+    } = input;
+
+    let span = errors.span();
+    let attrs = match &custom_message {
+        Some(custom_message) => attrs
+            .iter()
+            .map(|attribute| with_custom_message(attribute, custom_message))
+            .collect(),
+        None => attrs,
+    };
+    let field = RuleSetField::new(ident, key, errors, attrs.clone())
+        .with_context(context)
+        .with_async(asynchronous);
+
+    let mut errors = vec![];
+    let validators = attrs
+        .iter()
+        .filter_map(|attribute| {
+            match extract_field_validator(&field, attribute, &RenameMap::new()) {
+                Ok(validator) => Some(validator),
+                Err(validator_errors) => {
+                    errors.extend(validator_errors);
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let validators =
+        FieldValidators::new(Cow::<RuleSetField>::Owned(field), validators).get_tokens();
+
+    // The validators refer to the variables of the derived validation.
+    Ok(respan(quote!({ #validators }), span))
+}
+
+/// The attribute with the custom message of `#[validate(rules = ..., message = ...)]`
+/// instead of its own one.
+fn with_custom_message(attribute: &syn::Attribute, custom_message: &syn::Meta) -> syn::Attribute {
+    let syn::Meta::List(list) = &attribute.meta else {
+        return attribute.clone();
+    };
+    let Ok(metas) = list.parse_args_with(CommaSeparatedMetas::parse_terminated) else {
+        return attribute.clone();
+    };
+    match metas.first() {
+        Some(meta) if !["custom_async", "skip_if"].contains(&meta_name(meta).as_str()) => {
+            let rest = metas.iter().skip(1).filter(|meta| {
+                let name = meta_name(meta);
+                MetaNameValueCustomMessage::from_str(&name).is_err()
+                    && MetaListCustomMessage::from_str(&name).is_err()
+            });
+            syn::parse_quote!(#[validate(#meta, #custom_message, #(#rest),*)])
+        }
+        _ => attribute.clone(),
+    }
+}
+
+fn meta_name(meta: &syn::Meta) -> String {
+    meta.path()
+        .get_ident()
+        .map(ToString::to_string)
+        .unwrap_or_default()
+}
+
+/// `crate::...` paths as `$crate::...`, to refer to the crate of the rule set from other crates.
+fn dollar_crate(tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    proc_macro2::Group::new(group.delimiter(), dollar_crate(group.stream()));
+                replaced.set_span(group.span());
+                vec![TokenTree::Group(replaced)]
+            }
+            TokenTree::Ident(ident) if ident == "crate" => vec![
+                TokenTree::Punct(proc_macro2::Punct::new('$', proc_macro2::Spacing::Alone)),
+                TokenTree::Ident(ident),
+            ],
+            token => vec![token],
+        })
+        .collect()
+}
+
+/// The tokens resolved at `span`, except `$crate`, which is resolved by the macro of the rule set.
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut respanned =
+                    proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(group.span().resolved_at(span));
+                TokenTree::Group(respanned)
+            }
+            TokenTree::Ident(ident) if ident == "$crate" => TokenTree::Ident(ident),
+            mut token => {
+                token.set_span(token.span().resolved_at(span));
+                token
+            }
+        })
+        .collect()
+}
+
+/// `[#[validate(...)]...] field, errors, (key), [context], [custom message], asynchronous`
+pub struct RuleSetInput {
+    attrs: Vec<syn::Attribute>,
+    ident: syn::Ident,
+    errors: syn::Ident,
+    key: TokenStream,
+    context: Option<syn::Type>,
+    custom_message: Option<syn::Meta>,
+    asynchronous: bool,
+}
+
+impl Parse for RuleSetInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs;
+        syn::bracketed!(attrs in input);
+        let attrs = attrs.call(syn::Attribute::parse_outer)?;
+        let ident = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let errors = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let key;
+        syn::parenthesized!(key in input);
+        let key = key.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let context;
+        syn::bracketed!(context in input);
+        let context = (!context.is_empty()).then(|| context.parse()).transpose()?;
+        input.parse::<syn::Token![,]>()?;
+        let custom_message;
+        syn::bracketed!(custom_message in input);
+        let custom_message = (!custom_message.is_empty())
+            .then(|| custom_message.parse())
+            .transpose()?;
+        input.parse::<syn::Token![,]>()?;
+        let asynchronous = input.parse::<syn::LitBool>()?.value;
+
+        Ok(Self {
+            attrs,
+            ident,
+            errors,
+            key,
+            context,
+            custom_message,
+            asynchronous,
+        })
+    }
+}
@@ -0,0 +1,369 @@
+use crate::attribute::field_validate::extract_rule;
+use crate::attribute::{MetaNameValueFieldValidation, MetaPathFieldValidation};
+use crate::serde::attributes::{property_name, RenameRule, SerdeContainer, SerdeField};
+use crate::types::CommaSeparatedMetas;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::str::FromStr;
+
+/// `ValidateRules` implementation of `#[derive(Validate)]`,
+/// which describes the `#[validate(...)]` attributes as data.
+///
+/// The `#[validate]` fields link to the rules of their types through the `Validate` trait,
+/// or `ValidateWithContext<C>` with `#[validate(context = C)]`,
+/// so that the nested types can implement the validation by hand.
+pub fn expand_validate_rules(input: &syn::DeriveInput, context: Option<&syn::Type>) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let container = SerdeContainer::from_attrs(&input.attrs);
+    let rules = custom_rules(&input.attrs);
+
+    let (fields, variants) = match &input.data {
+        syn::Data::Struct(syn::DataStruct { fields, .. }) => {
+            (fields_rules(fields, container.rename_all, context), vec![])
+        }
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => (
+            vec![],
+            variants
+                .iter()
+                .map(|variant| {
+                    let variant_container = SerdeContainer::from_attrs(&variant.attrs);
+                    let name = variant_container.rename.clone().unwrap_or_else(|| {
+                        let name = variant.ident.to_string();
+                        match container.rename_all {
+                            Some(rule) => rule.apply_to_variant(&name),
+                            None => name,
+                        }
+                    });
+                    let fields =
+                        fields_rules(&variant.fields, variant_container.rename_all, context);
+
+                    quote!(::serde_valid::rules::VariantRules {
+                        name: #name,
+                        fields: vec![#(#fields),*],
+                    })
+                })
+                .collect(),
+        ),
+        syn::Data::Union(_) => (vec![], vec![]),
+    };
+
+    quote!(
+        impl #impl_generics ::serde_valid::rules::ValidateRules for #ident #type_generics #where_clause {
+            fn validate_rules() -> ::serde_valid::rules::TypeRules {
+                ::serde_valid::rules::TypeRules {
+                    name: stringify!(#ident),
+                    rules: vec![#(#rules),*],
+                    fields: vec![#(#fields),*],
+                    variants: vec![#(#variants),*],
+                }
+            }
+        }
+    )
+}
+
+/// The method of `Validate` or `ValidateWithContext`, which links the rules of a `#[validate]` field to its type.
+pub fn validate_rules_method() -> TokenStream {
+    quote!(
+        fn __validate_rules() -> Option<::serde_valid::rules::TypeRules> {
+            Some(<Self as ::serde_valid::rules::ValidateRules>::validate_rules())
+        }
+    )
+}
+
+fn fields_rules(
+    fields: &syn::Fields,
+    rename_all: Option<RenameRule>,
+    context: Option<&syn::Type>,
+) -> Vec<TokenStream> {
+    fields
+        .iter()
+        .enumerate()
+        .filter_map(|(index, field)| {
+            let key = match field.ident {
+                Some(_) => property_name(field, &SerdeField::from_attrs(&field.attrs), rename_all),
+                None => index.to_string(),
+            };
+            field_rules(field, &key, context)
+        })
+        .collect()
+}
+
+fn field_rules(field: &syn::Field, key: &str, context: Option<&syn::Type>) -> Option<TokenStream> {
+    let (rules, nested) = collect_field_rules(field)?;
+    let rules = rules.to_vec_tokens();
+    let ty = dereferenced_type(&field.ty);
+    let nested = match (nested, context) {
+        (false, _) => quote!(None),
+        (true, None) => quote!(Some(<#ty as ::serde_valid::Validate>::__validate_rules)),
+        (true, Some(context)) => quote!(Some(
+            <#ty as ::serde_valid::ValidateWithContext<#context>>::__validate_rules
+        )),
+    };
+
+    Some(quote!(::serde_valid::rules::FieldRules {
+        key: #key,
+        rules: #rules,
+        nested: #nested,
+    }))
+}
+
+/// The `Rule`s of the `#[validate(...)]` attributes of a field, and whether it has `#[validate]`.
+///
+/// `None` if the field has no `#[validate]` attribute.
+/// The rules with a `when = ...` condition or with `groups = [...]` are left out,
+/// since they do not always run.
+pub fn collect_field_rules(field: &syn::Field) -> Option<(FieldRuleTokens, bool)> {
+    let attributes = field
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+        .collect::<Vec<_>>();
+    if attributes.is_empty() {
+        return None;
+    }
+
+    Some(collect_rules(attributes))
+}
+
+/// The `Rule`s of the `#[validate(...)]` attributes of a `#[derive(RuleSet)]` type.
+pub fn collect_rule_set_rules(attrs: &[syn::Attribute]) -> FieldRuleTokens {
+    collect_rules(
+        attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("validate")),
+    )
+    .0
+}
+
+/// The `Rule`s of attributes, where the rules of `#[validate(rules = ...)]` are spliced in order.
+#[derive(Debug, Clone)]
+pub struct FieldRuleTokens(Vec<FieldRuleToken>);
+
+#[derive(Debug, Clone)]
+enum FieldRuleToken {
+    Rule(TokenStream),
+    RuleSet(syn::ExprPath),
+}
+
+impl FieldRuleTokens {
+    /// The `Vec<Rule>` expression.
+    pub fn to_vec_tokens(&self) -> TokenStream {
+        let mut groups = vec![];
+        let mut rules = vec![];
+        for token in &self.0 {
+            match token {
+                FieldRuleToken::Rule(rule) => rules.push(rule),
+                FieldRuleToken::RuleSet(rule_set) => {
+                    if !rules.is_empty() {
+                        groups.push(quote!(vec![#(#rules),*]));
+                        rules.clear();
+                    }
+                    groups.push(quote!(<#rule_set as ::serde_valid::RuleSet>::rules()));
+                }
+            }
+        }
+
+        if groups.is_empty() {
+            quote!(vec![#(#rules),*])
+        } else {
+            if !rules.is_empty() {
+                groups.push(quote!(vec![#(#rules),*]));
+            }
+            quote!([#(#groups),*].concat())
+        }
+    }
+
+    /// The `&[Rule]` expression.
+    #[cfg(feature = "proptest")]
+    pub fn to_slice_tokens(&self) -> TokenStream {
+        if self
+            .0
+            .iter()
+            .any(|token| matches!(token, FieldRuleToken::RuleSet(_)))
+        {
+            let rules = self.to_vec_tokens();
+            quote!(&#rules)
+        } else {
+            let rules = self.0.iter().filter_map(|token| match token {
+                FieldRuleToken::Rule(rule) => Some(rule),
+                FieldRuleToken::RuleSet(_) => None,
+            });
+            quote!(&[#(#rules),*])
+        }
+    }
+}
+
+fn collect_rules<'a>(
+    attributes: impl IntoIterator<Item = &'a syn::Attribute>,
+) -> (FieldRuleTokens, bool) {
+    let mut rules = vec![];
+    let mut nested = false;
+    for attribute in attributes {
+        match &attribute.meta {
+            syn::Meta::Path(_) => nested = true,
+            syn::Meta::List(list) => {
+                let Ok(metas) = list.parse_args_with(CommaSeparatedMetas::parse_terminated) else {
+                    continue;
+                };
+                if metas
+                    .iter()
+                    .any(|meta| meta.path().is_ident("when") || meta.path().is_ident("groups"))
+                {
+                    continue;
+                }
+                match metas.first() {
+                    Some(syn::Meta::NameValue(syn::MetaNameValue {
+                        path,
+                        value: syn::Expr::Path(rule_set),
+                        ..
+                    })) if path.is_ident("rules") => {
+                        rules.push(FieldRuleToken::RuleSet(rule_set.clone()));
+                    }
+                    Some(meta) => rules.extend(field_rule(meta).map(FieldRuleToken::Rule)),
+                    None => {}
+                }
+            }
+            syn::Meta::NameValue(_) => {}
+        }
+    }
+    (FieldRuleTokens(rules), nested)
+}
+
+fn field_rule(meta: &syn::Meta) -> Option<TokenStream> {
+    match meta {
+        syn::Meta::Path(path) => {
+            match MetaPathFieldValidation::from_str(&path.get_ident()?.to_string()).ok()? {
+                MetaPathFieldValidation::UniqueItems => {
+                    Some(quote!(::serde_valid::rules::Rule::UniqueItems))
+                }
+            }
+        }
+        syn::Meta::List(list) if list.path.is_ident("custom") => {
+            Some(custom_rule(&list.parse_args().ok()?))
+        }
+        syn::Meta::List(list) if list.path.is_ident("rule") => {
+            let (rule, _) = extract_rule(list).ok()?;
+            Some(quote!(::serde_valid::rules::Rule::UserDefined(
+                <#rule as ::serde_valid::validation::ValidationRule>::NAME
+            )))
+        }
+        syn::Meta::List(_) => None,
+        syn::Meta::NameValue(name_value) => {
+            let validation_type =
+                MetaNameValueFieldValidation::from_str(&name_value.path.get_ident()?.to_string())
+                    .ok()?;
+            let value = &name_value.value;
+            let number = |variant: TokenStream| {
+                quote!(::serde_valid::rules::Rule::#variant(
+                    ::serde_valid::validation::Number::from(#value)
+                ))
+            };
+            Some(match validation_type {
+                MetaNameValueFieldValidation::Minimum => number(quote!(Minimum)),
+                MetaNameValueFieldValidation::Maximum => number(quote!(Maximum)),
+                MetaNameValueFieldValidation::ExclusiveMinimum => number(quote!(ExclusiveMinimum)),
+                MetaNameValueFieldValidation::ExclusiveMaximum => number(quote!(ExclusiveMaximum)),
+                MetaNameValueFieldValidation::MultipleOf => number(quote!(MultipleOf)),
+                MetaNameValueFieldValidation::MinLength => {
+                    quote!(::serde_valid::rules::Rule::MinLength(#value))
+                }
+                MetaNameValueFieldValidation::MaxLength => {
+                    quote!(::serde_valid::rules::Rule::MaxLength(#value))
+                }
+                MetaNameValueFieldValidation::MinItems => {
+                    quote!(::serde_valid::rules::Rule::MinItems(#value))
+                }
+                MetaNameValueFieldValidation::MaxItems => {
+                    quote!(::serde_valid::rules::Rule::MaxItems(#value))
+                }
+                MetaNameValueFieldValidation::MinProperties => {
+                    quote!(::serde_valid::rules::Rule::MinProperties(#value))
+                }
+                MetaNameValueFieldValidation::MaxProperties => {
+                    quote!(::serde_valid::rules::Rule::MaxProperties(#value))
+                }
+                MetaNameValueFieldValidation::Pattern => quote!(
+                    ::serde_valid::rules::Rule::Pattern(
+                        ::serde_valid::validation::Pattern::from(#value)
+                    )
+                ),
+                MetaNameValueFieldValidation::Enumerate => {
+                    let syn::Expr::Array(array) = value else {
+                        return None;
+                    };
+                    let items = array.elems.iter();
+                    quote!(::serde_valid::rules::Rule::Enumerate(vec![
+                        #(::serde_valid::validation::Literal::from(#items)),*
+                    ]))
+                }
+                MetaNameValueFieldValidation::Custom
+                | MetaNameValueFieldValidation::CustomAsync => custom_rule(value),
+                MetaNameValueFieldValidation::SkipIf | MetaNameValueFieldValidation::Rules => {
+                    return None
+                }
+            })
+        }
+    }
+}
+
+/// The rules of `#[validate(custom = ...)]` on a struct or an enum.
+fn custom_rules(attrs: &[syn::Attribute]) -> Vec<TokenStream> {
+    attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+        .filter_map(|attribute| match &attribute.meta {
+            syn::Meta::List(list) => {
+                match list
+                    .parse_args_with(CommaSeparatedMetas::parse_terminated)
+                    .ok()?
+                    .first()?
+                {
+                    syn::Meta::NameValue(name_value) if name_value.path.is_ident("custom") => {
+                        Some(custom_rule(&name_value.value))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The rule of a custom validator, which is described by the path of its function.
+fn custom_rule(expr: &syn::Expr) -> TokenStream {
+    match custom_function_path(expr) {
+        Some(path) => quote!(::serde_valid::rules::Rule::Custom(Some(#path))),
+        None => quote!(::serde_valid::rules::Rule::Custom(None)),
+    }
+}
+
+/// The path of `validate_fn` or `validate_fn(args)`, without generic arguments.
+fn custom_function_path(expr: &syn::Expr) -> Option<String> {
+    let path = match expr {
+        syn::Expr::Path(syn::ExprPath { path, .. }) => path,
+        syn::Expr::Call(syn::ExprCall { func, .. }) => match func.as_ref() {
+            syn::Expr::Path(syn::ExprPath { path, .. }) => path,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+
+    Some(match path.leading_colon {
+        Some(_) => format!("::{segments}"),
+        None => segments,
+    })
+}
+
+fn dereferenced_type(ty: &syn::Type) -> &syn::Type {
+    match ty {
+        syn::Type::Reference(reference) => dereferenced_type(&reference.elem),
+        _ => ty,
+    }
+}
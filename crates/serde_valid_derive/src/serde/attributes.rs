@@ -0,0 +1,214 @@
+use crate::types::CommaSeparatedMetas;
+
+/// Serde attributes of a struct, an enum or an enum variant.
+#[derive(Debug, Default)]
+pub struct SerdeContainer {
+    pub rename: Option<String>,
+    pub rename_all: Option<RenameRule>,
+    pub tag: Option<String>,
+    pub content: Option<String>,
+    pub untagged: bool,
+    pub deny_unknown_fields: bool,
+    pub default: bool,
+    pub skip: bool,
+}
+
+/// Serde attributes of a field.
+#[derive(Debug, Default)]
+pub struct SerdeField {
+    pub rename: Option<String>,
+    pub default: bool,
+    pub skip: bool,
+    pub flatten: bool,
+}
+
+impl SerdeContainer {
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> Self {
+        let mut container = Self::default();
+        for meta in serde_metas(attrs) {
+            let name = meta.path().get_ident().map(|ident| ident.to_string());
+            match (name.as_deref(), &meta) {
+                (Some("rename"), _) => container.rename = deserialize_name(&meta),
+                (Some("rename_all"), _) => {
+                    container.rename_all =
+                        deserialize_name(&meta).and_then(|rule| rule.parse().ok())
+                }
+                (Some("tag"), syn::Meta::NameValue(name_value)) => {
+                    container.tag = lit_str(&name_value.value)
+                }
+                (Some("content"), syn::Meta::NameValue(name_value)) => {
+                    container.content = lit_str(&name_value.value)
+                }
+                (Some("untagged"), syn::Meta::Path(_)) => container.untagged = true,
+                (Some("deny_unknown_fields"), syn::Meta::Path(_)) => {
+                    container.deny_unknown_fields = true
+                }
+                (Some("default"), _) => container.default = true,
+                (Some("skip" | "skip_deserializing"), syn::Meta::Path(_)) => container.skip = true,
+                _ => {}
+            }
+        }
+        container
+    }
+}
+
+impl SerdeField {
+    pub fn from_attrs(attrs: &[syn::Attribute]) -> Self {
+        let mut field = Self::default();
+        for meta in serde_metas(attrs) {
+            let name = meta.path().get_ident().map(|ident| ident.to_string());
+            match (name.as_deref(), &meta) {
+                (Some("rename"), _) => field.rename = deserialize_name(&meta),
+                (Some("default"), _) => field.default = true,
+                (Some("skip" | "skip_deserializing"), syn::Meta::Path(_)) => field.skip = true,
+                (Some("flatten"), syn::Meta::Path(_)) => field.flatten = true,
+                _ => {}
+            }
+        }
+        field
+    }
+}
+
+/// The deserialized name of a named field.
+pub fn property_name(
+    field: &syn::Field,
+    serde_field: &SerdeField,
+    rename_all: Option<RenameRule>,
+) -> String {
+    serde_field.rename.clone().unwrap_or_else(|| {
+        let name = field.ident.as_ref().unwrap().to_string();
+        let name = name.strip_prefix("r#").unwrap_or(&name).to_string();
+        match rename_all {
+            Some(rule) => rule.apply_to_field(&name),
+            None => name,
+        }
+    })
+}
+
+fn serde_metas(attrs: &[syn::Attribute]) -> Vec<syn::Meta> {
+    attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("serde"))
+        .filter_map(|attribute| match &attribute.meta {
+            syn::Meta::List(list) => list
+                .parse_args_with(CommaSeparatedMetas::parse_terminated)
+                .ok(),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// The name used for deserialization, from `name = "..."` or `name(deserialize = "...")`.
+fn deserialize_name(meta: &syn::Meta) -> Option<String> {
+    match meta {
+        syn::Meta::NameValue(name_value) => lit_str(&name_value.value),
+        syn::Meta::List(list) => list
+            .parse_args_with(CommaSeparatedMetas::parse_terminated)
+            .ok()?
+            .into_iter()
+            .find_map(|meta| match meta {
+                syn::Meta::NameValue(name_value) if name_value.path.is_ident("deserialize") => {
+                    lit_str(&name_value.value)
+                }
+                _ => None,
+            }),
+        syn::Meta::Path(_) => None,
+    }
+}
+
+fn lit_str(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit_str),
+            ..
+        }) => Some(lit_str.value()),
+        _ => None,
+    }
+}
+
+/// `#[serde(rename_all = "...")]` rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl std::str::FromStr for RenameRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lowercase" => Ok(Self::Lower),
+            "UPPERCASE" => Ok(Self::Upper),
+            "PascalCase" => Ok(Self::Pascal),
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            "kebab-case" => Ok(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebab),
+            _ => Err(s.to_owned()),
+        }
+    }
+}
+
+impl RenameRule {
+    /// Rename a variant, written in `PascalCase`.
+    pub fn apply_to_variant(&self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_owned(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => variant[..1].to_ascii_lowercase() + &variant[1..],
+            _ => {
+                let mut snake = String::new();
+                for (index, c) in variant.char_indices() {
+                    if index > 0 && c.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(c.to_ascii_lowercase());
+                }
+                self.apply_to_snake_case(&snake)
+            }
+        }
+    }
+
+    /// Rename a field, written in `snake_case`.
+    pub fn apply_to_field(&self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper => field.to_ascii_uppercase(),
+            Self::Pascal | Self::Camel => {
+                let mut pascal = String::new();
+                let mut capitalize = *self == Self::Pascal;
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(c);
+                    }
+                }
+                pascal
+            }
+            _ => self.apply_to_snake_case(field),
+        }
+    }
+
+    fn apply_to_snake_case(&self, snake: &str) -> String {
+        match self {
+            Self::ScreamingSnake => snake.to_ascii_uppercase(),
+            Self::Kebab => snake.replace('_', "-"),
+            Self::ScreamingKebab => snake.to_ascii_uppercase().replace('_', "-"),
+            _ => snake.to_owned(),
+        }
+    }
+}
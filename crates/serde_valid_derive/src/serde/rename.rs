@@ -1,77 +1,30 @@
 use std::collections::HashMap;
 
-use crate::types::{CommaSeparatedMetas, Field, NamedField};
+use crate::serde::attributes::{property_name, RenameRule, SerdeField};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
 pub type RenameMap = HashMap<String, TokenStream>;
 
-pub fn collect_serde_rename_map(fields: &syn::FieldsNamed) -> RenameMap {
-    let mut renames = RenameMap::new();
-    for field in fields.named.iter() {
-        let named_field = NamedField::new(field);
-        for attribute in named_field.attrs() {
-            if attribute.path().is_ident("serde") {
-                if let Some(rename) = find_rename_from_serde_attributes(attribute) {
-                    renames.insert(
-                        field.ident.to_token_stream().to_string(),
-                        quote!(std::borrow::Cow::from(#rename)),
-                    );
-                }
+/// The keys of the fields renamed by `#[serde(rename = ...)]` or `#[serde(rename_all = ...)]`,
+/// which are the same as the property names of the JSON Schema.
+pub fn collect_serde_rename_map(
+    fields: &syn::FieldsNamed,
+    rename_all: Option<RenameRule>,
+) -> RenameMap {
+    fields
+        .named
+        .iter()
+        .filter_map(|field| {
+            let serde_field = SerdeField::from_attrs(&field.attrs);
+            if serde_field.rename.is_none() && rename_all.is_none() {
+                return None;
             }
-        }
-    }
-    renames
-}
-
-fn find_rename_from_serde_attributes(attribute: &syn::Attribute) -> Option<TokenStream> {
-    if let syn::Meta::List(serde_list) = &attribute.meta {
-        if let Ok(serde_nested_meta) =
-            serde_list.parse_args_with(CommaSeparatedMetas::parse_terminated)
-        {
-            for serde_meta in serde_nested_meta {
-                if let Some(rename) = find_rename_from_serde_rename_attributes(&serde_meta) {
-                    return Some(rename);
-                }
-            }
-        }
-    }
-    None
-}
-
-fn find_rename_from_serde_rename_attributes(serde_meta: &syn::Meta) -> Option<TokenStream> {
-    match serde_meta {
-        syn::Meta::NameValue(rename_name_value) => {
-            if let syn::Expr::Lit(syn::ExprLit {
-                lit: syn::Lit::Str(lit_str),
-                ..
-            }) = &rename_name_value.value
-            {
-                Some(lit_str.to_token_stream())
-            } else {
-                None
-            }
-        }
-        syn::Meta::List(rename_list) => {
-            if let Ok(nested) = rename_list.parse_args_with(CommaSeparatedMetas::parse_terminated) {
-                for rename_meta in nested {
-                    if !rename_meta.path().is_ident("deserialize") {
-                        continue;
-                    }
-                    if let syn::Meta::NameValue(deserialize_name_value) = rename_meta {
-                        if let syn::Expr::Lit(syn::ExprLit {
-                            lit: syn::Lit::Str(lit_str),
-                            ..
-                        }) = &deserialize_name_value.value
-                        {
-                            return Some(lit_str.to_token_stream());
-                        }
-                    }
-                }
-            }
-
-            None
-        }
-        _ => None,
-    }
+            let rename = property_name(field, &serde_field, rename_all);
+            Some((
+                field.ident.to_token_stream().to_string(),
+                quote!(std::borrow::Cow::from(#rename)),
+            ))
+        })
+        .collect()
 }
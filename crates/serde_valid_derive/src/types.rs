@@ -2,7 +2,7 @@ mod field;
 mod nested_meta;
 mod single_ident_path;
 
-pub use field::{Field, NamedField, UnnamedField};
+pub use field::{Field, NamedField, RuleSetField, UnnamedField};
 pub use nested_meta::NestedMeta;
 use proc_macro2::TokenStream;
 pub use single_ident_path::SingleIdentPath;
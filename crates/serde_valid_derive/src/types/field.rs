@@ -1,7 +1,9 @@
 mod named;
+mod rule_set;
 mod unnamed;
 
 pub use named::NamedField;
+pub use rule_set::RuleSetField;
 pub use unnamed::UnnamedField;
 
 pub trait Field {
@@ -17,6 +19,12 @@ pub trait Field {
 
     fn attrs(&self) -> &Vec<syn::Attribute>;
 
+    /// The type of `#[validate(context = ...)]` on the struct or the enum of the field.
+    fn context(&self) -> Option<&syn::Type>;
+
+    /// Whether the validators are generated for the derived `AsyncValidate`.
+    fn is_async(&self) -> bool;
+
     #[allow(dead_code)]
     fn vis(&self) -> &syn::Visibility;
 
@@ -8,6 +8,8 @@ use syn::spanned::Spanned;
 pub struct NamedField<'a> {
     name: String,
     field: Cow<'a, syn::Field>,
+    context: Option<&'a syn::Type>,
+    asynchronous: bool,
 }
 
 impl<'a> NamedField<'a> {
@@ -18,6 +20,19 @@ impl<'a> NamedField<'a> {
         Self {
             name: field.ident.as_ref().unwrap().to_string(),
             field: Cow::Borrowed(field),
+            context: None,
+            asynchronous: false,
+        }
+    }
+
+    pub fn with_context(self, context: Option<&'a syn::Type>) -> Self {
+        Self { context, ..self }
+    }
+
+    pub fn with_async(self, asynchronous: bool) -> Self {
+        Self {
+            asynchronous,
+            ..self
         }
     }
 }
@@ -49,6 +64,14 @@ impl Field for NamedField<'_> {
         self.field.attrs.as_ref()
     }
 
+    fn context(&self) -> Option<&syn::Type> {
+        self.context
+    }
+
+    fn is_async(&self) -> bool {
+        self.asynchronous
+    }
+
     fn vis(&self) -> &syn::Visibility {
         &self.field.vis
     }
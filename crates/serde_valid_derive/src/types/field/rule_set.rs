@@ -0,0 +1,93 @@
+use super::Field;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The field of `#[validate(rules = ...)]`, where the attributes of the rule set are expanded.
+#[derive(Debug, Clone)]
+pub struct RuleSetField {
+    name: String,
+    ident: syn::Ident,
+    key: TokenStream,
+    errors: syn::Ident,
+    attrs: Vec<syn::Attribute>,
+    context: Option<syn::Type>,
+    asynchronous: bool,
+    vis: syn::Visibility,
+    ty: syn::Type,
+}
+
+impl RuleSetField {
+    pub fn new(
+        ident: syn::Ident,
+        key: TokenStream,
+        errors: syn::Ident,
+        attrs: Vec<syn::Attribute>,
+    ) -> Self {
+        Self {
+            name: ident.to_string(),
+            ident,
+            key,
+            errors,
+            attrs,
+            context: None,
+            asynchronous: false,
+            vis: syn::Visibility::Inherited,
+            ty: syn::parse_quote!(_),
+        }
+    }
+
+    pub fn with_context(self, context: Option<syn::Type>) -> Self {
+        Self { context, ..self }
+    }
+
+    pub fn with_async(self, asynchronous: bool) -> Self {
+        Self {
+            asynchronous,
+            ..self
+        }
+    }
+}
+
+impl Field for RuleSetField {
+    fn name(&self) -> &String {
+        &self.name
+    }
+
+    fn ident(&self) -> &syn::Ident {
+        &self.ident
+    }
+
+    fn key(&self) -> TokenStream {
+        self.key.clone()
+    }
+
+    fn errors_variable(&self) -> TokenStream {
+        let errors = &self.errors;
+        quote!(#errors)
+    }
+
+    fn getter_token(&self) -> TokenStream {
+        let ident = &self.ident;
+        quote!(#ident)
+    }
+
+    fn attrs(&self) -> &Vec<syn::Attribute> {
+        &self.attrs
+    }
+
+    fn context(&self) -> Option<&syn::Type> {
+        self.context.as_ref()
+    }
+
+    fn is_async(&self) -> bool {
+        self.asynchronous
+    }
+
+    fn vis(&self) -> &syn::Visibility {
+        &self.vis
+    }
+
+    fn ty(&self) -> &syn::Type {
+        &self.ty
+    }
+}
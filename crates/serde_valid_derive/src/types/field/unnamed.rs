@@ -11,6 +11,8 @@ pub struct UnnamedField<'a> {
     index: usize,
     ident: syn::Ident,
     field: Cow<'a, syn::Field>,
+    context: Option<&'a syn::Type>,
+    asynchronous: bool,
 }
 
 impl<'a> UnnamedField<'a> {
@@ -23,6 +25,19 @@ impl<'a> UnnamedField<'a> {
             index,
             ident: syn::Ident::new(&format!("__{}", index), field.span()),
             field: Cow::Borrowed(field),
+            context: None,
+            asynchronous: false,
+        }
+    }
+
+    pub fn with_context(self, context: Option<&'a syn::Type>) -> Self {
+        Self { context, ..self }
+    }
+
+    pub fn with_async(self, asynchronous: bool) -> Self {
+        Self {
+            asynchronous,
+            ..self
         }
     }
 }
@@ -54,6 +69,14 @@ impl Field for UnnamedField<'_> {
         self.field.attrs.as_ref()
     }
 
+    fn context(&self) -> Option<&syn::Type> {
+        self.context
+    }
+
+    fn is_async(&self) -> bool {
+        self.asynchronous
+    }
+
     fn vis(&self) -> &syn::Visibility {
         &self.field.vis
     }
@@ -57,3 +57,19 @@ impl std::fmt::Display for Literal {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Literal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Literal::Bool(value) => serializer.serialize_bool(*value),
+            Literal::Number(value) => value.serialize(serializer),
+            Literal::String(value) => serializer.serialize_str(value),
+            Literal::Char(value) => serializer.serialize_char(*value),
+            Literal::Null => serializer.serialize_none(),
+        }
+    }
+}
@@ -124,3 +124,44 @@ impl_from_trait!(NonZeroU128);
 impl_from_trait!(NonZeroUsize);
 impl_from_trait!(f32);
 impl_from_trait!(f64);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Number {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match *self {
+            Number::I8(num) => serializer.serialize_i8(num),
+            Number::I16(num) => serializer.serialize_i16(num),
+            Number::I32(num) => serializer.serialize_i32(num),
+            Number::I64(num) => serializer.serialize_i64(num),
+            #[cfg(feature = "i128")]
+            Number::I128(num) => serializer.serialize_i128(num),
+            Number::Isize(num) => serializer.serialize_i64(num as i64),
+            Number::U8(num) => serializer.serialize_u8(num),
+            Number::U16(num) => serializer.serialize_u16(num),
+            Number::U32(num) => serializer.serialize_u32(num),
+            Number::U64(num) => serializer.serialize_u64(num),
+            #[cfg(feature = "i128")]
+            Number::U128(num) => serializer.serialize_u128(num),
+            Number::Usize(num) => serializer.serialize_u64(num as u64),
+            Number::NonZeroI8(num) => serializer.serialize_i8(num.get()),
+            Number::NonZeroI16(num) => serializer.serialize_i16(num.get()),
+            Number::NonZeroI32(num) => serializer.serialize_i32(num.get()),
+            Number::NonZeroI64(num) => serializer.serialize_i64(num.get()),
+            #[cfg(feature = "i128")]
+            Number::NonZeroI128(num) => serializer.serialize_i128(num.get()),
+            Number::NonZeroIsize(num) => serializer.serialize_i64(num.get() as i64),
+            Number::NonZeroU8(num) => serializer.serialize_u8(num.get()),
+            Number::NonZeroU16(num) => serializer.serialize_u16(num.get()),
+            Number::NonZeroU32(num) => serializer.serialize_u32(num.get()),
+            Number::NonZeroU64(num) => serializer.serialize_u64(num.get()),
+            #[cfg(feature = "i128")]
+            Number::NonZeroU128(num) => serializer.serialize_u128(num.get()),
+            Number::NonZeroUsize(num) => serializer.serialize_u64(num.get() as u64),
+            Number::F32(num) => serializer.serialize_f32(num),
+            Number::F64(num) => serializer.serialize_f64(num),
+        }
+    }
+}
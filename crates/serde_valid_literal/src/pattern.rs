@@ -21,3 +21,13 @@ impl std::fmt::Display for Pattern {
         write!(f, "{:}", self.0)
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pattern {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}